@@ -24,6 +24,24 @@ fn new_group(c: &mut Criterion) {
     group.finish()
 }
 
+fn clone_group(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Measurement::clone");
+
+    for unit_string in common::UNIT_STRINGS {
+        group.bench_with_input(
+            BenchmarkId::new("clone", unit_string),
+            unit_string,
+            |b, unit_string| {
+                let measurement = Measurement::try_new(1.0, unit_string).unwrap();
+
+                b.iter(|| measurement.clone());
+            },
+        );
+    }
+
+    group.finish()
+}
+
 //-----------------------------------------------------------------------------
 // impl Convertible
 //-----------------------------------------------------------------------------
@@ -67,6 +85,7 @@ fn convert_to_unit_group(c: &mut Criterion) {
 criterion_group!(
     measurement_benches,
     new_group,
+    clone_group,
     convert_to_str_group,
     convert_to_unit_group,
 );
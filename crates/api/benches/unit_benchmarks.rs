@@ -55,6 +55,8 @@ macro_rules! bench_over_inputs_math {
 
 bench_over_inputs_method!(is_special_group, "Unit::is_special()", is_special);
 
+bench_over_inputs_method!(clone_group, "Unit::clone()", clone);
+
 bench_over_inputs_method!(scalar_group, "Unit::scalar()", scalar);
 bench_over_inputs_method!(magnitude_group, "Unit::magnitude()", magnitude);
 bench_over_inputs_method!(expression_group, "Unit::expression()", expression);
@@ -116,6 +118,29 @@ fn is_compatible_with_group(c: &mut Criterion) {
     group.finish()
 }
 
+fn convert_slice_group(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Converter::convert_slice()");
+
+    for (lhs_string, rhs_string) in common::UNIT_PAIRS {
+        let lhs = Unit::from_str(lhs_string).unwrap();
+        let rhs = Unit::from_str(rhs_string).unwrap();
+
+        if let Ok(converter) = lhs.converter_to(&rhs) {
+            group.bench_with_input(
+                BenchmarkId::new("convert_slice", format!("{lhs_string}->{rhs_string}")),
+                &converter,
+                |b, converter| {
+                    let mut values = vec![1.0; 1_000];
+
+                    b.iter(|| converter.convert_slice(&mut values));
+                },
+            );
+        }
+    }
+
+    group.finish()
+}
+
 //-----------------------------------------------------------------------------
 // impl Display
 //-----------------------------------------------------------------------------
@@ -186,6 +211,7 @@ bench_over_inputs_math!(div_group, "Unit::div()", /);
 criterion_group!(
     unit_benches,
     is_special_group,
+    clone_group,
     scalar_group,
     magnitude_group,
     expression_group,
@@ -193,6 +219,7 @@ criterion_group!(
     to_reduced_group,
     composition_group,
     is_compatible_with_group,
+    convert_slice_group,
     display_group,
     from_str_group,
     partial_eq_group,
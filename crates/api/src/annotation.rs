@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, sync::Arc};
 
 use crate::{
     composable::ComposablyEq,
@@ -9,8 +9,11 @@ use crate::{
     Composable, IsCompatibleWith, Term,
 };
 
-#[derive(Default, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Annotation(String);
+/// The text inside an annotation's curly braces. It's shared, so cloning an annotated `Term` (and
+/// so its `Unit`) doesn't copy the text.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Annotation(Arc<str>);
 
 impl Annotation {
     #[must_use]
@@ -19,6 +22,12 @@ impl Annotation {
     }
 }
 
+impl Default for Annotation {
+    fn default() -> Self {
+        Self(Arc::from(""))
+    }
+}
+
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{{{annotation}}}", annotation = self.0)
@@ -36,7 +45,7 @@ where
     T: ToString + ?Sized,
 {
     fn from(value: &'a T) -> Self {
-        Self(value.to_string())
+        Self(Arc::from(value.to_string()))
     }
 }
 
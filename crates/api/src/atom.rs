@@ -17,6 +17,7 @@ mod is_compatible_with;
 mod partial_eq;
 mod partial_ord;
 mod reducible;
+mod table;
 
 #[cfg(feature = "v2")]
 mod v2;
@@ -346,6 +347,320 @@ pub enum Atom {
     Baud,
 }
 impl Atom {
+    /// Every `Atom`, in the order they're defined in `Atoms.toml`.
+    pub const ALL: [Self; 310] = [
+        Self::Meter,
+        Self::Second,
+        Self::Gram,
+        Self::Radian,
+        Self::Kelvin,
+        Self::Coulomb,
+        Self::Candela,
+        Self::TheNumberTenForArbitraryPowersStar,
+        Self::TheNumberTenForArbitraryPowersCaret,
+        Self::TheNumberPi,
+        Self::Percent,
+        Self::PartsPerThousand,
+        Self::PartsPerMillion,
+        Self::PartsPerBillion,
+        Self::PartsPerTrillion,
+        Self::Mole,
+        Self::Steradian,
+        Self::Hertz,
+        Self::Newton,
+        Self::Pascal,
+        Self::Joule,
+        Self::Watt,
+        Self::Ampere,
+        Self::Volt,
+        Self::Farad,
+        Self::Ohm,
+        Self::Siemens,
+        Self::Weber,
+        Self::DegreeCelsius,
+        Self::Tesla,
+        Self::Henry,
+        Self::Lumen,
+        Self::Lux,
+        Self::Becquerel,
+        Self::Gray,
+        Self::Sievert,
+        Self::Gon,
+        Self::Degree,
+        Self::MinuteAngle,
+        Self::SecondAngle,
+        Self::Liter,
+        Self::LiterSecondary,
+        Self::Are,
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+        Self::TropicalYear,
+        Self::MeanJulianYear,
+        Self::MeanGregorianYear,
+        Self::Year,
+        Self::Week,
+        Self::SynodalMonth,
+        Self::MeanJulianMonth,
+        Self::MeanGregorianMonth,
+        Self::Month,
+        Self::Tonne,
+        Self::Bar,
+        Self::UnifiedAtomicMassUnit,
+        Self::Electronvolt,
+        Self::AstronomicUnit,
+        Self::Parsec,
+        Self::VelocityOfLight,
+        Self::PlanckConstant,
+        Self::BoltzmannConstant,
+        Self::PermittivityOfVacuum,
+        Self::PermeabilityOfVacuum,
+        Self::ElementaryCharge,
+        Self::ElectronMass,
+        Self::ProtonMass,
+        Self::NewtonianConstantOfGravitation,
+        Self::StandardAccelerationOfFreeFall,
+        Self::StandardAtmosphere,
+        Self::LightYear,
+        Self::GramForce,
+        Self::PoundForceAvoirdupois,
+        Self::Kayser,
+        Self::Gal,
+        Self::Dyne,
+        Self::Erg,
+        Self::Poise,
+        Self::Biot,
+        Self::Stokes,
+        Self::Maxwell,
+        Self::Gauss,
+        Self::Oersted,
+        Self::Gilbert,
+        Self::Stilb,
+        Self::Lambert,
+        Self::Phot,
+        Self::Curie,
+        Self::Roentgen,
+        Self::RadiationAbsorbedDose,
+        Self::RadiationEquivalentMan,
+        Self::InchInternational,
+        Self::FootInternational,
+        Self::YardInternational,
+        Self::MileInternational,
+        Self::FathomInternational,
+        Self::NauticalMileInternational,
+        Self::KnotInternational,
+        Self::SquareInchInternational,
+        Self::SquareFootInternational,
+        Self::SquareYardInternational,
+        Self::CubicInchInternational,
+        Self::CubicFootInternational,
+        Self::CubicYardInternational,
+        Self::BoardFootInternational,
+        Self::CordInternational,
+        Self::MilInternational,
+        Self::CircularMilInternational,
+        Self::HandInternational,
+        Self::FootUS,
+        Self::YardUS,
+        Self::InchUS,
+        Self::RodUS,
+        Self::GuntersChainUS,
+        Self::LinkForGuntersChainUS,
+        Self::RamdensChainUS,
+        Self::LinkForRamdensChainUS,
+        Self::FathomUS,
+        Self::FurlongUS,
+        Self::MileUS,
+        Self::AcreUS,
+        Self::SquareRodUS,
+        Self::SquareMileUS,
+        Self::Section,
+        Self::Township,
+        Self::MilUS,
+        Self::InchBritish,
+        Self::FootBritish,
+        Self::RodBritish,
+        Self::GuntersChainBritish,
+        Self::LinkForGuntersChainBritish,
+        Self::FathomBritish,
+        Self::PaceBritish,
+        Self::YardBritish,
+        Self::MileBritish,
+        Self::NauticalMileBritish,
+        Self::KnotBritish,
+        Self::AcreBritish,
+        Self::QueenAnnesWineGallonUS,
+        Self::BarrelUS,
+        Self::QuartUS,
+        Self::PintUS,
+        Self::GillUS,
+        Self::FluidOunceUS,
+        Self::FluidDramUS,
+        Self::MinimUS,
+        Self::CordUS,
+        Self::BushelUS,
+        Self::HistoricalWinchesterGallon,
+        Self::PeckUS,
+        Self::DryQuartUS,
+        Self::DryPintUS,
+        Self::TablespoonUS,
+        Self::TeaspoonUS,
+        Self::CupUS,
+        Self::MetricFluidOunce,
+        Self::MetricCup,
+        Self::MetricTeaspoon,
+        Self::MetricTablespoon,
+        Self::GallonBritish,
+        Self::PeckBritish,
+        Self::BushelBritish,
+        Self::QuartBritish,
+        Self::PintBritish,
+        Self::GillBritish,
+        Self::FluidOunceBritish,
+        Self::FluidDramBritish,
+        Self::MinimBritish,
+        Self::Grain,
+        Self::PoundAvoirdupois,
+        Self::OunceAvoirdupois,
+        Self::DramAvoirdupois,
+        Self::ShortHundredweightAvoirdupois,
+        Self::LongHunderdweightAvoirdupois,
+        Self::ShortTonAvoirdupois,
+        Self::LongTonAvoirdupois,
+        Self::StoneAvoirdupois,
+        Self::PennyweightTroy,
+        Self::OunceTroy,
+        Self::PoundTroy,
+        Self::ScrupleApothecaries,
+        Self::DramApothecaries,
+        Self::OunceApothecaries,
+        Self::PoundApothecaries,
+        Self::MetricOunce,
+        Self::Line,
+        Self::Point,
+        Self::Pica,
+        Self::PrintersPoint,
+        Self::PrintersPica,
+        Self::Pied,
+        Self::Pouce,
+        Self::Ligne,
+        Self::Didot,
+        Self::Cicero,
+        Self::DegreeFahrenheit,
+        Self::DegreeRankine,
+        Self::DegreeReaumur,
+        Self::CalorieAt15C,
+        Self::CalorieAt20C,
+        Self::MeanCalorie,
+        Self::InternationalTableCalorie,
+        Self::ThermochemicalCalorie,
+        Self::Calorie,
+        Self::NutritionLabelCalories,
+        Self::BritishThermalUnitAt39F,
+        Self::BritishThermalUnitAt59F,
+        Self::BritishThermalUnitAt60F,
+        Self::MeanBritishThermalUnit,
+        Self::InternationalTableBritishThermalUnit,
+        Self::ThermochemicalBritishThermalUnit,
+        Self::BritishThermalUnit,
+        Self::Horsepower,
+        Self::Tex,
+        Self::Denier,
+        Self::MeterOfWaterColumn,
+        Self::MeterOfMercuryColumn,
+        Self::InchOfWaterColumn,
+        Self::InchOfMercuryColumn,
+        Self::PeripheralVascularResistanceUnit,
+        Self::WoodUnit,
+        Self::Diopter,
+        Self::PrismDiopter,
+        Self::PercentOfSlope,
+        Self::MeshInternational,
+        Self::Charriere,
+        Self::Drop,
+        Self::HounsfieldUnit,
+        Self::MetabolicEquivalent,
+        Self::HomeopathicPotencyOfDecimalSeriesRetired,
+        Self::HomeopathicPotencyOfCentesimalSeriesRetired,
+        Self::HomeopathicPotencyOfMillesimalSeriesRetired,
+        Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired,
+        Self::HomeopathicPotencyOfDecimalHahnemannianSeries,
+        Self::HomeopathicPotencyOfCentesimalHahnemannianSeries,
+        Self::HomeopathicPotencyOfMillesimalHahnemannianSeries,
+        Self::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries,
+        Self::HomeopathicPotencyOfDecimalKorsakovianSeries,
+        Self::HomeopathicPotencyOfCentesimalKorsakovianSeries,
+        Self::HomeopathicPotencyOfMillesimalKorsakovianSeries,
+        Self::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries,
+        Self::Equivalents,
+        Self::Osmole,
+        Self::PH,
+        Self::GramPercent,
+        Self::SvedbergUnit,
+        Self::HighPowerField,
+        Self::LowPowerField,
+        Self::Katal,
+        Self::Unit,
+        Self::InternationalUnit,
+        Self::InternationalUnitSecondary,
+        Self::ArbitraryUnit,
+        Self::UnitedStatesPharmacopeiaUnit,
+        Self::GplUnit,
+        Self::MplUnit,
+        Self::AplUnit,
+        Self::BethesdaUnit,
+        Self::AntiFactorXaUnit,
+        Self::ToddUnit,
+        Self::DyeUnit,
+        Self::SomogyiUnit,
+        Self::BodanskyUnit,
+        Self::KingArmstrongUnit,
+        Self::KunkelUnit,
+        Self::MacLaganUnit,
+        Self::TuberculinUnit,
+        Self::CellCultureInfectiousDose,
+        Self::TissueCultureInfectiousDose,
+        Self::EmbryoInfectiousDose,
+        Self::PlaqueFormingUnits,
+        Self::FocusFormingUnits,
+        Self::ColonyFormingUnits,
+        Self::IndexOfReactivity,
+        Self::BioequivalentAllergenUnit,
+        Self::AllergenUnit,
+        Self::AllergenUnitForAmbrosiaArtemisiifolia,
+        Self::ProteinNitrogenUnit,
+        Self::LimitOfFlocculation,
+        Self::DAntigenUnit,
+        Self::FibrinogenEquivalentUnit,
+        Self::ElisaUnit,
+        Self::EhrlichUnit,
+        Self::Neper,
+        Self::Bel,
+        Self::BelSoundPressure,
+        Self::BelVolt,
+        Self::BelMillivolt,
+        Self::BelMicrovolt,
+        Self::Bel10Nanovolt,
+        Self::BelWatt,
+        Self::BelKilowatt,
+        Self::Stere,
+        Self::Angstrom,
+        Self::Barn,
+        Self::TechnicalAtmosphere,
+        Self::Mho,
+        Self::PoundPerSquareInch,
+        Self::Circle,
+        Self::Spere,
+        Self::MetricCarat,
+        Self::CaratOfGoldAlloys,
+        Self::Smoot,
+        Self::MeterPerSquareSecondsPerSquareRootOfHertz,
+        Self::BitLogarithmusDualis,
+        Self::Bit,
+        Self::Byte,
+        Self::Baud,
+    ];
+
     pub(crate) fn definition(self) -> Definition<f64> {
         match self {
             Self::Meter => Definition::Base,
@@ -2601,182 +2916,20 @@ impl UcumSymbol for Atom {
 }
 impl UcumUnit for Atom {
     fn scalar(&self) -> f64 {
-        self.reduce_value(num_traits::One::one())
+        self.entry()
+            .scalar
+            .unwrap_or_else(|| self.reduce_value(num_traits::One::one()))
     }
     fn magnitude(&self) -> f64 {
         self.calculate_magnitude(self.scalar())
     }
     fn is_arbitrary(&self) -> bool {
-        matches!(
-            *self,
-            Self::AllergenUnit
-                | Self::AllergenUnitForAmbrosiaArtemisiifolia
-                | Self::AntiFactorXaUnit
-                | Self::AplUnit
-                | Self::ArbitraryUnit
-                | Self::BethesdaUnit
-                | Self::BioequivalentAllergenUnit
-                | Self::BodanskyUnit
-                | Self::CellCultureInfectiousDose
-                | Self::ColonyFormingUnits
-                | Self::DAntigenUnit
-                | Self::DyeUnit
-                | Self::EhrlichUnit
-                | Self::ElisaUnit
-                | Self::EmbryoInfectiousDose
-                | Self::FibrinogenEquivalentUnit
-                | Self::FocusFormingUnits
-                | Self::GplUnit
-                | Self::HomeopathicPotencyOfCentesimalHahnemannianSeries
-                | Self::HomeopathicPotencyOfCentesimalKorsakovianSeries
-                | Self::HomeopathicPotencyOfDecimalHahnemannianSeries
-                | Self::HomeopathicPotencyOfDecimalKorsakovianSeries
-                | Self::HomeopathicPotencyOfMillesimalHahnemannianSeries
-                | Self::HomeopathicPotencyOfMillesimalKorsakovianSeries
-                | Self::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries
-                | Self::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries
-                | Self::IndexOfReactivity
-                | Self::InternationalUnit
-                | Self::InternationalUnitSecondary
-                | Self::KingArmstrongUnit
-                | Self::KunkelUnit
-                | Self::LimitOfFlocculation
-                | Self::MacLaganUnit
-                | Self::MplUnit
-                | Self::PlaqueFormingUnits
-                | Self::ProteinNitrogenUnit
-                | Self::SomogyiUnit
-                | Self::TissueCultureInfectiousDose
-                | Self::ToddUnit
-                | Self::TuberculinUnit
-                | Self::UnitedStatesPharmacopeiaUnit
-        )
+        self.entry().is_arbitrary
     }
     fn is_special(&self) -> bool {
-        matches!(
-            *self,
-            Self::Bel
-                | Self::Bel10Nanovolt
-                | Self::BelKilowatt
-                | Self::BelMicrovolt
-                | Self::BelMillivolt
-                | Self::BelSoundPressure
-                | Self::BelVolt
-                | Self::BelWatt
-                | Self::BitLogarithmusDualis
-                | Self::DegreeCelsius
-                | Self::DegreeFahrenheit
-                | Self::DegreeReaumur
-                | Self::HomeopathicPotencyOfCentesimalSeriesRetired
-                | Self::HomeopathicPotencyOfDecimalSeriesRetired
-                | Self::HomeopathicPotencyOfMillesimalSeriesRetired
-                | Self::HomeopathicPotencyOfQuintamillesimalSeriesRetired
-                | Self::MeterPerSquareSecondsPerSquareRootOfHertz
-                | Self::Neper
-                | Self::PH
-                | Self::PercentOfSlope
-                | Self::PrismDiopter
-        )
+        self.entry().is_special
     }
     fn is_metric(&self) -> bool {
-        matches!(
-            *self,
-            Self::Ampere
-                | Self::Are
-                | Self::Bar
-                | Self::Baud
-                | Self::Becquerel
-                | Self::Bel
-                | Self::Bel10Nanovolt
-                | Self::BelKilowatt
-                | Self::BelMicrovolt
-                | Self::BelMillivolt
-                | Self::BelSoundPressure
-                | Self::BelVolt
-                | Self::BelWatt
-                | Self::Biot
-                | Self::Bit
-                | Self::BoltzmannConstant
-                | Self::Byte
-                | Self::Calorie
-                | Self::CalorieAt15C
-                | Self::CalorieAt20C
-                | Self::Candela
-                | Self::Coulomb
-                | Self::Curie
-                | Self::DegreeCelsius
-                | Self::Dyne
-                | Self::ElectronMass
-                | Self::Electronvolt
-                | Self::ElementaryCharge
-                | Self::Equivalents
-                | Self::Erg
-                | Self::Farad
-                | Self::Gal
-                | Self::Gauss
-                | Self::Gilbert
-                | Self::Gram
-                | Self::GramForce
-                | Self::GramPercent
-                | Self::Gray
-                | Self::Henry
-                | Self::Hertz
-                | Self::InternationalTableCalorie
-                | Self::InternationalUnit
-                | Self::InternationalUnitSecondary
-                | Self::Joule
-                | Self::Katal
-                | Self::Kayser
-                | Self::Kelvin
-                | Self::Lambert
-                | Self::LightYear
-                | Self::Liter
-                | Self::LiterSecondary
-                | Self::Lumen
-                | Self::Lux
-                | Self::Maxwell
-                | Self::MeanCalorie
-                | Self::Meter
-                | Self::MeterOfMercuryColumn
-                | Self::MeterOfWaterColumn
-                | Self::Mho
-                | Self::Mole
-                | Self::Neper
-                | Self::Newton
-                | Self::NewtonianConstantOfGravitation
-                | Self::Oersted
-                | Self::Ohm
-                | Self::Osmole
-                | Self::Parsec
-                | Self::Pascal
-                | Self::PermeabilityOfVacuum
-                | Self::PermittivityOfVacuum
-                | Self::Phot
-                | Self::PlanckConstant
-                | Self::Poise
-                | Self::ProtonMass
-                | Self::Radian
-                | Self::RadiationAbsorbedDose
-                | Self::RadiationEquivalentMan
-                | Self::Roentgen
-                | Self::Second
-                | Self::Siemens
-                | Self::Sievert
-                | Self::StandardAccelerationOfFreeFall
-                | Self::Steradian
-                | Self::Stere
-                | Self::Stilb
-                | Self::Stokes
-                | Self::Tesla
-                | Self::Tex
-                | Self::ThermochemicalCalorie
-                | Self::Tonne
-                | Self::UnifiedAtomicMassUnit
-                | Self::Unit
-                | Self::VelocityOfLight
-                | Self::Volt
-                | Self::Watt
-                | Self::Weber
-        )
+        self.entry().is_metric
     }
 }
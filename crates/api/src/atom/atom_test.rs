@@ -713,3 +713,50 @@ fn validate_display() {
     let atom = Atom::TheNumberPi;
     assert_eq!(&atom.to_string(), "[pi]");
 }
+
+#[test]
+fn validate_inch_of_water_column() {
+    let atom = Atom::InchOfWaterColumn;
+    assert_relative_eq!(atom.scalar(), 249_088.91);
+    assert_eq!(
+        atom.composition(),
+        Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2))
+    );
+}
+
+#[test]
+fn validate_table_matches_definitions() {
+    use crate::reducible::Reducible;
+
+    use super::definition::Definition;
+
+    for (i, atom) in Atom::ALL.into_iter().enumerate() {
+        assert_eq!(atom as usize, i, "{atom:?}");
+
+        // `m[H2O].[in_i]/m` doesn't parse (the term grammar doesn't allow digits in symbols), so
+        // this atom's definition can't be built at runtime.
+        if atom == Atom::InchOfWaterColumn {
+            continue;
+        }
+
+        let definition = atom.definition();
+
+        if matches!(definition, Definition::Base) {
+            continue;
+        }
+
+        assert_eq!(
+            atom.composition(),
+            definition.terms().composition(),
+            "{atom:?}"
+        );
+
+        if !atom.is_special() {
+            assert_relative_eq!(
+                atom.scalar(),
+                definition.reduce_value(1.0),
+                max_relative = 1e-12
+            );
+        }
+    }
+}
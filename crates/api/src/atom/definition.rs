@@ -3,7 +3,7 @@
 
 pub(super) mod consts;
 
-use std::{borrow::Cow, str::FromStr};
+use std::borrow::Cow;
 
 use num_traits::One;
 
@@ -33,14 +33,21 @@ pub(crate) enum Definition<V> {
     },
 }
 
+/// Parses a definition's unit expression directly, instead of through `Unit::from_str()`, so that
+/// definitions don't fill (or count against) the user's `unit::cache`.
+///
+fn parse_definition(expression: &'static str) -> Cow<'static, [Term]> {
+    crate::unit::parser::parse(expression).map_or_else(
+        |_| unreachable!("expected valid unit definition string: {expression}"),
+        Cow::Owned,
+    )
+}
+
 impl<V> Definition<V> {
     pub(crate) fn new_value_terms(value: V, expression: &'static str) -> Self {
         Self::ValueTerms {
             value,
-            terms: crate::Unit::from_str(expression).map_or_else(
-                |_| unreachable!("expected valid unit definition string: {expression}"),
-                crate::Unit::into_terms,
-            ),
+            terms: parse_definition(expression),
         }
     }
 
@@ -51,10 +58,7 @@ impl<V> Definition<V> {
     ) -> Self {
         Self::ValueTermsSpecial {
             value,
-            terms: crate::Unit::from_str(expression).map_or_else(
-                |_| unreachable!("expected valid unit definition string: {expression}"),
-                crate::Unit::into_terms,
-            ),
+            terms: parse_definition(expression),
             function_set,
         }
     }
@@ -72,6 +76,14 @@ impl<V> Definition<V> {
         }
     }
 
+    pub(crate) const fn function_set(&self) -> Option<&FunctionSet<V>> {
+        match self {
+            Self::ValueSpecial { function_set, .. }
+            | Self::ValueTermsSpecial { function_set, .. } => Some(function_set),
+            Self::Base | Self::Value(_) | Self::ValueTerms { .. } => None,
+        }
+    }
+
     pub(crate) const fn terms(&self) -> &Cow<'static, [Term]> {
         match self {
             Self::Value(_) | Self::ValueSpecial { .. } | Self::Base => {
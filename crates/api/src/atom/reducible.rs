@@ -2,7 +2,9 @@ use crate::{reducible::Reducible, Atom, UcumUnit};
 
 impl Reducible<f64> for Atom {
     fn reduce_value(&self, value: f64) -> f64 {
-        self.definition().reduce_value(value)
+        self.entry()
+            .scalar
+            .unwrap_or_else(|| self.definition().reduce_value(value))
     }
 
     fn calculate_magnitude(&self, value: f64) -> f64 {
@@ -0,0 +1,2195 @@
+//-----------------------------------------------------------------------------
+// DO NOT EDIT THIS FILE!
+// This is generated by wise_units-atom_generator.
+//-----------------------------------------------------------------------------
+
+#![allow(clippy::approx_constant, clippy::unreadable_literal)]
+use super::Atom;
+use crate::Composition;
+/// An `Atom`'s definition, reduced to base units.
+pub(super) struct AtomEntry {
+    /// `None` for special atoms, whose scalars depend on the value being converted.
+    pub(super) scalar: Option<f64>,
+    pub(super) composition: Composition,
+    pub(super) is_arbitrary: bool,
+    pub(super) is_metric: bool,
+    pub(super) is_special: bool,
+}
+/// Every `Atom`'s entry, in the order they're defined in `Atoms.toml`.
+pub(super) static ATOMS: [AtomEntry; 310] = [
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, Some(1), None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, Some(1), None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(Some(1), None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, Some(1), None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(10.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(10.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3.141592653589793),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.01),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.001),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1e-6),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1e-9),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1e-12),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6.0221367e23),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, Some(2), None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(None, Some(1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-3)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(Some(1), None, None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.001),
+        composition: Composition::new_any(Some(2), Some(-2), None, Some(-1), None, None, Some(2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(Some(-2), Some(2), None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.001),
+        composition: Composition::new_any(Some(2), Some(-2), None, Some(-1), None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, Some(1), None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(Some(-1), None, None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000.0),
+        composition: Composition::new_any(Some(-2), Some(2), None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, Some(1), None, Some(2), None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, Some(-2), Some(1), None, Some(2), None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.015707963267948967),
+        composition: Composition::new_any(None, None, None, None, Some(1), None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.017453292519943295),
+        composition: Composition::new_any(None, None, None, None, Some(1), None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0002908882086657216),
+        composition: Composition::new_any(None, None, None, None, Some(1), None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4.84813681109536e-6),
+        composition: Composition::new_any(None, None, None, None, Some(1), None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0010000000000000002),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0010000000000000002),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(100.0),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(60.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3600.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(86400.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(31556925.216),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(31557600.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(31556952.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(31557600.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(604800.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2551442.976),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2629800.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2629746.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2629800.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1000000.0),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(100000000.0),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.6605402e-24),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.60217733e-16),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(149597870691.0),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3.085678e16),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(299792458.0),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6.6260755e-31),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.380658e-20),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, Some(-1), Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(8.854187817000001e-15),
+        composition: Composition::new_any(Some(2), Some(-3), None, Some(-1), None, None, Some(2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0012566370614359172),
+        composition: Composition::new_any(Some(-2), Some(1), None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.60217733e-19),
+        composition: Composition::new_any(Some(1), None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(9.1093897e-28),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.6726231e-24),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6.67259e-14),
+        composition: Composition::new_any(None, Some(3), None, Some(-1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(9.80665),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(101325000.0),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(9460730472580800.0),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(9.80665),
+        composition: Composition::new_any(None, Some(1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4448.2216152605),
+        composition: Composition::new_any(None, Some(1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(100.0),
+        composition: Composition::new_any(None, Some(-1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.01),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.01),
+        composition: Composition::new_any(None, Some(1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0001),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(100.0),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(10.0),
+        composition: Composition::new_any(Some(1), None, None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0001),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1e-5),
+        composition: Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.1),
+        composition: Composition::new_any(Some(-1), None, None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(79.57747154594767),
+        composition: Composition::new_any(Some(-1), Some(1), None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.7957747154594768),
+        composition: Composition::new_any(Some(-1), Some(2), None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(10000.0),
+        composition: Composition::new_any(None, Some(-2), Some(1), None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(31415.926535897932),
+        composition: Composition::new_any(None, Some(-2), Some(1), None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0001),
+        composition: Composition::new_any(None, Some(-2), Some(1), None, Some(2), None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(37000000000.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2.58e-7),
+        composition: Composition::new_any(Some(1), None, None, Some(-1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.01),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.01),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.025400000000000002),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.3048),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.9144000000000001),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1609.344),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.8288000000000002),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1852.0),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.5144444444444445),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0006451600000000001),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.09290304),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.8361273600000002),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.6387064000000003e-5),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.028316846592000004),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.7645548579840002),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.002359737216),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3.6245563637760005),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2.5400000000000004e-5),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1217369588.0052204),
+        composition: Composition::new_any(None, Some(-2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.10160000000000001),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.3048006096012192),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.9144018288036575),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.025400050800101596),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(5.029210058420117),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(20.116840233680467),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.20116840233680466),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(30.480060960121918),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.3048006096012192),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.828803657607315),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(201.16840233680466),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1609.3472186944373),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4046.872609874252),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(25.292953811714074),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2589998.470319521),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2589998.470319521),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(93239944.93150276),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2.5400050800101597e-5),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.025399980000000003),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.30479976000000003),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(5.02919604),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(20.11678416),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.2011678416),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.82879856),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.7619994000000001),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.91439928),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1609.3427328000002),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1853.1825408000002),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.5147729280000001),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4046.850049400269),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0037854117840000006),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.158987294928),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0009463529460000001),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.00047317647300000007),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.00011829411825000002),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2.9573529562500005e-5),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3.6966911953125006e-6),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6.1611519921875e-8),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3.6245563637760005),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.03523907016688001),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.004404883770860001),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.008809767541720002),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0011012209427150002),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0005506104713575001),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.4786764781250002e-5),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4.9289215937500005e-6),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.00023658823650000004),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3.0000000000000004e-5),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.00024000000000000003),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(5.000000000000001e-6),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.5000000000000002e-5),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.004546090000000002),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.009092180000000004),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.036368720000000014),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0011365225000000004),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0005682612500000002),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.00014206531250000006),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(2.841306250000001e-5),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3.5516328125000015e-6),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(5.9193880208333354e-8),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.06479891),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(453.59237),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(28.349523125),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.7718451953125),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(45359.237),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(50802.345440000005),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(907184.74),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1016046.9088000001),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6350.293180000001),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.5551738400000001),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(31.103476800000003),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(373.2417216),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.2959782),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3.8879346),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(31.1034768),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(373.2417216),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(28.0),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.002116666666666667),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0003527777777777778),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.004233333333333334),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.00035145980000000004),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.004217517600000001),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.3248),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.027066666666666662),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.002255555555555555),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0003759259259259258),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.00451111111111111),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, Some(1), None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: Some(0.5555555555555556),
+        composition: Composition::new_any(None, None, None, None, None, Some(1), None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, Some(1), None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: Some(4185.8),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4181.9),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4190.0199999999995),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4186.8),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4184.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4184.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(4184000.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1059670.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1054800.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1054680.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1055870.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1055055.85262),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1054350.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1054350.0),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(745699.8715822703),
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-3)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.001),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(111.1111111111111),
+        composition: Composition::new_any(None, Some(1), None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(9806650.0),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(133322000.0),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(249088.91000000003),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(3386378.8000000003),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(133321999999.99998),
+        composition: Composition::new_any(None, Some(-4), None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(7999319999.999998),
+        composition: Composition::new_any(None, Some(-4), None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, Some(-1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, Some(1), None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, Some(1), None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: Some(39.370078740157474),
+        composition: Composition::new_any(None, Some(-1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.0003333333333333333),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(5.000000000000001e-8),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(5.8333333333333346e-5),
+        composition: Composition::new_any(None, Some(3), None, Some(1), None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6.0221367e23),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6.0221367e23),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, Some(-3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: Some(9999.999999999996),
+        composition: Composition::new_any(None, Some(-3), None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1e-13),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(100.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6.0221367e23),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.00368945e16),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: true,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(Some(-1), Some(2), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-3)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, Some(2), None, Some(1), None, None, Some(-3)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, Some(3), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0000000000000002e-10),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0000000000000001e-28),
+        composition: Composition::new_any(None, Some(2), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(98066500.0),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.001),
+        composition: Composition::new_any(Some(2), Some(-2), None, Some(-1), None, None, Some(1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6894757.293168359),
+        composition: Composition::new_any(None, Some(-1), None, Some(1), None, None, Some(-2)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(6.283185307179586),
+        composition: Composition::new_any(None, None, None, None, Some(1), None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(12.566370614359172),
+        composition: Composition::new_any(None, None, None, None, Some(2), None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.2),
+        composition: Composition::new_any(None, None, None, Some(1), None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(0.041666666666666664),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.7018000000000002),
+        composition: Composition::new_any(None, Some(1), None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, Some(2), None, None, None, None, Some(-5)),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: None,
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: false,
+        is_special: true,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(8.0),
+        composition: Composition::new_any(None, None, None, None, None, None, None),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+    AtomEntry {
+        scalar: Some(1.0),
+        composition: Composition::new_any(None, None, None, None, None, None, Some(-1)),
+        is_arbitrary: false,
+        is_metric: true,
+        is_special: false,
+    },
+];
+impl Atom {
+    pub(super) fn entry(self) -> &'static AtomEntry {
+        &ATOMS[self as usize]
+    }
+}
@@ -12,6 +12,18 @@ pub enum Error {
     #[error(transparent)]
     ParsingFailed(#[from] ParserError),
 
+    #[error("Unable to parse measurement value: {0:?}")]
+    InvalidMeasurementValue(String),
+
+    #[error("Invalid unit alias: {alias:?} => {expression:?}")]
+    InvalidAlias { alias: String, expression: String },
+
+    #[error("Unable to read unit alias table: {0}")]
+    InvalidAliasTable(String),
+
     #[error("Operation caused a divide by 0")]
     DivideByZero,
+
+    #[error("Value is out of range for its type: {0}")]
+    ValueOutOfRange(String),
 }
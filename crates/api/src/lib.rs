@@ -32,6 +32,8 @@ pub mod invert;
 pub mod is_compatible_with;
 pub mod measurement;
 pub mod property;
+#[cfg(feature = "rational")]
+pub mod rational;
 pub mod reduce;
 pub mod term;
 pub mod unit;
@@ -1,26 +1,36 @@
+mod approx_eq;
 mod composable;
 mod convert;
 mod convertible;
 mod display;
 mod field_eq;
+mod from_str;
 mod invert;
 mod is_compatible_with;
+mod locale;
+#[cfg(feature = "cffi")]
+pub mod measurement_ffi;
 mod num_traits;
 mod ops;
 mod partial_eq;
 mod partial_ord;
+mod range;
 mod reducible;
 mod to_reduced;
 mod ucum_unit;
+mod uncertain;
 #[cfg(feature = "v2")]
 mod v2;
+mod value;
 
-use crate::{reducible::Reducible, ucum_unit::UcumUnit, unit::Unit};
-
-use ::num_traits::One;
+pub use self::{
+    locale::{Locale, LocalizedDisplay},
+    range::MeasurementRange,
+    uncertain::{UncertainMeasurement, Uncertainty},
+    value::Value,
+};
 
-#[cfg(feature = "cffi")]
-use ffi_common::derive::FFI;
+use crate::{unit::Unit, Error};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -40,11 +50,22 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(in_meters.value(), 1000.0);
 /// ```
 ///
+/// The value is an `f64` unless otherwise specified; see `Value` for the other supported types.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wise_units::{Convertible, Measurement, Unit};
+///
+/// let one_km = Measurement::new(1.0_f32, Unit::from_str("km").unwrap());
+/// let in_meters = one_km.convert_to("m").unwrap();
+///
+/// assert_eq!(in_meters.value(), 1000.0_f32);
+/// ```
+///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "cffi", derive(FFI))]
 #[derive(Clone, Debug)]
-pub struct Measurement {
-    value: f64,
+pub struct Measurement<V = f64> {
+    value: V,
     unit: Unit,
 }
 
@@ -69,6 +90,18 @@ impl Measurement {
         })
     }
 
+    /// Intended for comparing `Unit`s or `Measurements`, when the order of the `Term`s in a `Unit`
+    /// don't hold order (they're not sorted) after some operations. This allows tests to sort them
+    /// and thus tests to have valid expectations.
+    ///
+    #[cfg(test)]
+    pub(crate) fn sort_terms(&mut self) -> &mut Self {
+        let _ = self.unit.sort_terms();
+        self
+    }
+}
+
+impl<V> Measurement<V> {
     /// Standard constructor.
     ///
     /// ```
@@ -81,7 +114,7 @@ impl Measurement {
     /// ```
     ///
     #[must_use]
-    pub const fn new(value: f64, unit: Unit) -> Self {
+    pub const fn new(value: V, unit: Unit) -> Self {
         Self { value, unit }
     }
 
@@ -89,7 +122,10 @@ impl Measurement {
     ///
     #[must_use]
     #[inline]
-    pub const fn value(&self) -> f64 {
+    pub const fn value(&self) -> V
+    where
+        V: Copy,
+    {
         self.value
     }
 
@@ -100,35 +136,26 @@ impl Measurement {
     pub const fn unit(&self) -> &Unit {
         &self.unit
     }
+}
 
+impl<V: Value> Measurement<V> {
     /// The value of the `Measurement` in terms of `other_unit`. Only used for
     /// converting, and does not check the compatibility of units.
     ///
-    fn converted_scalar(&self, other_unit: &Unit) -> f64 {
-        if self.is_special() && other_unit.is_special() {
-            let ts = self.unit.reduce_value(self.value);
-            other_unit.calculate_magnitude(ts)
-        } else if self.is_special() {
-            self.unit.reduce_value(self.value)
-        } else if other_unit.is_special() {
-            other_unit.calculate_magnitude(self.value)
-        } else {
-            self.scalar() / other_unit.reduce_value(One::one())
-        }
+    fn try_converted_scalar(&self, other_unit: &Unit) -> Result<V, Error> {
+        self.value.convert(&self.unit, other_unit)
     }
+}
 
-    /// Intended for comparing `Unit`s or `Measurements`, when the order of the `Term`s in a `Unit`
-    /// don't hold order (they're not sorted) after some operations. This allows tests to sort them
-    /// and thus tests to have valid expectations.
+impl Measurement {
+    /// `try_converted_scalar()` for `f64`s, which can't fail.
     ///
-    #[cfg(test)]
-    pub(crate) fn sort_terms(&mut self) -> &mut Self {
-        let _ = self.unit.sort_terms();
-        self
+    fn converted_scalar(&self, other_unit: &Unit) -> f64 {
+        value::convert_f64(self.value, &self.unit, other_unit)
     }
 }
 
-impl AsRef<Self> for Measurement {
+impl<V> AsRef<Self> for Measurement<V> {
     fn as_ref(&self) -> &Self {
         self
     }
@@ -182,36 +209,42 @@ mod tests {
         // No special units
         let m = Measurement::try_new(1.0, "m").unwrap();
         let unit = Unit::from_str("m").unwrap();
-        assert_relative_eq!(m.converted_scalar(&unit), 1.0);
-        assert_ulps_eq!(m.converted_scalar(&unit), 1.0);
+        assert_relative_eq!(m.try_converted_scalar(&unit).unwrap(), 1.0);
+        assert_ulps_eq!(m.try_converted_scalar(&unit).unwrap(), 1.0);
 
         let m = Measurement::try_new(1.0, "m").unwrap();
         let unit = Unit::from_str("km").unwrap();
-        assert_relative_eq!(m.converted_scalar(&unit), 0.001);
-        assert_ulps_eq!(m.converted_scalar(&unit), 0.001);
+        assert_relative_eq!(m.try_converted_scalar(&unit).unwrap(), 0.001);
+        assert_ulps_eq!(m.try_converted_scalar(&unit).unwrap(), 0.001);
 
         let m = Measurement::try_new(1000.0, "m").unwrap();
         let unit = Unit::from_str("km").unwrap();
-        assert_relative_eq!(m.converted_scalar(&unit), 1.0);
-        assert_ulps_eq!(m.converted_scalar(&unit), 1.0);
+        assert_relative_eq!(m.try_converted_scalar(&unit).unwrap(), 1.0);
+        assert_ulps_eq!(m.try_converted_scalar(&unit).unwrap(), 1.0);
 
         // Measurement unit is not special, but other_unit is
         let m = Measurement::try_new(1.0, "K").unwrap();
         let unit = Unit::from_str("Cel").unwrap();
-        assert_relative_eq!(m.converted_scalar(&unit), -272.15);
-        assert_ulps_eq!(m.converted_scalar(&unit), -272.15);
+        assert_relative_eq!(m.try_converted_scalar(&unit).unwrap(), -272.15);
+        assert_ulps_eq!(m.try_converted_scalar(&unit).unwrap(), -272.15);
 
         // Measurement unit is special, but other_unit is not
         let m = Measurement::try_new(1.0, "Cel").unwrap();
         let unit = Unit::from_str("K").unwrap();
-        assert_relative_eq!(m.converted_scalar(&unit), 274.15);
-        assert_ulps_eq!(m.converted_scalar(&unit), 274.15);
+        assert_relative_eq!(m.try_converted_scalar(&unit).unwrap(), 274.15);
+        assert_ulps_eq!(m.try_converted_scalar(&unit).unwrap(), 274.15);
 
         // Measurement unit and other_unit are special
         let m = Measurement::try_new(1.0, "Cel").unwrap();
         let unit = Unit::from_str("[degF]").unwrap();
-        assert_relative_eq!(m.converted_scalar(&unit), 33.799_999_999_999_955);
-        assert_ulps_eq!(m.converted_scalar(&unit), 33.799_999_999_999_955);
+        assert_relative_eq!(
+            m.try_converted_scalar(&unit).unwrap(),
+            33.799_999_999_999_955
+        );
+        assert_ulps_eq!(
+            m.try_converted_scalar(&unit).unwrap(),
+            33.799_999_999_999_955
+        );
     }
 
     #[cfg(feature = "serde")]
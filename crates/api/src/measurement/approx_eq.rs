@@ -0,0 +1,203 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{is_compatible_with::IsCompatibleWith, measurement::Measurement, ucum_unit::UcumUnit};
+
+impl Measurement {
+    /// `true` if `self` and `other` are compatible and differ by no more than `tolerance` (ex.
+    /// "within 0.5 kg"), which can be in any unit compatible with them. A tolerance in a special
+    /// unit is taken as a difference, so `0.5 Cel` is `0.5 K`.
+    ///
+    /// ```rust
+    /// use wise_units::{Convertible, Measurement};
+    ///
+    /// let reading = Measurement::try_new(12.3, "kg").unwrap();
+    /// let expected = Measurement::try_new(27.0, "[lb_av]").unwrap();
+    ///
+    /// let tolerance = Measurement::try_new(0.5, "kg").unwrap();
+    /// assert!(reading.approx_eq(&expected, &tolerance));
+    ///
+    /// let tolerance = Measurement::try_new(10.0, "g").unwrap();
+    /// assert!(!reading.approx_eq(&expected, &tolerance));
+    /// ```
+    ///
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: &Self) -> bool {
+        if !self.unit.is_compatible_with(&other.unit)
+            || !self.unit.is_compatible_with(&tolerance.unit)
+        {
+            return false;
+        }
+
+        let tolerance_zero = Self::new(0.0, tolerance.unit.clone());
+        let tolerance = (tolerance.scalar() - tolerance_zero.scalar()).abs();
+
+        (self.scalar() - other.scalar()).abs() <= tolerance
+    }
+
+    /// `other`'s value in terms of `self`'s unit, if they're compatible.
+    ///
+    fn comparable_value(&self, other: &Self) -> Option<f64> {
+        self.unit
+            .is_compatible_with(&other.unit)
+            .then(|| other.converted_scalar(&self.unit))
+    }
+}
+
+//-----------------------------------------------------------------------------
+// impl AbsDiffEq, RelativeEq, UlpsEq
+//-----------------------------------------------------------------------------
+/// `Measurement`s are approximately equal if their `Unit`s are compatible and, once `other` is
+/// converted to `self`'s unit, their values are (approximately) equal. Epsilons are in terms of
+/// `self`'s unit.
+///
+/// ```rust
+/// use approx::{abs_diff_eq, assert_relative_eq};
+/// use wise_units::{Convertible, Measurement};
+///
+/// let measurement = Measurement::try_new(12.3, "kg").unwrap();
+/// let round_tripped = measurement
+///     .convert_to("[lb_av]")
+///     .and_then(|m| m.convert_to("[oz_av]"))
+///     .and_then(|m| m.convert_to("kg"))
+///     .unwrap();
+/// assert_relative_eq!(measurement, round_tripped);
+///
+/// let other = Measurement::try_new(12_301.0, "g").unwrap();
+/// assert!(abs_diff_eq!(measurement, other, epsilon = 0.01));
+/// assert!(!abs_diff_eq!(measurement, other, epsilon = 0.000_1));
+/// ```
+///
+impl AbsDiffEq for Measurement {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        matches!(
+            self.comparable_value(other),
+            Some(value) if self.value.abs_diff_eq(&value, epsilon)
+        )
+    }
+}
+
+impl RelativeEq for Measurement {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        matches!(
+            self.comparable_value(other),
+            Some(value) if self.value.relative_eq(&value, epsilon, max_relative)
+        )
+    }
+}
+
+impl UlpsEq for Measurement {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        matches!(
+            self.comparable_value(other),
+            Some(value) if self.value.ulps_eq(&value, epsilon, max_ulps)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{abs_diff_eq, relative_eq, ulps_eq};
+
+    use crate::Convertible;
+
+    use super::*;
+
+    fn measurement(value: f64, unit: &str) -> Measurement {
+        Measurement::try_new(value, unit).unwrap()
+    }
+
+    #[test]
+    fn validate_approx_eq() {
+        let subject = measurement(20.0, "Cel");
+        assert!(subject.approx_eq(&measurement(293.4, "K"), &measurement(0.5, "K")));
+        assert!(subject.approx_eq(&measurement(293.4, "K"), &measurement(0.5, "Cel")));
+        assert!(subject.approx_eq(&measurement(68.5, "[degF]"), &measurement(0.5, "Cel")));
+        assert!(!subject.approx_eq(&measurement(68.5, "[degF]"), &measurement(0.2, "Cel")));
+
+        let subject = measurement(1.0, "km");
+        assert!(subject.approx_eq(&measurement(999.0, "m"), &measurement(-1.0, "m")));
+        assert!(!subject.approx_eq(&measurement(998.0, "m"), &measurement(1.0, "m")));
+        assert!(!subject.approx_eq(&measurement(1.0, "km"), &measurement(1.0, "s")));
+        assert!(!subject.approx_eq(&measurement(1.0, "s"), &measurement(1.0, "m")));
+    }
+
+    #[test]
+    fn validate_abs_diff_eq() {
+        let subject = measurement(1.0, "km");
+
+        assert!(abs_diff_eq!(subject, measurement(1000.0, "m")));
+        assert!(abs_diff_eq!(
+            subject,
+            measurement(999.0, "m"),
+            epsilon = 0.002
+        ));
+        assert!(!abs_diff_eq!(
+            subject,
+            measurement(999.0, "m"),
+            epsilon = 0.000_5
+        ));
+        assert!(!abs_diff_eq!(subject, measurement(1.0, "s"), epsilon = 1.0));
+    }
+
+    #[test]
+    fn validate_relative_eq() {
+        let subject = measurement(12.3, "kg");
+        let round_tripped = subject
+            .convert_to("[lb_av]")
+            .and_then(|m| m.convert_to("[oz_av]"))
+            .and_then(|m| m.convert_to("kg"))
+            .unwrap();
+
+        assert!(relative_eq!(subject, round_tripped));
+        assert!(relative_eq!(
+            subject,
+            measurement(27.0, "[lb_av]"),
+            max_relative = 0.01
+        ));
+        assert!(!relative_eq!(subject, measurement(27.0, "[lb_av]")));
+        assert!(!relative_eq!(
+            subject,
+            measurement(12.3, "m"),
+            max_relative = 1.0
+        ));
+
+        let subject = measurement(20.0, "Cel");
+        assert!(relative_eq!(
+            subject,
+            measurement(68.0, "[degF]"),
+            max_relative = 1.0e-12
+        ));
+    }
+
+    #[test]
+    fn validate_ulps_eq() {
+        let subject = measurement(1.0, "km");
+
+        assert!(ulps_eq!(subject, measurement(1000.0, "m")));
+        assert!(!ulps_eq!(subject, measurement(1001.0, "m")));
+        assert!(!ulps_eq!(
+            subject,
+            measurement(1.0, "ks"),
+            max_ulps = u32::MAX
+        ));
+    }
+}
@@ -3,7 +3,7 @@ use crate::{Composable, Composition, Measurement};
 //-----------------------------------------------------------------------------
 // impl Composable
 //-----------------------------------------------------------------------------
-impl Composable for Measurement {
+impl<V> Composable for Measurement<V> {
     #[inline]
     fn composition(&self) -> Composition {
         self.unit.composition()
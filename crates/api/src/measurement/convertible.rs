@@ -1,50 +1,65 @@
 #![allow(clippy::large_enum_variant)]
 #![allow(clippy::result_large_err)]
 
-use crate::{Convertible, Error, IsCompatibleWith, Measurement, Unit};
+use crate::{measurement::Value, Convertible, Error, IsCompatibleWith, Measurement, Unit};
 use std::str::FromStr;
 
-/// This implementation of `Convertible` lets you pass in a `&str` for the
-/// `Unit`, which will parse the chars and convert accordingly. If `expression`
-/// is invalid, you'll get an `Error`. If `self`'s `Unit` and `other_unit` are
-/// incompatible, you'll get an `Error`.
+/// Implements `Convertible<&str>` for a concrete `Measurement` type; it's not generic over `Value`
+/// so the `f64` implementation can be exposed over FFI.
 ///
-#[cfg_attr(feature = "cffi", ffi_common::derive::expose_impl)]
-impl<'a> Convertible<&'a str> for Measurement {
-    type Output = Self;
-    type ConversionError = Error;
-
-    #[inline]
-    fn convert_to(&self, expression: &'a str) -> Result<Self, Self::ConversionError> {
-        let dest_unit = Unit::from_str(expression)?;
-
-        if self.is_compatible_with(&dest_unit) {
-            Ok(Self {
-                value: self.converted_scalar(&dest_unit),
-                unit: dest_unit,
-            })
-        } else {
-            Err(Error::IncompatibleUnitTypes {
-                lhs: self.unit.expression(),
-                rhs: expression.to_string(),
-            })
+macro_rules! impl_convertible_str {
+    ($(#[$meta:meta])* $measurement:ty) => {
+        /// This implementation of `Convertible` lets you pass in a `&str` for the
+        /// `Unit`, which will parse the chars and convert accordingly. If `expression`
+        /// is invalid, you'll get an `Error`. If `self`'s `Unit` and `other_unit` are
+        /// incompatible, or the converted value is out of range, you'll get an `Error`.
+        ///
+        $(#[$meta])*
+        impl<'a> Convertible<&'a str> for $measurement {
+            type Output = Self;
+            type ConversionError = Error;
+
+            #[inline]
+            fn convert_to(&self, expression: &'a str) -> Result<Self, Self::ConversionError> {
+                let dest_unit = Unit::from_str(expression)?;
+
+                if self.unit.is_compatible_with(&dest_unit) {
+                    Ok(Self {
+                        value: self.try_converted_scalar(&dest_unit)?,
+                        unit: dest_unit,
+                    })
+                } else {
+                    Err(Error::IncompatibleUnitTypes {
+                        lhs: self.unit.expression(),
+                        rhs: expression.to_string(),
+                    })
+                }
+            }
         }
-    }
+    };
 }
 
+impl_convertible_str!(
+    #[cfg_attr(feature = "cffi", ffi_common::derive::expose_impl)]
+    Measurement
+);
+impl_convertible_str!(Measurement<f32>);
+#[cfg(feature = "decimal")]
+impl_convertible_str!(Measurement<rust_decimal::Decimal>);
+
 /// This implementation of `Convertible` skips any string parsing and gets
 /// right to converting to `other_unit`. If `self`'s `Unit` and `other_unit`
-/// are incompatible, you'll get an `Error`.
+/// are incompatible, or the converted value is out of range for `V`, you'll get an `Error`.
 ///
-impl<'a> Convertible<&'a Unit> for Measurement {
+impl<'a, V: Value> Convertible<&'a Unit> for Measurement<V> {
     type Output = Self;
     type ConversionError = Error;
 
     #[inline]
     fn convert_to(&self, rhs: &'a Unit) -> Result<Self, Self::ConversionError> {
-        if self.is_compatible_with(rhs) {
+        if self.unit.is_compatible_with(rhs) {
             Ok(Self {
-                value: self.converted_scalar(rhs),
+                value: self.try_converted_scalar(rhs)?,
                 unit: rhs.clone(),
             })
         } else {
@@ -166,4 +181,33 @@ mod tests {
 
         assert!(m1.convert_to(&u2).is_err());
     }
+
+    #[test]
+    fn validate_convert_to_f32() {
+        let meter = Measurement::new(1000.0_f32, METER);
+
+        let converted = meter.convert_to("km").unwrap();
+        assert_ulps_eq!(converted.value, 1.0);
+
+        let converted = meter.convert_to(&KILOMETER).unwrap();
+        assert_ulps_eq!(converted.value, 1.0);
+
+        assert!(meter.convert_to("kg").is_err());
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn validate_convert_to_decimal() {
+        use rust_decimal::Decimal;
+
+        let pounds = Measurement::new(Decimal::from(100), Unit::from_str("[lb_av]").unwrap());
+        let converted = pounds.convert_to("kg").unwrap();
+        assert_eq!(converted.value, Decimal::from_str("45.359237").unwrap());
+
+        let converted = converted.convert_to("[lb_av]").unwrap();
+        assert_eq!(converted.value, Decimal::from(100));
+
+        let ounces = pounds.convert_to("[oz_av]").unwrap();
+        assert_eq!(ounces.value, Decimal::from(1600));
+    }
 }
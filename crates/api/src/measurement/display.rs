@@ -4,7 +4,7 @@ use std::fmt;
 //-----------------------------------------------------------------------------
 // impl Display
 //-----------------------------------------------------------------------------
-impl fmt::Display for Measurement {
+impl<V: fmt::Display> fmt::Display for Measurement<V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} {}", self.value, self.unit)
     }
@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use crate::{Error, Measurement, Unit};
+
+//-----------------------------------------------------------------------------
+// impl FromStr
+//-----------------------------------------------------------------------------
+/// Parses the form that `Display` outputs: a value, optional whitespace, then a unit expression
+/// (ex. `"12.5 kg/har"`, `"-1.5e3m"`). When there's no whitespace, the value is the longest
+/// leading decimal number (with optional sign and exponent); unit expressions that start with a
+/// digit (ex. `10*3/uL`) must be separated from the value by whitespace. The value must be finite
+/// (so not `NaN` or `inf`).
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use wise_units::{Error, Measurement};
+///
+/// let measurement = Measurement::from_str("12.5 kg/har").unwrap();
+/// assert_eq!(measurement, Measurement::try_new(12.5, "kg/har").unwrap());
+/// assert_eq!(measurement.to_string().parse::<Measurement>().unwrap(), measurement);
+///
+/// assert!(matches!(
+///     Measurement::from_str("twelve kg"),
+///     Err(Error::InvalidMeasurementValue(_))
+/// ));
+/// assert!(matches!(
+///     Measurement::from_str("12 kgg"),
+///     Err(Error::ParsingFailed(_))
+/// ));
+/// ```
+///
+impl FromStr for Measurement {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let expression = expression.trim();
+
+        let (value, unit) = expression.find(char::is_whitespace).map_or_else(
+            || expression.split_at(number_length(expression)),
+            |index| (&expression[..index], expression[index..].trim_start()),
+        );
+
+        let value = f64::from_str(value)
+            .ok()
+            .filter(|number| number.is_finite())
+            .ok_or_else(|| Error::InvalidMeasurementValue(value.to_string()))?;
+
+        Ok(Self::new(value, Unit::from_str(unit)?))
+    }
+}
+
+/// The length of the decimal number at the start of `expression`: `[+-]digits[.digits][(e|E)[+-]digits]`.
+///
+pub(super) fn number_length(expression: &str) -> usize {
+    let bytes = expression.as_bytes();
+    let digits_from = |start: usize| {
+        bytes[start.min(bytes.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut length = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    length += digits_from(length);
+
+    if bytes.get(length) == Some(&b'.') {
+        length += 1 + digits_from(length + 1);
+    }
+
+    if matches!(bytes.get(length), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(length + 1), Some(b'+' | b'-')));
+        let exponent_digits = digits_from(length + 1 + sign);
+
+        // Otherwise the "e" is the start of the unit (ex. "2eV").
+        if exponent_digits > 0 {
+            length += 1 + sign + exponent_digits;
+        }
+    }
+
+    length
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_ulps_eq;
+
+    use crate::unit::DiagnosticKind;
+
+    use super::*;
+
+    macro_rules! validate_from_str {
+        ($test_name:ident, $input:expr, $value:expr, $unit:expr) => {
+            #[test]
+            fn $test_name() {
+                let measurement = Measurement::from_str($input).unwrap();
+
+                assert_ulps_eq!(measurement.value(), $value);
+                assert_eq!(measurement.unit().expression(), $unit);
+            }
+        };
+    }
+
+    validate_from_str!(validate_space, "12.5 kg/har", 12.5, "kg/har");
+    validate_from_str!(validate_no_space, "12.5kg/har", 12.5, "kg/har");
+    validate_from_str!(
+        validate_extra_whitespace,
+        "  12.5 \t kg/har ",
+        12.5,
+        "kg/har"
+    );
+    validate_from_str!(validate_integer, "3 m", 3.0, "m");
+    validate_from_str!(validate_negative, "-3.5 Cel", -3.5, "Cel");
+    validate_from_str!(validate_positive, "+3.5m", 3.5, "m");
+    validate_from_str!(validate_leading_dot, ".5 m", 0.5, "m");
+    validate_from_str!(validate_scientific, "1.5e3 m", 1500.0, "m");
+    validate_from_str!(validate_scientific_no_space, "1.5E-3m", 0.0015, "m");
+    validate_from_str!(validate_unit_starting_with_e, "2eV", 2.0, "eV");
+    validate_from_str!(
+        validate_unit_starting_with_digits,
+        "2 10*3/uL",
+        2.0,
+        "10*3/uL"
+    );
+    validate_from_str!(validate_unity, "2 1", 2.0, "1");
+    validate_from_str!(validate_annotation, "2 {tbl}", 2.0, "{tbl}");
+
+    #[test]
+    fn validate_round_trip() {
+        for measurement in [
+            Measurement::try_new(12.5, "kg/har").unwrap(),
+            Measurement::try_new(-0.001, "[lb_av]/[acr_us]").unwrap(),
+            Measurement::try_new(1e21, "10*3/uL").unwrap(),
+            Measurement::try_new(42.0, "1").unwrap(),
+        ] {
+            let parsed = Measurement::from_str(&measurement.to_string()).unwrap();
+
+            assert_ulps_eq!(parsed.value(), measurement.value());
+            assert_eq!(parsed.unit().expression(), measurement.unit().expression());
+        }
+    }
+
+    #[test]
+    fn validate_value_error() {
+        assert_eq!(
+            Measurement::from_str("twelve kg").unwrap_err(),
+            Error::InvalidMeasurementValue("twelve".to_string())
+        );
+        assert_eq!(
+            Measurement::from_str("kg").unwrap_err(),
+            Error::InvalidMeasurementValue(String::new())
+        );
+        assert_eq!(
+            Measurement::from_str("1.2.3 kg").unwrap_err(),
+            Error::InvalidMeasurementValue("1.2.3".to_string())
+        );
+
+        for value in ["NaN", "inf", "-infinity", "1e999"] {
+            assert_eq!(
+                Measurement::from_str(&format!("{value} kg")).unwrap_err(),
+                Error::InvalidMeasurementValue(value.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn validate_unit_error() {
+        let diagnostic_kind = |input| match Measurement::from_str(input) {
+            Err(Error::ParsingFailed(error)) => error.diagnostic().unwrap().kind,
+            other => panic!("expected a unit error, got {other:?}"),
+        };
+
+        assert_eq!(diagnostic_kind("12 kgg"), DiagnosticKind::UnknownSymbol);
+        assert_eq!(diagnostic_kind("12"), DiagnosticKind::EmptyExpression);
+        assert_eq!(diagnostic_kind("12 kg/"), DiagnosticKind::DanglingOperator);
+    }
+}
@@ -1,20 +1,34 @@
 use crate::{is_compatible_with::IsCompatibleWith, measurement::Measurement, unit::Unit};
 
-impl IsCompatibleWith for Measurement {
+impl<V> IsCompatibleWith for Measurement<V> {
     #[inline]
     fn is_compatible_with(&self, rhs: &Self) -> bool {
-        self.is_compatible_with(&rhs.unit)
+        self.unit.is_compatible_with(&rhs.unit)
     }
 }
 
-#[cfg_attr(feature = "cffi", ffi_common::derive::expose_impl)]
-impl IsCompatibleWith<Unit> for Measurement {
-    #[inline]
-    fn is_compatible_with(&self, rhs: &Unit) -> bool {
-        self.unit.is_compatible_with(rhs)
-    }
+/// Not generic over `Value`, so the `f64` implementation can be exposed over FFI.
+///
+macro_rules! impl_is_compatible_with_unit {
+    ($(#[$meta:meta])* $measurement:ty) => {
+        $(#[$meta])*
+        impl IsCompatibleWith<Unit> for $measurement {
+            #[inline]
+            fn is_compatible_with(&self, rhs: &Unit) -> bool {
+                self.unit.is_compatible_with(rhs)
+            }
+        }
+    };
 }
 
+impl_is_compatible_with_unit!(
+    #[cfg_attr(feature = "cffi", ffi_common::derive::expose_impl)]
+    Measurement
+);
+impl_is_compatible_with_unit!(Measurement<f32>);
+#[cfg(feature = "decimal")]
+impl_is_compatible_with_unit!(Measurement<rust_decimal::Decimal>);
+
 #[cfg(test)]
 mod tests {
     use crate::{is_compatible_with::IsCompatibleWith, measurement::Measurement, unit::Unit};
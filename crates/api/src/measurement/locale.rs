@@ -0,0 +1,471 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Error, Measurement, Unit};
+
+/// Describes how a `Measurement`'s value is written in a given locale: the decimal separator, the
+/// separator between groups of thousands, and the separator between the value and the unit.
+///
+/// ```rust
+/// use wise_units::{measurement::Locale, Measurement};
+///
+/// let measurement = Measurement::try_new(1234.5, "kg").unwrap();
+///
+/// assert_eq!(measurement.display_localized(&Locale::DE_DE).to_string(), "1.234,5 kg");
+/// assert_eq!(
+///     Measurement::from_str_localized("1.234,5 kg", &Locale::DE_DE).unwrap(),
+///     measurement
+/// );
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_field_names)]
+pub struct Locale {
+    decimal_separator: char,
+    group_separator: Option<char>,
+    unit_separator: char,
+}
+
+impl Locale {
+    /// `1,234.5 kg`
+    pub const EN_US: Self = Self::new('.').group_separator(Some(','));
+
+    /// `1,234.5 kg`
+    pub const EN_CA: Self = Self::EN_US;
+
+    /// `1 234,5 kg`, using no-break spaces.
+    pub const FR_CA: Self = Self::new(',')
+        .group_separator(Some('\u{a0}'))
+        .unit_separator('\u{a0}');
+
+    /// `1 234,5 kg`, using a narrow no-break space between groups and a no-break space before the
+    /// unit.
+    pub const FR_FR: Self = Self::new(',')
+        .group_separator(Some('\u{202f}'))
+        .unit_separator('\u{a0}');
+
+    /// `1.234,5 kg`
+    pub const DE_DE: Self = Self::new(',').group_separator(Some('.'));
+
+    /// `1.234,5 kg`
+    pub const PT_BR: Self = Self::new(',').group_separator(Some('.'));
+
+    /// `1.234,5 kg`
+    pub const ES_ES: Self = Self::new(',').group_separator(Some('.'));
+
+    /// A locale that uses `decimal_separator`, doesn't group thousands, and separates the value
+    /// from the unit with a space.
+    ///
+    #[must_use]
+    pub const fn new(decimal_separator: char) -> Self {
+        Self {
+            decimal_separator,
+            group_separator: None,
+            unit_separator: ' ',
+        }
+    }
+
+    /// Set the separator between groups of thousands, if any.
+    ///
+    #[must_use]
+    pub const fn group_separator(self, group_separator: Option<char>) -> Self {
+        Self {
+            group_separator,
+            ..self
+        }
+    }
+
+    /// Set the separator between the value and the unit.
+    ///
+    #[must_use]
+    pub const fn unit_separator(self, unit_separator: char) -> Self {
+        Self {
+            unit_separator,
+            ..self
+        }
+    }
+
+    /// Looks up one of the predefined locales by its language tag (ex. `"de-DE"`, `"pt_BR"`,
+    /// `"fr"`), ignoring case.
+    ///
+    #[must_use]
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let locale = match tag.to_ascii_lowercase().replace('_', "-").as_str() {
+            "en" | "en-us" => Self::EN_US,
+            "en-ca" => Self::EN_CA,
+            "fr-ca" => Self::FR_CA,
+            "fr" | "fr-fr" => Self::FR_FR,
+            "de" | "de-de" | "de-at" => Self::DE_DE,
+            "pt-br" => Self::PT_BR,
+            "es" | "es-es" => Self::ES_ES,
+            _ => return None,
+        };
+
+        Some(locale)
+    }
+
+    /// Rewrites `number`, as output by `f64`'s `Display`, for this locale.
+    ///
+    fn localize(&self, number: &str) -> String {
+        let (sign, unsigned) = number
+            .strip_prefix('-')
+            .map_or(("", number), |unsigned| ("-", unsigned));
+
+        if !unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+            // NaN, inf
+            return number.to_string();
+        }
+
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let mut output = String::with_capacity(number.len() + integer.len() / 3 + 1);
+        output.push_str(sign);
+
+        for (index, digit) in integer.chars().enumerate() {
+            if let Some(group_separator) = self.group_separator {
+                if index > 0 && (integer.len() - index) % 3 == 0 {
+                    output.push(group_separator);
+                }
+            }
+
+            output.push(digit);
+        }
+
+        if !fraction.is_empty() {
+            output.push(self.decimal_separator);
+            output.push_str(fraction);
+        }
+
+        output
+    }
+
+    /// Spaces are hard to tell apart, so if the group separator is one, any of them is accepted.
+    ///
+    const fn is_group_separator(&self, c: char) -> bool {
+        match self.group_separator {
+            Some(group_separator) if is_space(group_separator) => is_space(c),
+            Some(group_separator) => c == group_separator,
+            None => false,
+        }
+    }
+
+    /// The byte length of the localized number at the start of `expression`.
+    ///
+    fn number_length(&self, expression: &str) -> usize {
+        let chars: Vec<(usize, char)> = expression.char_indices().collect();
+        let is_digit_at =
+            |index: usize| matches!(chars.get(index), Some((_, c)) if c.is_ascii_digit());
+        let mut index = usize::from(matches!(chars.first(), Some((_, '+' | '-'))));
+
+        while let Some(&(_, c)) = chars.get(index) {
+            if c.is_ascii_digit() || c == self.decimal_separator {
+                index += 1;
+            } else if self.is_group_separator(c) {
+                // A space is only a group separator if it's followed by exactly 3 digits; otherwise
+                // it separates the value from a unit (ex. "2 10*3/uL").
+                let is_group = if is_space(c) {
+                    (1..=3).all(|offset| is_digit_at(index + offset)) && !is_digit_at(index + 4)
+                } else {
+                    is_digit_at(index + 1)
+                };
+
+                if !is_group {
+                    break;
+                }
+
+                index += 1;
+            } else if matches!(c, 'e' | 'E') {
+                let sign = usize::from(matches!(chars.get(index + 1), Some((_, '+' | '-'))));
+
+                // Otherwise the "e" is the start of the unit (ex. "2eV").
+                if !is_digit_at(index + 1 + sign) {
+                    break;
+                }
+
+                index += 1 + sign;
+            } else {
+                break;
+            }
+        }
+
+        chars.get(index).map_or(expression.len(), |&(byte, _)| byte)
+    }
+
+    /// Parses `number`, checking that any group separators are in the right places.
+    ///
+    fn parse(&self, number: &str) -> Option<f64> {
+        let (integer, fraction) = number
+            .split_once(self.decimal_separator)
+            .map_or((number, None), |(integer, fraction)| {
+                (integer, Some(fraction))
+            });
+
+        let groups: Vec<&str> = integer.split(|c| self.is_group_separator(c)).collect();
+
+        if let Some((first, rest)) = groups.split_first() {
+            let first_digits = first.trim_start_matches(['+', '-']).len();
+
+            if !rest.is_empty()
+                && (!(1..=3).contains(&first_digits) || rest.iter().any(|group| group.len() != 3))
+            {
+                return None;
+            }
+        }
+
+        let mut normalized = groups.concat();
+
+        if let Some(fraction) = fraction {
+            normalized.push('.');
+            normalized.push_str(fraction);
+        }
+
+        f64::from_str(&normalized)
+            .ok()
+            .filter(|number| number.is_finite())
+    }
+}
+
+const fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\u{a0}' | '\u{202f}')
+}
+
+impl Measurement {
+    /// Displays `self` with its value formatted for `locale`. Precision can be set as usual (ex.
+    /// `format!("{:.2}", measurement.display_localized(&Locale::FR_FR))`).
+    ///
+    #[must_use]
+    pub const fn display_localized<'a>(&'a self, locale: &'a Locale) -> LocalizedDisplay<'a> {
+        LocalizedDisplay {
+            measurement: self,
+            locale,
+        }
+    }
+
+    /// Parses a measurement whose value is formatted for `locale` (ex. `"1.234,5 kg"` for
+    /// `Locale::DE_DE`). Like `Measurement::from_str()`, whitespace between the value and the
+    /// unit is optional, and scientific notation is accepted. When `locale` uses spaces to group
+    /// thousands, any kind of space is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidMeasurementValue` if the value isn't a valid, finite number for
+    /// `locale`, or `Error::ParsingFailed` if the unit is invalid.
+    ///
+    #[allow(clippy::result_large_err)]
+    pub fn from_str_localized(expression: &str, locale: &Locale) -> Result<Self, Error> {
+        let expression = expression.trim();
+        let (value, unit) = expression.split_at(locale.number_length(expression));
+
+        let value = locale
+            .parse(value)
+            .ok_or_else(|| Error::InvalidMeasurementValue(value.to_string()))?;
+
+        Ok(Self::new(value, Unit::from_str(unit.trim_start())?))
+    }
+}
+
+/// Helper for displaying a `Measurement` for a `Locale`; see `Measurement::display_localized()`.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct LocalizedDisplay<'a> {
+    measurement: &'a Measurement,
+    locale: &'a Locale,
+}
+
+impl fmt::Display for LocalizedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = f.precision().map_or_else(
+            || self.measurement.value.to_string(),
+            |precision| format!("{:.*}", precision, self.measurement.value),
+        );
+
+        write!(
+            f,
+            "{}{}{}",
+            self.locale.localize(&value),
+            self.locale.unit_separator,
+            self.measurement.unit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_ulps_eq;
+
+    use super::*;
+
+    macro_rules! validate_display {
+        ($test_name:ident, $value:expr, $locale:expr, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                let measurement = Measurement::try_new($value, "kg").unwrap();
+                let output = measurement.display_localized(&$locale).to_string();
+
+                assert_eq!(output, $expected);
+            }
+        };
+    }
+
+    validate_display!(validate_display_en_us, 1234.5, Locale::EN_US, "1,234.5 kg");
+    validate_display!(validate_display_de_de, 1234.5, Locale::DE_DE, "1.234,5 kg");
+    validate_display!(validate_display_pt_br, 1234.5, Locale::PT_BR, "1.234,5 kg");
+    validate_display!(
+        validate_display_fr_fr,
+        1234.5,
+        Locale::FR_FR,
+        "1\u{202f}234,5\u{a0}kg"
+    );
+    validate_display!(
+        validate_display_fr_ca,
+        1234.5,
+        Locale::FR_CA,
+        "1\u{a0}234,5\u{a0}kg"
+    );
+    validate_display!(validate_display_small, 12.5, Locale::DE_DE, "12,5 kg");
+    validate_display!(
+        validate_display_integer,
+        1_000_000.0,
+        Locale::DE_DE,
+        "1.000.000 kg"
+    );
+    validate_display!(
+        validate_display_negative,
+        -123_456.0,
+        Locale::EN_US,
+        "-123,456 kg"
+    );
+    validate_display!(
+        validate_display_ungrouped,
+        1234.5,
+        Locale::new(','),
+        "1234,5 kg"
+    );
+    validate_display!(validate_display_nan, f64::NAN, Locale::DE_DE, "NaN kg");
+
+    #[test]
+    fn validate_display_precision() {
+        let measurement = Measurement::try_new(1234.567, "kg").unwrap();
+
+        assert_eq!(
+            format!("{:.2}", measurement.display_localized(&Locale::DE_DE)),
+            "1.234,57 kg"
+        );
+    }
+
+    macro_rules! validate_parse {
+        ($test_name:ident, $input:expr, $locale:expr, $value:expr, $unit:expr) => {
+            #[test]
+            fn $test_name() {
+                let measurement = Measurement::from_str_localized($input, &$locale).unwrap();
+
+                assert_ulps_eq!(measurement.value(), $value);
+                assert_eq!(measurement.unit().expression(), $unit);
+            }
+        };
+    }
+
+    validate_parse!(
+        validate_parse_en_us,
+        "1,234.5 kg",
+        Locale::EN_US,
+        1234.5,
+        "kg"
+    );
+    validate_parse!(
+        validate_parse_de_de,
+        "1.234,5 kg",
+        Locale::DE_DE,
+        1234.5,
+        "kg"
+    );
+    validate_parse!(
+        validate_parse_de_de_no_groups,
+        "1234,5kg",
+        Locale::DE_DE,
+        1234.5,
+        "kg"
+    );
+    validate_parse!(
+        validate_parse_fr_fr,
+        "1\u{202f}234,5\u{a0}kg",
+        Locale::FR_FR,
+        1234.5,
+        "kg"
+    );
+    validate_parse!(
+        validate_parse_fr_fr_plain_spaces,
+        "1 234,5 kg",
+        Locale::FR_FR,
+        1234.5,
+        "kg"
+    );
+    validate_parse!(
+        validate_parse_fr_fr_many_groups,
+        "-1 234 567 kg",
+        Locale::FR_FR,
+        -1_234_567.0,
+        "kg"
+    );
+    validate_parse!(
+        validate_parse_fr_fr_digit_unit,
+        "2 10*3/uL",
+        Locale::FR_FR,
+        2.0,
+        "10*3/uL"
+    );
+    validate_parse!(
+        validate_parse_scientific,
+        "1,5e3 m",
+        Locale::DE_DE,
+        1500.0,
+        "m"
+    );
+    validate_parse!(validate_parse_e_unit, "2,5eV", Locale::DE_DE, 2.5, "eV");
+
+    #[test]
+    fn validate_parse_errors() {
+        assert_eq!(
+            Measurement::from_str_localized("1.5 kg", &Locale::DE_DE).unwrap_err(),
+            Error::InvalidMeasurementValue("1.5".to_string())
+        );
+        assert_eq!(
+            Measurement::from_str_localized("12.34.567 kg", &Locale::DE_DE).unwrap_err(),
+            Error::InvalidMeasurementValue("12.34.567".to_string())
+        );
+        assert_eq!(
+            Measurement::from_str_localized("1,2,3 kg", &Locale::DE_DE).unwrap_err(),
+            Error::InvalidMeasurementValue("1,2,3".to_string())
+        );
+        assert_eq!(
+            Measurement::from_str_localized("1e999 kg", &Locale::EN_US).unwrap_err(),
+            Error::InvalidMeasurementValue("1e999".to_string())
+        );
+        assert!(matches!(
+            Measurement::from_str_localized("1,5 kgg", &Locale::DE_DE),
+            Err(Error::ParsingFailed(_))
+        ));
+    }
+
+    #[test]
+    fn validate_round_trip() {
+        for locale in [Locale::EN_US, Locale::FR_FR, Locale::FR_CA, Locale::DE_DE] {
+            for value in [0.0, 0.25, -12.5, 1234.5, 9_876_543.21] {
+                let measurement = Measurement::try_new(value, "kg/har").unwrap();
+                let output = measurement.display_localized(&locale).to_string();
+
+                assert_eq!(
+                    Measurement::from_str_localized(&output, &locale).unwrap(),
+                    measurement,
+                    "{output}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn validate_from_tag() {
+        assert_eq!(Locale::from_tag("de-DE"), Some(Locale::DE_DE));
+        assert_eq!(Locale::from_tag("pt_br"), Some(Locale::PT_BR));
+        assert_eq!(Locale::from_tag("FR"), Some(Locale::FR_FR));
+        assert_eq!(Locale::from_tag("xx-YY"), None);
+    }
+}
@@ -0,0 +1,57 @@
+//!
+//! FFI for (`f64`) `Measurement`s. This is written out instead of derived, since `Measurement` is
+//! generic over its `Value`, and only the `f64` version is exposed.
+//!
+
+use crate::{Measurement, Unit};
+
+/// Initializes a `Measurement` with `value` and the `Unit` behind `unit`, taking ownership of
+/// the `Unit`.
+///
+/// # Safety
+///
+/// `unit` is dereferenced and freed, so make sure it's not null, and don't use it again!
+///
+#[no_mangle]
+pub unsafe extern "C" fn measurement_rust_ffi_init(
+    value: f64,
+    unit: *mut Unit,
+) -> *const Measurement {
+    let unit = *Box::from_raw(unit);
+
+    Box::into_raw(Box::new(Measurement::new(value, unit)))
+}
+
+/// Returns ownership of the `Measurement` behind `ptr` to Rust, to be freed.
+///
+/// # Safety
+///
+/// `ptr` is dereferenced, so make sure it's not null!
+///
+#[no_mangle]
+pub unsafe extern "C" fn measurement_rust_ffi_free(ptr: *const Measurement) {
+    drop(Box::from_raw(ptr.cast_mut()));
+}
+
+/// Returns the `value` of the `Measurement` behind `ptr`.
+///
+/// # Safety
+///
+/// `ptr` is dereferenced, so make sure it's not null!
+///
+#[no_mangle]
+#[allow(clippy::missing_const_for_fn)]
+pub unsafe extern "C" fn get_measurement_value(ptr: *const Measurement) -> f64 {
+    (*ptr).value()
+}
+
+/// Clones the `unit` of the `Measurement` behind `ptr` and returns it behind a new raw pointer.
+///
+/// # Safety
+///
+/// `ptr` is dereferenced, so make sure it's not null!
+///
+#[no_mangle]
+pub unsafe extern "C" fn get_measurement_unit(ptr: *const Measurement) -> *const Unit {
+    Box::into_raw(Box::new((*ptr).unit().clone()))
+}
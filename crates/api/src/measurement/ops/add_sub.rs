@@ -3,17 +3,24 @@
 //!
 use std::ops::{Add, Sub};
 
-use crate::{convertible::Convertible, error::Error, measurement::Measurement};
+use crate::{
+    convertible::Convertible,
+    error::Error,
+    measurement::{Measurement, Value},
+};
 
 //-----------------------------------------------------------------------------
 // impl Add
 //-----------------------------------------------------------------------------
-#[cfg_attr(
-    feature = "cffi",
-    ffi_common::derive::expose_fn(extend_type(Measurement))
-)]
+#[cfg(feature = "cffi")]
+#[ffi_common::derive::expose_fn(extend_type(Measurement))]
 #[allow(clippy::result_large_err)]
 fn add_measurements(lhs: &Measurement, rhs: &Measurement) -> Result<Measurement, Error> {
+    try_add(lhs, rhs)
+}
+
+#[allow(clippy::result_large_err)]
+fn try_add<V: Value>(lhs: &Measurement<V>, rhs: &Measurement<V>) -> Result<Measurement<V>, Error> {
     let rhs_converted = rhs.convert_to(&lhs.unit)?;
     let new_value = lhs.value + rhs_converted.value;
 
@@ -23,51 +30,54 @@ fn add_measurements(lhs: &Measurement, rhs: &Measurement) -> Result<Measurement,
     })
 }
 
-impl Add for Measurement {
+impl<V: Value> Add for Measurement<V> {
     type Output = Result<Self, Error>;
 
     #[inline]
     fn add(self, other: Self) -> Self::Output {
-        add_measurements(&self, &other)
+        try_add(&self, &other)
     }
 }
 
-impl<'a> Add<&'a Self> for Measurement {
+impl<'a, V: Value> Add<&'a Self> for Measurement<V> {
     type Output = Result<Self, Error>;
 
     #[inline]
     fn add(self, other: &'a Self) -> Self::Output {
-        add_measurements(&self, other)
+        try_add(&self, other)
     }
 }
 
-impl<'a> Add for &'a Measurement {
-    type Output = Result<Measurement, Error>;
+impl<'a, V: Value> Add for &'a Measurement<V> {
+    type Output = Result<Measurement<V>, Error>;
 
     #[inline]
-    fn add(self, other: &'a Measurement) -> Self::Output {
-        add_measurements(self, other)
+    fn add(self, other: &'a Measurement<V>) -> Self::Output {
+        try_add(self, other)
     }
 }
 
-impl<'a> Add<Measurement> for &'a Measurement {
-    type Output = Result<Measurement, Error>;
+impl<'a, V: Value> Add<Measurement<V>> for &'a Measurement<V> {
+    type Output = Result<Measurement<V>, Error>;
 
     #[inline]
-    fn add(self, other: Measurement) -> Self::Output {
-        add_measurements(self, &other)
+    fn add(self, other: Measurement<V>) -> Self::Output {
+        try_add(self, &other)
     }
 }
 
 //-----------------------------------------------------------------------------
 // impl Sub
 //-----------------------------------------------------------------------------
-#[cfg_attr(
-    feature = "cffi",
-    ffi_common::derive::expose_fn(extend_type(Measurement))
-)]
+#[cfg(feature = "cffi")]
+#[ffi_common::derive::expose_fn(extend_type(Measurement))]
 #[allow(clippy::result_large_err)]
 fn sub_measurements(lhs: &Measurement, rhs: &Measurement) -> Result<Measurement, Error> {
+    try_sub(lhs, rhs)
+}
+
+#[allow(clippy::result_large_err)]
+fn try_sub<V: Value>(lhs: &Measurement<V>, rhs: &Measurement<V>) -> Result<Measurement<V>, Error> {
     let rhs_converted = rhs.convert_to(&lhs.unit)?;
     let new_value = lhs.value - rhs_converted.value;
 
@@ -77,39 +87,39 @@ fn sub_measurements(lhs: &Measurement, rhs: &Measurement) -> Result<Measurement,
     })
 }
 
-impl Sub for Measurement {
+impl<V: Value> Sub for Measurement<V> {
     type Output = Result<Self, Error>;
 
     #[inline]
     fn sub(self, other: Self) -> Self::Output {
-        sub_measurements(&self, &other)
+        try_sub(&self, &other)
     }
 }
 
-impl<'a> Sub<&'a Self> for Measurement {
+impl<'a, V: Value> Sub<&'a Self> for Measurement<V> {
     type Output = Result<Self, Error>;
 
     #[inline]
     fn sub(self, other: &'a Self) -> Self::Output {
-        sub_measurements(&self, other)
+        try_sub(&self, other)
     }
 }
 
-impl<'a> Sub for &'a Measurement {
-    type Output = Result<Measurement, Error>;
+impl<'a, V: Value> Sub for &'a Measurement<V> {
+    type Output = Result<Measurement<V>, Error>;
 
     #[inline]
-    fn sub(self, other: &'a Measurement) -> Self::Output {
-        sub_measurements(self, other)
+    fn sub(self, other: &'a Measurement<V>) -> Self::Output {
+        try_sub(self, other)
     }
 }
 
-impl<'a> Sub<Measurement> for &'a Measurement {
-    type Output = Result<Measurement, Error>;
+impl<'a, V: Value> Sub<Measurement<V>> for &'a Measurement<V> {
+    type Output = Result<Measurement<V>, Error>;
 
     #[inline]
-    fn sub(self, other: Measurement) -> Self::Output {
-        sub_measurements(self, &other)
+    fn sub(self, other: Measurement<V>) -> Self::Output {
+        try_sub(self, &other)
     }
 }
 
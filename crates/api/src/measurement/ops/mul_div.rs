@@ -1,14 +1,15 @@
 use std::ops::{Div, Mul};
 
-use crate::{convertible::Convertible, measurement::Measurement};
+use crate::{
+    convertible::Convertible,
+    measurement::{Measurement, Value},
+};
 
 //          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
 //          ┃                        impl Mul                         ┃
 //          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
-#[cfg_attr(
-    feature = "cffi",
-    ffi_common::derive::expose_fn(extend_type(Measurement))
-)]
+#[cfg(feature = "cffi")]
+#[ffi_common::derive::expose_fn(extend_type(Measurement))]
 fn mul_measurements(lhs: &Measurement, rhs: &Measurement) -> Measurement {
     let converted_rhs = rhs.convert_to(&lhs.unit);
     let actual_rhs = converted_rhs.as_ref().unwrap_or(rhs);
@@ -22,7 +23,7 @@ fn mul_measurements(lhs: &Measurement, rhs: &Measurement) -> Measurement {
 // ╭───────────────╮
 // │ Owned * Owned │
 // ╰───────────────╯
-impl Mul for Measurement {
+impl<V: Value> Mul for Measurement<V> {
     type Output = Self;
 
     #[inline]
@@ -39,7 +40,7 @@ impl Mul for Measurement {
 // ╭──────────────────╮
 // │ Owned * Borrowed │
 // ╰──────────────────╯
-impl<'a> Mul<&'a Self> for Measurement {
+impl<'a, V: Value> Mul<&'a Self> for Measurement<V> {
     type Output = Self;
 
     #[inline]
@@ -57,11 +58,11 @@ impl<'a> Mul<&'a Self> for Measurement {
 // ╭─────────────────────╮
 // │ Borrowed * Borrowed │
 // ╰─────────────────────╯
-impl<'a> Mul for &'a Measurement {
-    type Output = Measurement;
+impl<'a, V: Value> Mul for &'a Measurement<V> {
+    type Output = Measurement<V>;
 
     #[inline]
-    fn mul(self, other: &'a Measurement) -> Self::Output {
+    fn mul(self, other: &'a Measurement<V>) -> Self::Output {
         let converted_rhs = other.convert_to(&self.unit);
         let actual_rhs = converted_rhs.as_ref().unwrap_or(other);
 
@@ -75,11 +76,11 @@ impl<'a> Mul for &'a Measurement {
 // ╭──────────────────╮
 // │ Borrowed * Owned │
 // ╰──────────────────╯
-impl<'a> Mul<Measurement> for &'a Measurement {
-    type Output = Measurement;
+impl<'a, V: Value> Mul<Measurement<V>> for &'a Measurement<V> {
+    type Output = Measurement<V>;
 
     #[inline]
-    fn mul(self, other: Measurement) -> Self::Output {
+    fn mul(self, other: Measurement<V>) -> Self::Output {
         let actual_rhs = other.convert_to(&self.unit).unwrap_or(other);
 
         Measurement {
@@ -89,41 +90,11 @@ impl<'a> Mul<Measurement> for &'a Measurement {
     }
 }
 
-/// Multiplies the `Measurement`'s scalar by `other` and returns a new
-/// `Measurement`.
-///
-impl Mul<f64> for Measurement {
-    type Output = Self;
-
-    #[inline]
-    fn mul(self, other: f64) -> Self::Output {
-        Self {
-            value: self.value * other,
-            unit: self.unit,
-        }
-    }
-}
-
-#[cfg_attr(feature = "cffi", ffi_common::derive::expose_impl)]
-impl<'a> Mul<f64> for &'a Measurement {
-    type Output = Measurement;
-
-    #[inline]
-    fn mul(self, other: f64) -> Self::Output {
-        Measurement {
-            value: self.value * other,
-            unit: self.unit.clone(),
-        }
-    }
-}
-
 //          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
 //          ┃                        impl Div                         ┃
 //          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
-#[cfg_attr(
-    feature = "cffi",
-    ffi_common::derive::expose_fn(extend_type(Measurement))
-)]
+#[cfg(feature = "cffi")]
+#[ffi_common::derive::expose_fn(extend_type(Measurement))]
 fn div_measurements(lhs: &Measurement, rhs: &Measurement) -> Measurement {
     let converted_rhs = rhs.convert_to(&lhs.unit);
     let actual_rhs = converted_rhs.as_ref().unwrap_or(rhs);
@@ -137,7 +108,7 @@ fn div_measurements(lhs: &Measurement, rhs: &Measurement) -> Measurement {
 // ╭───────────────╮
 // │ Owned / Owned │
 // ╰───────────────╯
-impl Div for Measurement {
+impl<V: Value> Div for Measurement<V> {
     type Output = Self;
 
     #[inline]
@@ -154,11 +125,11 @@ impl Div for Measurement {
 // ╭─────────────────────╮
 // │ Borrowed * Borrowed │
 // ╰─────────────────────╯
-impl<'a> Div for &'a Measurement {
-    type Output = Measurement;
+impl<'a, V: Value> Div for &'a Measurement<V> {
+    type Output = Measurement<V>;
 
     #[inline]
-    fn div(self, other: &'a Measurement) -> Self::Output {
+    fn div(self, other: &'a Measurement<V>) -> Self::Output {
         let converted_rhs = other.convert_to(&self.unit);
         let actual_rhs = converted_rhs.as_ref().unwrap_or(other);
 
@@ -172,7 +143,7 @@ impl<'a> Div for &'a Measurement {
 // ╭──────────────────╮
 // │ Owned * Borrowed │
 // ╰──────────────────╯
-impl<'a> Div<&'a Self> for Measurement {
+impl<'a, V: Value> Div<&'a Self> for Measurement<V> {
     type Output = Self;
 
     #[inline]
@@ -190,11 +161,11 @@ impl<'a> Div<&'a Self> for Measurement {
 // ╭──────────────────╮
 // │ Borrowed * Owned │
 // ╰──────────────────╯
-impl<'a> Div<Measurement> for &'a Measurement {
-    type Output = Measurement;
+impl<'a, V: Value> Div<Measurement<V>> for &'a Measurement<V> {
+    type Output = Measurement<V>;
 
     #[inline]
-    fn div(self, other: Measurement) -> Self::Output {
+    fn div(self, other: Measurement<V>) -> Self::Output {
         let actual_rhs = other.convert_to(&self.unit).unwrap_or(other);
 
         Measurement {
@@ -204,34 +175,81 @@ impl<'a> Div<Measurement> for &'a Measurement {
     }
 }
 
-/// Divides the `Measurement`'s scalar by `other` and returns a new
-/// `Measurement`.
+//          ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//          ┃                 impl Mul<V> and Div<V>                  ┃
+//          ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+/// Implements multiplying and dividing a `Measurement<V>` by a `V`. These aren't generic over
+/// `Value` so the `f64` implementations can be exposed over FFI; `$meta` is applied to the
+/// borrowed implementations.
 ///
-impl Div<f64> for Measurement {
-    type Output = Self;
+macro_rules! impl_scalar_mul_div {
+    ($(#[$meta:meta])* $value:ty) => {
+        /// Multiplies the `Measurement`'s scalar by `other` and returns a new
+        /// `Measurement`.
+        ///
+        impl Mul<$value> for Measurement<$value> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: $value) -> Self::Output {
+                Self {
+                    value: self.value * other,
+                    unit: self.unit,
+                }
+            }
+        }
 
-    #[inline]
-    fn div(self, other: f64) -> Self::Output {
-        Self {
-            value: self.value / other,
-            unit: self.unit,
+        $(#[$meta])*
+        impl<'a> Mul<$value> for &'a Measurement<$value> {
+            type Output = Measurement<$value>;
+
+            #[inline]
+            fn mul(self, other: $value) -> Self::Output {
+                Measurement {
+                    value: self.value * other,
+                    unit: self.unit.clone(),
+                }
+            }
         }
-    }
-}
 
-#[cfg_attr(feature = "cffi", ffi_common::derive::expose_impl)]
-impl<'a> Div<f64> for &'a Measurement {
-    type Output = Measurement;
+        /// Divides the `Measurement`'s scalar by `other` and returns a new
+        /// `Measurement`.
+        ///
+        impl Div<$value> for Measurement<$value> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, other: $value) -> Self::Output {
+                Self {
+                    value: self.value / other,
+                    unit: self.unit,
+                }
+            }
+        }
 
-    #[inline]
-    fn div(self, other: f64) -> Self::Output {
-        Measurement {
-            value: self.value / other,
-            unit: self.unit.clone(),
+        $(#[$meta])*
+        impl<'a> Div<$value> for &'a Measurement<$value> {
+            type Output = Measurement<$value>;
+
+            #[inline]
+            fn div(self, other: $value) -> Self::Output {
+                Measurement {
+                    value: self.value / other,
+                    unit: self.unit.clone(),
+                }
+            }
         }
-    }
+    };
 }
 
+impl_scalar_mul_div!(
+    #[cfg_attr(feature = "cffi", ffi_common::derive::expose_impl)]
+    f64
+);
+impl_scalar_mul_div!(f32);
+#[cfg(feature = "decimal")]
+impl_scalar_mul_div!(rust_decimal::Decimal);
+
 #[cfg(test)]
 mod tests {
     use crate::{testing::const_units::l2::METER_SQUARED, unit::UNITY};
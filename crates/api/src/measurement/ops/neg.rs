@@ -1,11 +1,11 @@
 use std::ops::Neg;
 
-use crate::measurement::Measurement;
+use crate::measurement::{Measurement, Value};
 
 // ╭──────────╮
 // │ impl Neg │
 // ╰──────────╯
-impl Neg for Measurement {
+impl<V: Value> Neg for Measurement<V> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -16,8 +16,8 @@ impl Neg for Measurement {
     }
 }
 
-impl<'a> Neg for &'a Measurement {
-    type Output = Measurement;
+impl<'a, V: Value> Neg for &'a Measurement<V> {
+    type Output = Measurement<V>;
 
     fn neg(self) -> Self::Output {
         Measurement {
@@ -27,7 +27,7 @@ impl<'a> Neg for &'a Measurement {
     }
 }
 
-impl<'a> Neg for &'a mut Measurement {
+impl<'a, V: Value> Neg for &'a mut Measurement<V> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
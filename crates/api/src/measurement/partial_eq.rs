@@ -7,6 +7,9 @@ use crate::{is_compatible_with::IsCompatibleWith, measurement::Measurement, ucum
 /// a) their `Unit`s are compatible
 /// b) their `scalar()` values are equal
 ///
+/// For a configurable tolerance, use `Measurement::approx_eq()` or the `approx` traits
+/// (`AbsDiffEq`, `RelativeEq`, `UlpsEq`).
+///
 /// ```rust
 /// use wise_units::Measurement;
 ///
@@ -0,0 +1,605 @@
+#![allow(clippy::result_large_err)]
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::Bound::{self, Excluded, Included, Unbounded},
+    str::FromStr,
+};
+
+use super::from_str::number_length;
+use crate::{Convertible, Error, IsCompatibleWith, Measurement, Unit};
+
+/// A range of values in a single `Unit`, where each bound can be inclusive, exclusive, or
+/// unbounded (ex. a label rate of `1.5–2 [pt_us]/[acr_us]`, or a reference range of `<5 mg/L`).
+///
+/// `Measurement`s are compared against the bounds using `PartialOrd for Measurement`, so they can
+/// be in any compatible unit.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use wise_units::{measurement::MeasurementRange, Measurement};
+///
+/// let range = MeasurementRange::from_str("10–20 kg/har").unwrap();
+/// assert!(range.contains(&Measurement::try_new(15.0, "kg/har").unwrap()));
+/// assert!(range.contains(&Measurement::try_new(1.5, "g/m2").unwrap()));
+/// assert!(!range.contains(&Measurement::try_new(25.0, "kg/har").unwrap()));
+///
+/// let range = MeasurementRange::from_str("<5 mg/L").unwrap();
+/// assert!(range.contains(&Measurement::try_new(4.0, "mg/L").unwrap()));
+/// assert!(!range.contains(&Measurement::try_new(5.0, "mg/L").unwrap()));
+/// assert_eq!(range.to_string(), "<5 mg/L");
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeasurementRange {
+    lower: Bound<f64>,
+    upper: Bound<f64>,
+    unit: Unit,
+}
+
+impl MeasurementRange {
+    #[must_use]
+    pub const fn new(lower: Bound<f64>, upper: Bound<f64>, unit: Unit) -> Self {
+        Self { lower, upper, unit }
+    }
+
+    #[must_use]
+    pub const fn lower(&self) -> Bound<f64> {
+        self.lower
+    }
+
+    #[must_use]
+    pub const fn upper(&self) -> Bound<f64> {
+        self.upper
+    }
+
+    #[must_use]
+    pub const fn unit(&self) -> &Unit {
+        &self.unit
+    }
+
+    /// `true` if no value is within the bounds (ex. `(5, 5) m`, or a lower bound above the upper).
+    ///
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match (self.lower, self.upper) {
+            (Included(lower), Included(upper)) => !matches!(
+                lower.partial_cmp(&upper),
+                Some(Ordering::Less | Ordering::Equal)
+            ),
+            (Included(lower) | Excluded(lower), Excluded(upper))
+            | (Excluded(lower), Included(upper)) => {
+                lower.partial_cmp(&upper) != Some(Ordering::Less)
+            }
+            _ => false,
+        }
+    }
+
+    /// Where the range is relative to `measurement`: `Less` if it's entirely below it, `Greater`
+    /// if it's entirely above it, or `Equal` if it contains it. Returns `None` if the units are
+    /// incompatible or the range is empty.
+    ///
+    /// ```rust
+    /// use std::{cmp::Ordering, str::FromStr};
+    /// use wise_units::{measurement::MeasurementRange, Measurement};
+    ///
+    /// let range = MeasurementRange::from_str("1.5–2 [pt_us]/[acr_us]").unwrap();
+    /// let rate = Measurement::try_new(3.0, "[pt_us]/[acr_us]").unwrap();
+    /// assert_eq!(range.compare(&rate), Some(Ordering::Less));
+    /// ```
+    ///
+    #[must_use]
+    pub fn compare(&self, measurement: &Measurement) -> Option<Ordering> {
+        if self.is_empty() || !self.unit.is_compatible_with(&measurement.unit) {
+            return None;
+        }
+
+        let above_lower = match self.lower {
+            Included(lower) => *measurement >= self.at(lower),
+            Excluded(lower) => *measurement > self.at(lower),
+            Unbounded => true,
+        };
+
+        let below_upper = match self.upper {
+            Included(upper) => *measurement <= self.at(upper),
+            Excluded(upper) => *measurement < self.at(upper),
+            Unbounded => true,
+        };
+
+        match (above_lower, below_upper) {
+            (true, true) => Some(Ordering::Equal),
+            (false, _) => Some(Ordering::Greater),
+            (true, false) => Some(Ordering::Less),
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, measurement: &Measurement) -> bool {
+        self.compare(measurement) == Some(Ordering::Equal)
+    }
+
+    /// `true` if the ranges have any value in common.
+    ///
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The values in both ranges, in `self`'s unit. Returns `None` if the units are incompatible
+    /// or the ranges don't overlap.
+    ///
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let other = other.convert_to(&self.unit).ok()?;
+
+        let intersection = Self {
+            lower: pick(self.lower, other.lower, Ordering::Greater, true),
+            upper: pick(self.upper, other.upper, Ordering::Less, true),
+            unit: self.unit.clone(),
+        };
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// The values in either range, in `self`'s unit. Returns `None` if the units are incompatible
+    /// or there's a gap between the ranges (so their union isn't a range).
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::measurement::MeasurementRange;
+    ///
+    /// let lhs = MeasurementRange::from_str("[1, 2) m").unwrap();
+    /// let rhs = MeasurementRange::from_str("200–300 cm").unwrap();
+    /// assert_eq!(lhs.union(&rhs).unwrap().to_string(), "1–3 m");
+    ///
+    /// let rhs = MeasurementRange::from_str("(2, 3] m").unwrap();
+    /// assert!(lhs.union(&rhs).is_none());
+    /// ```
+    ///
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let other = other.convert_to(&self.unit).ok()?;
+
+        if other.is_empty() {
+            return Some(self.clone());
+        }
+
+        if self.is_empty() {
+            return Some(other);
+        }
+
+        if separated(self.upper, other.lower) || separated(other.upper, self.lower) {
+            return None;
+        }
+
+        Some(Self {
+            lower: pick(self.lower, other.lower, Ordering::Greater, false),
+            upper: pick(self.upper, other.upper, Ordering::Less, false),
+            unit: self.unit.clone(),
+        })
+    }
+
+    fn at(&self, value: f64) -> Measurement {
+        Measurement::new(value, self.unit.clone())
+    }
+}
+
+/// Picks between two lower bounds (when `tighter_order` is `Greater`) or two upper bounds (when
+/// it's `Less`): the one that excludes more values if `tighter`, otherwise the one that excludes
+/// fewer.
+///
+fn pick(lhs: Bound<f64>, rhs: Bound<f64>, tighter_order: Ordering, tighter: bool) -> Bound<f64> {
+    let lhs_is_tighter = match (lhs, rhs) {
+        (Unbounded, _) => false,
+        (_, Unbounded) => true,
+        (Included(l) | Excluded(l), Included(r) | Excluded(r)) => match l.partial_cmp(&r) {
+            Some(Ordering::Equal) | None => matches!(lhs, Excluded(_)),
+            Some(order) => order == tighter_order,
+        },
+    };
+
+    if lhs_is_tighter == tighter {
+        lhs
+    } else {
+        rhs
+    }
+}
+
+/// `true` if there are values between `upper` and a later range's `lower` that neither includes.
+///
+fn separated(upper: Bound<f64>, lower: Bound<f64>) -> bool {
+    match (upper, lower) {
+        (Included(u) | Excluded(u), Included(l) | Excluded(l)) => match u.partial_cmp(&l) {
+            Some(Ordering::Less) => true,
+            Some(Ordering::Equal) => matches!((upper, lower), (Excluded(_), Excluded(_))),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// ╭──────────────────╮
+// │ impl Convertible │
+// ╰──────────────────╯
+impl<'a> Convertible<&'a Unit> for MeasurementRange {
+    type Output = Self;
+    type ConversionError = Error;
+
+    /// Converts both bounds. If the conversion is decreasing (ex. `[pH]` to `mol/l`), the bounds
+    /// swap places.
+    ///
+    fn convert_to(&self, rhs: &'a Unit) -> Result<Self, Self::ConversionError> {
+        if !self.unit.is_compatible_with(rhs) {
+            return Err(Error::IncompatibleUnitTypes {
+                lhs: self.unit.expression(),
+                rhs: rhs.expression(),
+            });
+        }
+
+        let convert = |bound| match bound {
+            Included(value) => Included(self.at(value).converted_scalar(rhs)),
+            Excluded(value) => Excluded(self.at(value).converted_scalar(rhs)),
+            Unbounded => Unbounded,
+        };
+
+        let decreasing = self.at(2.0).converted_scalar(rhs) < self.at(1.0).converted_scalar(rhs);
+
+        let (lower, upper) = if decreasing {
+            (convert(self.upper), convert(self.lower))
+        } else {
+            (convert(self.lower), convert(self.upper))
+        };
+
+        Ok(Self::new(lower, upper, rhs.clone()))
+    }
+}
+
+impl<'a> Convertible<&'a str> for MeasurementRange {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, expression: &'a str) -> Result<Self, Self::ConversionError> {
+        let unit = Unit::from_str(expression)?;
+
+        self.convert_to(&unit)
+    }
+}
+
+// ╭──────────────╮
+// │ impl Display │
+// ╰──────────────╯
+/// Uses the short forms where they apply (ex. `10–20 kg`, `<5 mg/L`, `≥2 m`), and interval
+/// notation otherwise (ex. `(10, 20] kg`, `(-∞, ∞) kg`).
+///
+impl fmt::Display for MeasurementRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = &self.unit;
+
+        match (self.lower, self.upper) {
+            (Included(lower), Included(upper)) => write!(f, "{lower}–{upper} {unit}"),
+            (Unbounded, Excluded(upper)) => write!(f, "<{upper} {unit}"),
+            (Unbounded, Included(upper)) => write!(f, "≤{upper} {unit}"),
+            (Excluded(lower), Unbounded) => write!(f, ">{lower} {unit}"),
+            (Included(lower), Unbounded) => write!(f, "≥{lower} {unit}"),
+            (lower, upper) => {
+                match lower {
+                    Included(lower) => write!(f, "[{lower}, ")?,
+                    Excluded(lower) => write!(f, "({lower}, ")?,
+                    Unbounded => f.write_str("(-∞, ")?,
+                }
+
+                match upper {
+                    Included(upper) => write!(f, "{upper}] {unit}"),
+                    Excluded(upper) => write!(f, "{upper}) {unit}"),
+                    Unbounded => write!(f, "∞) {unit}"),
+                }
+            }
+        }
+    }
+}
+
+// ╭──────────────╮
+// │ impl FromStr │
+// ╰──────────────╯
+/// Parses the forms that `Display` outputs, plus `<=`/`>=` for `≤`/`≥`, `-` for `–`, and a single
+/// value (ex. `5 mg/L`) for a range containing only that value. In interval notation, an infinite
+/// endpoint (`∞`, `inf`) is unbounded.
+///
+/// ```rust
+/// use std::{ops::Bound, str::FromStr};
+/// use wise_units::measurement::MeasurementRange;
+///
+/// let range = MeasurementRange::from_str("1.5-2 [pt_us]/[acr_us]").unwrap();
+/// assert_eq!(range.lower(), Bound::Included(1.5));
+/// assert_eq!(range.upper(), Bound::Included(2.0));
+///
+/// let range = MeasurementRange::from_str(">=0.5 mmol/L").unwrap();
+/// assert_eq!(range.lower(), Bound::Included(0.5));
+/// assert_eq!(range.upper(), Bound::Unbounded);
+///
+/// let range = MeasurementRange::from_str("(0, ∞) Cel").unwrap();
+/// assert_eq!(range.lower(), Bound::Excluded(0.0));
+/// assert_eq!(range.upper(), Bound::Unbounded);
+/// ```
+///
+impl FromStr for MeasurementRange {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let expression = expression.trim();
+
+        // (prefix, inclusive, is the lower bound)
+        let one_sided = [
+            ("<=", true, false),
+            ("≤", true, false),
+            ("<", false, false),
+            (">=", true, true),
+            ("≥", true, true),
+            (">", false, true),
+        ];
+
+        for (prefix, inclusive, lower) in one_sided {
+            if let Some(rest) = expression.strip_prefix(prefix) {
+                let measurement = Measurement::from_str(rest)?;
+                let bound = if inclusive {
+                    Included(measurement.value)
+                } else {
+                    Excluded(measurement.value)
+                };
+
+                return Ok(if lower {
+                    Self::new(bound, Unbounded, measurement.unit)
+                } else {
+                    Self::new(Unbounded, bound, measurement.unit)
+                });
+            }
+        }
+
+        if expression.starts_with(['[', '(']) {
+            return parse_interval(expression);
+        }
+
+        let (lower, rest) = expression.split_at(number_length(expression));
+
+        match rest.trim_start().strip_prefix(['–', '-']) {
+            Some(upper) if !lower.is_empty() => {
+                let upper = Measurement::from_str(upper)?;
+
+                Ok(Self::new(
+                    Included(parse_value(lower)?),
+                    Included(upper.value),
+                    upper.unit,
+                ))
+            }
+            _ => {
+                let measurement = Measurement::from_str(expression)?;
+
+                Ok(Self::new(
+                    Included(measurement.value),
+                    Included(measurement.value),
+                    measurement.unit,
+                ))
+            }
+        }
+    }
+}
+
+/// Parses `[lower, upper] unit`, where either bracket can instead be a parenthesis.
+///
+fn parse_interval(expression: &str) -> Result<MeasurementRange, Error> {
+    let invalid = || Error::InvalidMeasurementValue(expression.to_string());
+
+    let close = expression.find([']', ')']).ok_or_else(invalid)?;
+    let (lower, upper) = expression[1..close].split_once(',').ok_or_else(invalid)?;
+    let unit = Unit::from_str(expression[close + 1..].trim())?;
+
+    let bound = |endpoint: &str, inclusive: bool| {
+        let value = parse_value(&endpoint.trim().replace('∞', "inf"))?;
+
+        Ok::<_, Error>(if value.is_infinite() {
+            Unbounded
+        } else if inclusive {
+            Included(value)
+        } else {
+            Excluded(value)
+        })
+    };
+
+    Ok(MeasurementRange::new(
+        bound(lower, expression.starts_with('['))?,
+        bound(upper, expression[close..].starts_with(']'))?,
+        unit,
+    ))
+}
+
+fn parse_value(value: &str) -> Result<f64, Error> {
+    f64::from_str(value).map_err(|_| Error::InvalidMeasurementValue(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    fn range(expression: &str) -> MeasurementRange {
+        MeasurementRange::from_str(expression).unwrap()
+    }
+
+    fn measurement(value: f64, unit: &str) -> Measurement {
+        Measurement::try_new(value, unit).unwrap()
+    }
+
+    #[test]
+    fn validate_from_str() {
+        let unit = |expression| Unit::from_str(expression).unwrap();
+
+        assert_eq!(
+            range("10–20 kg/har"),
+            MeasurementRange::new(Included(10.0), Included(20.0), unit("kg/har"))
+        );
+        assert_eq!(
+            range(" 10 - 20kg/har "),
+            MeasurementRange::new(Included(10.0), Included(20.0), unit("kg/har"))
+        );
+        assert_eq!(
+            range("-5--2 Cel"),
+            MeasurementRange::new(Included(-5.0), Included(-2.0), unit("Cel"))
+        );
+        assert_eq!(
+            range("<5 mg/L"),
+            MeasurementRange::new(Unbounded, Excluded(5.0), unit("mg/L"))
+        );
+        assert_eq!(
+            range("≤ 5 mg/L"),
+            MeasurementRange::new(Unbounded, Included(5.0), unit("mg/L"))
+        );
+        assert_eq!(
+            range(">1e3 g"),
+            MeasurementRange::new(Excluded(1000.0), Unbounded, unit("g"))
+        );
+        assert_eq!(
+            range("[1, 2) [in_i]"),
+            MeasurementRange::new(Included(1.0), Excluded(2.0), unit("[in_i]"))
+        );
+        assert_eq!(
+            range("(-inf, 2] 10*3/uL"),
+            MeasurementRange::new(Unbounded, Included(2.0), unit("10*3/uL"))
+        );
+        assert_eq!(
+            range("5 mg/L"),
+            MeasurementRange::new(Included(5.0), Included(5.0), unit("mg/L"))
+        );
+
+        assert!(matches!(
+            MeasurementRange::from_str("ten–20 kg"),
+            Err(Error::InvalidMeasurementValue(_))
+        ));
+        assert!(matches!(
+            MeasurementRange::from_str("10–twenty kg"),
+            Err(Error::InvalidMeasurementValue(_))
+        ));
+        assert!(matches!(
+            MeasurementRange::from_str("[1; 2] kg"),
+            Err(Error::InvalidMeasurementValue(_))
+        ));
+        assert!(matches!(
+            MeasurementRange::from_str("<5 kgg"),
+            Err(Error::ParsingFailed(_))
+        ));
+    }
+
+    #[test]
+    fn validate_display() {
+        for expression in [
+            "10–20 kg/har",
+            "<5 mg/L",
+            "≤5 mg/L",
+            ">0.5 mmol/L",
+            "≥0.5 mmol/L",
+            "[1, 2) m",
+            "(1, 2] m",
+            "(1, 2) m",
+            "(-∞, ∞) m",
+        ] {
+            assert_eq!(range(expression).to_string(), expression);
+        }
+
+        assert_eq!(range("[1, inf) m").to_string(), "≥1 m");
+        assert_eq!(range("10-20 kg").to_string(), "10–20 kg");
+    }
+
+    #[test]
+    fn validate_compare() {
+        let subject = range("10–20 kg/har");
+
+        assert_eq!(
+            subject.compare(&measurement(15.0, "kg/har")),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            subject.compare(&measurement(10.0, "kg/har")),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            subject.compare(&measurement(2.5, "g/m2")),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            subject.compare(&measurement(0.5, "g/m2")),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(subject.compare(&measurement(15.0, "kg")), None);
+        assert_eq!(range("(5, 5] m").compare(&measurement(5.0, "m")), None);
+
+        let subject = range("<5 mg/L");
+        assert!(subject.contains(&measurement(4.999, "mg/L")));
+        assert!(subject.contains(&measurement(-1.0, "g/L")));
+        assert!(!subject.contains(&measurement(5.0, "mg/L")));
+        assert!(!subject.contains(&measurement(0.006, "g/L")));
+        assert!(subject.contains(&measurement(4.0, "ug/mL")));
+
+        let subject = range("(0, 100) Cel");
+        assert!(subject.contains(&measurement(300.0, "K")));
+        assert!(!subject.contains(&measurement(273.15, "K")));
+        assert!(!subject.contains(&measurement(213.0, "[degF]")));
+    }
+
+    #[test]
+    fn validate_is_empty() {
+        assert!(!range("5–5 m").is_empty());
+        assert!(range("[5, 5) m").is_empty());
+        assert!(range("(5, 5] m").is_empty());
+        assert!(range("20–10 m").is_empty());
+        assert!(!range("(-∞, ∞) m").is_empty());
+    }
+
+    #[test]
+    fn validate_convert_to() {
+        let subject = range("(1, 2] kg").convert_to("g").unwrap();
+        assert_eq!(subject, range("(1000, 2000] g"));
+
+        let subject = range("≥0 Cel").convert_to("[degF]").unwrap();
+        match subject.lower() {
+            Included(lower) => assert_relative_eq!(lower, 32.0, max_relative = 1.0e-12),
+            bound => panic!("unexpected lower bound: {bound:?}"),
+        }
+        assert_eq!(subject.upper(), Unbounded);
+
+        assert!(matches!(
+            range("1–2 kg").convert_to("m"),
+            Err(Error::IncompatibleUnitTypes { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_intersection() {
+        let lhs = range("[1, 3) m");
+
+        assert_eq!(
+            lhs.intersection(&range("200–400 cm")),
+            Some(range("[2, 3) m"))
+        );
+        assert_eq!(lhs.intersection(&range("(0, 1] m")), Some(range("1–1 m")));
+        assert_eq!(lhs.intersection(&range("≥2 m")), Some(range("[2, 3) m")));
+        assert_eq!(lhs.intersection(&range("(-∞, 5) m")), Some(lhs.clone()));
+        assert_eq!(lhs.intersection(&range("3–4 m")), None);
+        assert_eq!(lhs.intersection(&range("1–2 kg")), None);
+
+        assert!(lhs.overlaps(&range("<1.5 m")));
+        assert!(!lhs.overlaps(&range("<1 m")));
+    }
+
+    #[test]
+    fn validate_union() {
+        let lhs = range("[1, 3) m");
+
+        assert_eq!(lhs.union(&range("200–400 cm")), Some(range("1–4 m")));
+        assert_eq!(lhs.union(&range("3–4 m")), Some(range("1–4 m")));
+        assert_eq!(lhs.union(&range("(3, 4] m")), None);
+        assert_eq!(lhs.union(&range("<2 m")), Some(range("<3 m")));
+        assert_eq!(lhs.union(&range("[5, 5) m")), Some(lhs.clone()));
+        assert_eq!(lhs.union(&range("1–2 kg")), None);
+    }
+}
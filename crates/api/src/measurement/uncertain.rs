@@ -0,0 +1,475 @@
+#![allow(clippy::result_large_err)]
+
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use num_traits::Pow;
+
+use crate::{term::Exponent, Convertible, Error, IsCompatibleWith, Measurement, UcumUnit, Unit};
+
+/// Relative size of the step used to estimate how a conversion to or from a special unit (ex.
+/// `B[W]`) scales an uncertainty.
+///
+const DERIVATIVE_STEP: f64 = 1.0e-6;
+
+/// A standard uncertainty, either in the measurement's unit or as a fraction of its value.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Uncertainty {
+    /// Ex. `0.4` for `12.3 ± 0.4 kg`.
+    ///
+    Absolute(f64),
+
+    /// Ex. `0.05` for `12.3 kg ± 5%`.
+    ///
+    Relative(f64),
+}
+
+/// A `Measurement` paired with its (absolute, standard) uncertainty.
+///
+/// Arithmetic propagates the uncertainty using first-order rules, treating the operands as
+/// uncorrelated, and converting scales it along with the value.
+///
+/// ```rust
+/// use wise_units::{
+///     measurement::{UncertainMeasurement, Uncertainty},
+///     Convertible, Measurement,
+/// };
+///
+/// let mass = Measurement::try_new(12.3, "kg").unwrap();
+/// let mass = UncertainMeasurement::new(mass, Uncertainty::Absolute(0.4));
+/// assert_eq!(mass.to_string(), "12.3 ± 0.4 kg");
+///
+/// let in_grams = mass.convert_to("g").unwrap();
+/// assert_eq!(in_grams.to_string(), "12300 ± 400 g");
+///
+/// let total = (&mass + &mass).unwrap();
+/// assert!((total.uncertainty() - 0.565_685).abs() < 0.000_001);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct UncertainMeasurement {
+    measurement: Measurement,
+    uncertainty: f64,
+}
+
+impl UncertainMeasurement {
+    #[must_use]
+    pub fn new(measurement: Measurement, uncertainty: Uncertainty) -> Self {
+        let uncertainty = match uncertainty {
+            Uncertainty::Absolute(uncertainty) => uncertainty,
+            Uncertainty::Relative(uncertainty) => uncertainty * measurement.value,
+        };
+
+        Self {
+            measurement,
+            uncertainty: uncertainty.abs(),
+        }
+    }
+
+    #[must_use]
+    pub const fn measurement(&self) -> &Measurement {
+        &self.measurement
+    }
+
+    #[must_use]
+    pub const fn value(&self) -> f64 {
+        self.measurement.value
+    }
+
+    #[must_use]
+    pub const fn unit(&self) -> &Unit {
+        &self.measurement.unit
+    }
+
+    /// The absolute uncertainty, in `unit()`.
+    ///
+    #[must_use]
+    pub const fn uncertainty(&self) -> f64 {
+        self.uncertainty
+    }
+
+    /// The uncertainty as a fraction of `value()`.
+    ///
+    #[must_use]
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty / self.measurement.value.abs()
+    }
+
+    /// `self`'s value and uncertainty in terms of `unit`, if it's compatible (like how the
+    /// `Measurement` ops convert their right-hand side).
+    ///
+    fn in_unit(&self, unit: &Unit) -> (f64, f64) {
+        if self.measurement.unit.is_compatible_with(unit) {
+            (
+                self.measurement.converted_scalar(unit),
+                self.scaled_uncertainty(unit),
+            )
+        } else {
+            (self.measurement.value, self.uncertainty)
+        }
+    }
+
+    fn scaled_uncertainty(&self, unit: &Unit) -> f64 {
+        let from = &self.measurement.unit;
+
+        let sensitivity = if from.is_special() || unit.is_special() {
+            let value = self.measurement.value;
+            let step = value.abs().max(1.0) * DERIVATIVE_STEP;
+            let convert = |value| Measurement::new(value, from.clone()).converted_scalar(unit);
+
+            (convert(value + step) - convert(value - step)) / (2.0 * step)
+        } else {
+            from.scalar() / unit.scalar()
+        };
+
+        (self.uncertainty * sensitivity).abs()
+    }
+}
+
+impl From<Measurement> for UncertainMeasurement {
+    /// A `Measurement` with no uncertainty.
+    ///
+    fn from(measurement: Measurement) -> Self {
+        Self {
+            measurement,
+            uncertainty: 0.0,
+        }
+    }
+}
+
+impl fmt::Display for UncertainMeasurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ± {} {}",
+            self.measurement.value, self.uncertainty, self.measurement.unit
+        )
+    }
+}
+
+// ╭──────────────────╮
+// │ impl Convertible │
+// ╰──────────────────╯
+impl<'a> Convertible<&'a Unit> for UncertainMeasurement {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, rhs: &'a Unit) -> Result<Self, Self::ConversionError> {
+        Ok(Self {
+            measurement: self.measurement.convert_to(rhs)?,
+            uncertainty: self.scaled_uncertainty(rhs),
+        })
+    }
+}
+
+impl<'a> Convertible<&'a str> for UncertainMeasurement {
+    type Output = Self;
+    type ConversionError = Error;
+
+    fn convert_to(&self, expression: &'a str) -> Result<Self, Self::ConversionError> {
+        let unit = Unit::from_str(expression)?;
+
+        self.convert_to(&unit)
+    }
+}
+
+// ╭───────────────────────────────────╮
+// │ impl Add, Sub, Mul, Div, Neg, Pow │
+// ╰───────────────────────────────────╯
+fn add_uncertain(
+    lhs: &UncertainMeasurement,
+    rhs: &UncertainMeasurement,
+) -> Result<UncertainMeasurement, Error> {
+    let measurement = (&lhs.measurement + &rhs.measurement)?;
+    let (_, rhs_uncertainty) = rhs.in_unit(lhs.unit());
+
+    Ok(UncertainMeasurement {
+        measurement,
+        uncertainty: lhs.uncertainty.hypot(rhs_uncertainty),
+    })
+}
+
+fn sub_uncertain(
+    lhs: &UncertainMeasurement,
+    rhs: &UncertainMeasurement,
+) -> Result<UncertainMeasurement, Error> {
+    let measurement = (&lhs.measurement - &rhs.measurement)?;
+    let (_, rhs_uncertainty) = rhs.in_unit(lhs.unit());
+
+    Ok(UncertainMeasurement {
+        measurement,
+        uncertainty: lhs.uncertainty.hypot(rhs_uncertainty),
+    })
+}
+
+fn mul_uncertain(lhs: &UncertainMeasurement, rhs: &UncertainMeasurement) -> UncertainMeasurement {
+    let (rhs_value, rhs_uncertainty) = rhs.in_unit(lhs.unit());
+
+    UncertainMeasurement {
+        measurement: &lhs.measurement * &rhs.measurement,
+        uncertainty: (rhs_value * lhs.uncertainty).hypot(lhs.value() * rhs_uncertainty),
+    }
+}
+
+fn div_uncertain(lhs: &UncertainMeasurement, rhs: &UncertainMeasurement) -> UncertainMeasurement {
+    let (rhs_value, rhs_uncertainty) = rhs.in_unit(lhs.unit());
+
+    UncertainMeasurement {
+        measurement: &lhs.measurement / &rhs.measurement,
+        uncertainty: (lhs.uncertainty / rhs_value)
+            .hypot(lhs.value() * rhs_uncertainty / (rhs_value * rhs_value)),
+    }
+}
+
+/// Implements `$op_trait` for each owned/borrowed combination of `UncertainMeasurement`s, using
+/// `$function`, which takes both by reference.
+///
+macro_rules! impl_binary_op {
+    ($op_trait:ident, $op_fn:ident, $function:ident, $output:ty) => {
+        impl $op_trait for UncertainMeasurement {
+            type Output = $output;
+
+            #[inline]
+            fn $op_fn(self, other: Self) -> Self::Output {
+                $function(&self, &other)
+            }
+        }
+
+        impl<'a> $op_trait<&'a Self> for UncertainMeasurement {
+            type Output = $output;
+
+            #[inline]
+            fn $op_fn(self, other: &'a Self) -> Self::Output {
+                $function(&self, other)
+            }
+        }
+
+        impl<'a> $op_trait for &'a UncertainMeasurement {
+            type Output = $output;
+
+            #[inline]
+            fn $op_fn(self, other: &'a UncertainMeasurement) -> Self::Output {
+                $function(self, other)
+            }
+        }
+
+        impl<'a> $op_trait<UncertainMeasurement> for &'a UncertainMeasurement {
+            type Output = $output;
+
+            #[inline]
+            fn $op_fn(self, other: UncertainMeasurement) -> Self::Output {
+                $function(self, &other)
+            }
+        }
+    };
+}
+
+impl_binary_op!(
+    Add,
+    add,
+    add_uncertain,
+    Result<UncertainMeasurement, Error>
+);
+impl_binary_op!(
+    Sub,
+    sub,
+    sub_uncertain,
+    Result<UncertainMeasurement, Error>
+);
+impl_binary_op!(Mul, mul, mul_uncertain, UncertainMeasurement);
+impl_binary_op!(Div, div, div_uncertain, UncertainMeasurement);
+
+impl Mul<f64> for UncertainMeasurement {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self::Output {
+        Self {
+            measurement: self.measurement * other,
+            uncertainty: (self.uncertainty * other).abs(),
+        }
+    }
+}
+
+impl Div<f64> for UncertainMeasurement {
+    type Output = Self;
+
+    fn div(self, other: f64) -> Self::Output {
+        Self {
+            measurement: self.measurement / other,
+            uncertainty: (self.uncertainty / other).abs(),
+        }
+    }
+}
+
+impl Neg for UncertainMeasurement {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            measurement: -self.measurement,
+            uncertainty: self.uncertainty,
+        }
+    }
+}
+
+impl Pow<Exponent> for UncertainMeasurement {
+    type Output = Self;
+
+    fn pow(self, rhs: Exponent) -> Self::Output {
+        let uncertainty =
+            (f64::from(rhs) * self.measurement.value.powi(rhs - 1) * self.uncertainty).abs();
+
+        Self {
+            measurement: self.measurement.pow(rhs),
+            uncertainty,
+        }
+    }
+}
+
+impl Measurement {
+    /// Pairs `self` with `uncertainty`.
+    ///
+    /// ```rust
+    /// use wise_units::{measurement::Uncertainty, Measurement};
+    ///
+    /// let yield_rate = Measurement::try_new(180.0, "[bu_us]/[acr_us]").unwrap();
+    /// let yield_rate = yield_rate.with_uncertainty(Uncertainty::Relative(0.05));
+    /// assert_eq!(yield_rate.to_string(), "180 ± 9 [bu_us]/[acr_us]");
+    /// ```
+    ///
+    #[must_use]
+    pub fn with_uncertainty(self, uncertainty: Uncertainty) -> UncertainMeasurement {
+        UncertainMeasurement::new(self, uncertainty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    fn uncertain(value: f64, unit: &str, uncertainty: f64) -> UncertainMeasurement {
+        Measurement::try_new(value, unit)
+            .unwrap()
+            .with_uncertainty(Uncertainty::Absolute(uncertainty))
+    }
+
+    macro_rules! assert_uncertain {
+        ($subject:expr, $value:expr, $unit:expr, $uncertainty:expr) => {
+            let subject = $subject;
+            assert_relative_eq!(subject.value(), $value, max_relative = 1.0e-12);
+            assert_eq!(subject.unit(), &Unit::from_str($unit).unwrap());
+            assert_relative_eq!(subject.uncertainty(), $uncertainty, max_relative = 1.0e-9);
+        };
+    }
+
+    #[test]
+    fn validate_new() {
+        let subject = Measurement::try_new(-20.0, "kg")
+            .unwrap()
+            .with_uncertainty(Uncertainty::Relative(0.1));
+        assert_uncertain!(&subject, -20.0, "kg", 2.0);
+        assert_relative_eq!(subject.relative_uncertainty(), 0.1);
+
+        let subject = uncertain(20.0, "kg", -2.0);
+        assert_uncertain!(&subject, 20.0, "kg", 2.0);
+
+        let subject = UncertainMeasurement::from(Measurement::try_new(20.0, "kg").unwrap());
+        assert_uncertain!(&subject, 20.0, "kg", 0.0);
+    }
+
+    #[test]
+    fn validate_display() {
+        assert_eq!(uncertain(12.3, "kg", 0.4).to_string(), "12.3 ± 0.4 kg");
+        assert_eq!(
+            uncertain(1.5, "[pt_us]/[acr_us]", 0.25).to_string(),
+            "1.5 ± 0.25 [pt_us]/[acr_us]"
+        );
+    }
+
+    #[test]
+    fn validate_convert_to() {
+        let subject = uncertain(12.3, "kg", 0.4);
+        assert_uncertain!(subject.convert_to("g").unwrap(), 12_300.0, "g", 400.0);
+        assert_uncertain!(
+            subject
+                .convert_to(&Unit::from_str("[lb_av]").unwrap())
+                .unwrap(),
+            27.116_858_248_739_94,
+            "[lb_av]",
+            0.881_849_048_739_510_3
+        );
+        assert!(subject.convert_to("m").is_err());
+
+        // Affine: a difference of 1 Cel is a difference of 1.8 [degF].
+        let subject = uncertain(20.0, "Cel", 0.5);
+        assert_uncertain!(subject.convert_to("[degF]").unwrap(), 68.0, "[degF]", 0.9);
+        assert_uncertain!(subject.convert_to("K").unwrap(), 293.15, "K", 0.5);
+
+        // Non-linear: d/dx 10^x = ln(10) * 10^x
+        let subject = uncertain(2.0, "B[W]", 0.01);
+        assert_uncertain!(
+            subject.convert_to("W").unwrap(),
+            100.0,
+            "W",
+            std::f64::consts::LN_10 * 100.0 * 0.01
+        );
+    }
+
+    #[test]
+    fn validate_add_sub() {
+        let lhs = uncertain(12.0, "kg", 0.3);
+        let rhs = uncertain(500.0, "g", 40.0);
+
+        assert_uncertain!((&lhs + &rhs).unwrap(), 12.5, "kg", 0.3_f64.hypot(0.04));
+        assert_uncertain!(
+            (lhs.clone() - rhs).unwrap(),
+            11.5,
+            "kg",
+            0.3_f64.hypot(0.04)
+        );
+        assert!((lhs + uncertain(1.0, "m", 0.1)).is_err());
+    }
+
+    #[test]
+    fn validate_mul_div() {
+        let lhs = uncertain(10.0, "m", 0.1);
+        let rhs = uncertain(200.0, "cm", 4.0);
+
+        // 10 m * 2 m; relative uncertainties of 1% and 2%.
+        let product = &lhs * &rhs;
+        assert_relative_eq!(product.value(), 20.0);
+        assert_relative_eq!(product.uncertainty(), 20.0 * 0.01_f64.hypot(0.02));
+
+        let quotient = lhs.clone() / rhs;
+        assert_relative_eq!(quotient.value(), 5.0);
+        assert_relative_eq!(quotient.uncertainty(), 5.0 * 0.01_f64.hypot(0.02));
+
+        // Incompatible units aren't converted.
+        let rhs = uncertain(2.0, "s", 0.1);
+        let quotient = lhs / rhs;
+        assert_uncertain!(quotient, 5.0, "m/s", 5.0 * 0.01_f64.hypot(0.05));
+    }
+
+    #[test]
+    fn validate_scalar_ops() {
+        let subject = uncertain(10.0, "m", 0.1);
+
+        assert_uncertain!(subject.clone() * -3.0, -30.0, "m", 0.3);
+        assert_uncertain!(subject.clone() / 4.0, 2.5, "m", 0.025);
+        assert_uncertain!(-subject, -10.0, "m", 0.1);
+    }
+
+    #[test]
+    fn validate_pow() {
+        let subject = uncertain(10.0, "m", 0.1);
+
+        assert_uncertain!(subject.clone().pow(2), 100.0, "m2", 2.0);
+        assert_uncertain!(subject.pow(-1), 0.1, "m-1", 0.001);
+    }
+}
@@ -0,0 +1,385 @@
+use std::{fmt, ops::Neg};
+
+use num_traits::Num;
+
+use crate::{
+    reducible::{terms_scalar, Reducible, ScalarValue},
+    Error, UcumUnit, Unit,
+};
+
+/// The numeric type a `Measurement` stores its value as. `f64` is the default; `f32` is also
+/// supported, as is `rust_decimal::Decimal` when the `decimal` feature is enabled.
+///
+/// Only `from_f64()`, `to_f64()`, `checked_mul()`, and `checked_div()` need implementing. The
+/// default `convert()` builds each non-special unit's scalar from the atom definitions *in*
+/// `Self`, so for a decimal type, definitions that are exact (ex. `[lb_av]` = 7000 `[gr]` =
+/// 453.592 37 `g`) stay exact. Special units (ex. `Cel`, `[pH]`) are converted through `f64`.
+///
+pub trait Value: Num + Copy + PartialOrd + Neg<Output = Self> + fmt::Debug + fmt::Display {
+    /// Converts a definition constant (ex. `64.798_91`) to `Self`, or `None` if it's out of
+    /// `Self`'s range (including a nonzero value that `Self` would round to zero). Types that can
+    /// represent decimal fractions exactly should convert the value as written, not its binary
+    /// expansion.
+    ///
+    fn from_f64(value: f64) -> Option<Self>;
+
+    /// `None` if `self` can't be represented as an `f64`.
+    ///
+    fn to_f64(self) -> Option<f64>;
+
+    /// `self * rhs`, or `None` if that overflows, or underflows to zero.
+    ///
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// `self / rhs`, or `None` if `rhs` is zero or that overflows, or underflows to zero.
+    ///
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    /// Raises `self` to `exponent`, by squaring.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::DivideByZero` if `self` is zero and `exponent` is negative, and
+    /// `Error::ValueOutOfRange` if the result overflows or underflows.
+    ///
+    fn checked_powi(self, exponent: i32) -> Result<Self, Error> {
+        if exponent < 0 && self.is_zero() {
+            return Err(Error::DivideByZero);
+        }
+
+        let out_of_range = || Error::ValueOutOfRange(format!("{self}^{exponent}"));
+        let mut base = self;
+        let mut remaining = exponent.unsigned_abs();
+        let mut power = Self::one();
+
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                power = power.checked_mul(base).ok_or_else(out_of_range)?;
+            }
+
+            remaining >>= 1;
+
+            if remaining > 0 {
+                base = base.checked_mul(base).ok_or_else(out_of_range)?;
+            }
+        }
+
+        if exponent < 0 {
+            Self::one().checked_div(power).ok_or_else(out_of_range)
+        } else {
+            Ok(power)
+        }
+    }
+
+    /// Converts `self` from a count of `from` to a count of `to`. Doesn't check that the units are
+    /// compatible.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ValueOutOfRange` if the result, or a scalar along the way, overflows or
+    /// underflows `Self`.
+    ///
+    fn convert(self, from: &Unit, to: &Unit) -> Result<Self, Error> {
+        let out_of_range = || {
+            Error::ValueOutOfRange(format!(
+                "{self} {} in {}",
+                from.expression(),
+                to.expression()
+            ))
+        };
+
+        if from.is_special() || to.is_special() {
+            let converted = self.to_f64().ok_or_else(out_of_range)?.convert(from, to)?;
+
+            return Self::from_f64(converted).ok_or_else(out_of_range);
+        }
+
+        let from_scalar = terms_scalar::<Self>(from.terms())?;
+        let to_scalar = terms_scalar::<Self>(to.terms())?;
+
+        self.checked_mul(from_scalar)
+            .and_then(|value| value.checked_div(to_scalar))
+            .ok_or_else(out_of_range)
+    }
+}
+
+/// `checked_mul()` and `checked_div()` for the float types, which overflow to infinity and
+/// underflow to zero instead of failing.
+///
+macro_rules! impl_checked_float_ops {
+    () => {
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+            let product = self * rhs;
+            let overflowed = product.is_infinite() && self.is_finite() && rhs.is_finite();
+            let underflowed = product == 0.0 && self != 0.0 && rhs != 0.0;
+
+            (!overflowed && !underflowed).then_some(product)
+        }
+
+        fn checked_div(self, rhs: Self) -> Option<Self> {
+            let quotient = self / rhs;
+            let overflowed = quotient.is_infinite() && self.is_finite();
+            let underflowed = quotient == 0.0 && self != 0.0 && rhs.is_finite();
+
+            (!overflowed && !underflowed).then_some(quotient)
+        }
+    };
+}
+
+/// The `f64` conversion, which can't fail: out-of-range results are infinite or zero, as with
+/// any other `f64` arithmetic.
+///
+pub(crate) fn convert_f64(value: f64, from: &Unit, to: &Unit) -> f64 {
+    if from.is_special() && to.is_special() {
+        to.calculate_magnitude(from.reduce_value(value))
+    } else if from.is_special() {
+        from.reduce_value(value)
+    } else if to.is_special() {
+        to.calculate_magnitude(value)
+    } else {
+        value * from.reduce_value(num_traits::One::one()) / to.reduce_value(num_traits::One::one())
+    }
+}
+
+impl Value for f64 {
+    fn from_f64(value: f64) -> Option<Self> {
+        Some(value)
+    }
+
+    fn to_f64(self) -> Option<f64> {
+        Some(self)
+    }
+
+    impl_checked_float_ops!();
+
+    fn convert(self, from: &Unit, to: &Unit) -> Result<Self, Error> {
+        Ok(convert_f64(self, from, to))
+    }
+}
+
+impl Value for f32 {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_f64(value: f64) -> Option<Self> {
+        let narrowed = value as Self;
+        let overflowed = narrowed.is_infinite() && value.is_finite();
+        let underflowed = narrowed == 0.0 && value != 0.0;
+
+        (!overflowed && !underflowed).then_some(narrowed)
+    }
+
+    fn to_f64(self) -> Option<f64> {
+        Some(f64::from(self))
+    }
+
+    impl_checked_float_ops!();
+
+    fn convert(self, from: &Unit, to: &Unit) -> Result<Self, Error> {
+        Self::from_f64(convert_f64(f64::from(self), from, to)).ok_or_else(|| {
+            Error::ValueOutOfRange(format!(
+                "{self} {} in {}",
+                from.expression(),
+                to.expression()
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Value for rust_decimal::Decimal {
+    /// Parses the shortest representation of `value` (ex. `64.79891`, not
+    /// `64.7989100000000064...`), falling back to the nearest `Decimal` if that has too many
+    /// digits.
+    ///
+    fn from_f64(value: f64) -> Option<Self> {
+        let decimal = <Self as std::str::FromStr>::from_str(&value.to_string())
+            .ok()
+            .or_else(|| <Self as num_traits::FromPrimitive>::from_f64(value))?;
+
+        // Too small for `Decimal`'s 28 fractional digits, so it rounded to zero.
+        (!decimal.is_zero() || value == 0.0).then_some(decimal)
+    }
+
+    fn to_f64(self) -> Option<f64> {
+        num_traits::ToPrimitive::to_f64(&self)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::checked_mul(self, rhs)
+            .filter(|product| !product.is_zero() || self.is_zero() || rhs.is_zero())
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::checked_div(self, rhs).filter(|quotient| !quotient.is_zero() || self.is_zero())
+    }
+}
+
+impl<V: Value> ScalarValue for V {
+    fn from_definition(value: f64) -> Result<Self, Error> {
+        Self::from_f64(value).ok_or_else(|| Error::ValueOutOfRange(value.to_string()))
+    }
+
+    fn try_mul(self, rhs: Self) -> Result<Self, Error> {
+        self.checked_mul(rhs)
+            .ok_or_else(|| Error::ValueOutOfRange(format!("{self} * {rhs}")))
+    }
+
+    fn try_powi(self, exponent: i32) -> Result<Self, Error> {
+        self.checked_powi(exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use approx::assert_ulps_eq;
+
+    use super::*;
+
+    fn unit(expression: &str) -> Unit {
+        Unit::from_str(expression).unwrap()
+    }
+
+    #[test]
+    fn validate_scalar() {
+        let unit = unit("[lb_av]");
+        assert_ulps_eq!(terms_scalar::<f64>(unit.terms()).unwrap(), unit.scalar());
+
+        let unit = Unit::from_str("km/h").unwrap();
+        assert_ulps_eq!(terms_scalar::<f64>(unit.terms()).unwrap(), unit.scalar());
+
+        let unit = Unit::from_str("10.[in_i]2{stuff}").unwrap();
+        assert_ulps_eq!(terms_scalar::<f64>(unit.terms()).unwrap(), unit.scalar());
+    }
+
+    #[test]
+    fn validate_checked_powi() {
+        assert_ulps_eq!(2.0_f64.checked_powi(10).unwrap(), 1024.0);
+        assert_ulps_eq!(2.0_f64.checked_powi(-3).unwrap(), 0.125);
+        assert_ulps_eq!(0.0_f64.checked_powi(3).unwrap(), 0.0);
+        assert_ulps_eq!(0.0_f64.checked_powi(0).unwrap(), 1.0);
+        assert_eq!(0.0_f64.checked_powi(-1), Err(Error::DivideByZero));
+        assert!(matches!(
+            10.0_f64.checked_powi(400),
+            Err(Error::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            10.0_f64.checked_powi(-400),
+            Err(Error::ValueOutOfRange(_))
+        ));
+        assert_ulps_eq!(1.0_f64.checked_powi(i32::MIN).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn validate_f32_convert() {
+        let km = unit("km");
+        let m = unit("m");
+        assert_ulps_eq!(2.5_f32.convert(&km, &m).unwrap(), 2500.0);
+
+        let cel = unit("Cel");
+        let k = unit("K");
+        assert_ulps_eq!(1.0_f32.convert(&cel, &k).unwrap(), 274.15);
+
+        assert!(matches!(
+            1.0_f32.convert(&unit("10*40"), &unit("1")),
+            Err(Error::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            1.0_f32.convert(&unit("10*-50"), &unit("1")),
+            Err(Error::ValueOutOfRange(_))
+        ));
+    }
+
+    #[cfg(feature = "decimal")]
+    mod decimal {
+        use rust_decimal::Decimal;
+
+        use crate::{Convertible, Measurement};
+
+        use super::*;
+
+        fn decimal(value: &str) -> Decimal {
+            Decimal::from_str(value).unwrap()
+        }
+
+        fn convert(value: &str, from: &str, to: &str) -> Result<Decimal, Error> {
+            Measurement::new(decimal(value), unit(from))
+                .convert_to(to)
+                .map(|converted| converted.value())
+        }
+
+        #[test]
+        fn validate_checked_powi() {
+            assert_eq!(decimal("2").checked_powi(3), Ok(decimal("8")));
+            assert_eq!(decimal("2").checked_powi(-2), Ok(decimal("0.25")));
+            assert_eq!(decimal("2").checked_powi(0), Ok(Decimal::ONE));
+            assert_eq!(Decimal::ZERO.checked_powi(-2), Err(Error::DivideByZero));
+            assert!(matches!(
+                decimal("10").checked_powi(30),
+                Err(Error::ValueOutOfRange(_))
+            ));
+            assert!(matches!(
+                decimal("10").checked_powi(-30),
+                Err(Error::ValueOutOfRange(_))
+            ));
+        }
+
+        #[test]
+        fn validate_from_f64() {
+            assert_eq!(
+                <Decimal as Value>::from_f64(64.798_91),
+                Some(decimal("64.79891"))
+            );
+            assert_eq!(
+                <Decimal as Value>::from_f64(1.0e-24),
+                Some(decimal("0.000000000000000000000001"))
+            );
+            assert_eq!(<Decimal as Value>::from_f64(0.0), Some(Decimal::ZERO));
+            assert_eq!(<Decimal as Value>::from_f64(f64::MAX), None);
+            assert_eq!(<Decimal as Value>::from_f64(6.626_070_15e-34), None);
+            assert_eq!(<Decimal as Value>::from_f64(f64::NAN), None);
+        }
+
+        #[test]
+        fn validate_scalar() {
+            let unit = unit("[lb_av]");
+            assert_eq!(
+                terms_scalar::<Decimal>(unit.terms()),
+                Ok(decimal("453.59237"))
+            );
+
+            let unit = Unit::from_str("[oz_av]").unwrap();
+            assert_eq!(
+                terms_scalar::<Decimal>(unit.terms()),
+                Ok(decimal("28.349523125"))
+            );
+        }
+
+        #[test]
+        fn validate_convert() {
+            assert_eq!(convert("100", "[lb_av]", "kg"), Ok(decimal("45.359237")));
+            assert_eq!(
+                convert("1", "kg", "[lb_av]"),
+                Ok(decimal("1000") / decimal("453.59237"))
+            );
+            assert_eq!(convert("1", "Cel", "K"), Ok(decimal("274.15")));
+            assert_eq!(convert("1", "m1000", "m1000"), Ok(Decimal::ONE));
+        }
+
+        #[test]
+        fn validate_convert_out_of_range() {
+            for (from, to) in [
+                ("10*30", "1"),
+                ("J.s", "[h]"),
+                ("[h]", "J.s"),
+                ("m1000", "km1000"),
+                ("1", "10*30"),
+            ] {
+                assert!(
+                    matches!(convert("1", from, to), Err(Error::ValueOutOfRange(_))),
+                    "{from} -> {to}"
+                );
+            }
+        }
+    }
+}
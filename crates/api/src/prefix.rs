@@ -55,7 +55,7 @@ pub(crate) mod u128 {
 /// A `Prefix` is essentially a multiplier for an `Atom` within a `Term`; ex.
 /// the "c" in "cm" modifies meter by 0.01. The UCUM spec says these should
 /// only pertain to metric units, but that rule is not adhered to in
-/// `wise_units`.
+/// `wise_units`; use `Unit::validate_strict()` to check for it.
 ///
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Prefix {
@@ -85,6 +85,37 @@ pub enum Prefix {
     Zetta,
 }
 
+impl Prefix {
+    /// Every `Prefix`, in alphabetical order.
+    ///
+    pub const ALL: [Self; 24] = [
+        Self::Atto,
+        Self::Centi,
+        Self::Deci,
+        Self::Deka,
+        Self::Exa,
+        Self::Femto,
+        Self::Gibi,
+        Self::Giga,
+        Self::Hecto,
+        Self::Kibi,
+        Self::Kilo,
+        Self::Mebi,
+        Self::Mega,
+        Self::Micro,
+        Self::Milli,
+        Self::Nano,
+        Self::Peta,
+        Self::Pico,
+        Self::Tebi,
+        Self::Tera,
+        Self::Yocto,
+        Self::Yotta,
+        Self::Zepto,
+        Self::Zetta,
+    ];
+}
+
 impl UcumSymbol for Prefix {
     fn classification(&self) -> Classification {
         Classification::Si
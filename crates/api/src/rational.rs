@@ -0,0 +1,212 @@
+//! Exact rational scalars, for converting between non-special units without the error that `f64`
+//! scalars pick up over multi-step definitions (ex. `[ft_i]` is 12 `[in_i]`, which is 2.54 `cm`).
+//!
+//! ```rust
+//! use std::str::FromStr;
+//! use wise_units::{rational::BigRational, Unit};
+//!
+//! let inch = Unit::from_str("[in_i]").unwrap();
+//! let foot = Unit::from_str("[ft_i]").unwrap();
+//!
+//! let factor = inch.exact_factor_to(&foot).unwrap();
+//! assert_eq!(factor, BigRational::new(1.into(), 12.into()));
+//! ```
+//!
+//! Definition and prefix values are stored as `f64`s, so they're read back as the shortest decimal
+//! that rounds to the same `f64` (ex. `64.798_91` is exactly `6_479_891 / 10^5`). That's the
+//! literal from UCUM's definitions whenever it has no more than 15 significant digits, which all
+//! of them do, so scalars are exact wherever UCUM's definitions are. The exception is `[pi]` (and
+//! atoms defined in terms of it), which is only as exact as `std::f64::consts::PI`.
+//!
+pub use num_rational::BigRational;
+
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+use crate::{
+    is_compatible_with::IsCompatibleWith,
+    reducible::{terms_scalar, ScalarValue},
+    term::Exponent,
+    Atom, Error, Term, UcumUnit, Unit,
+};
+
+impl ScalarValue for BigRational {
+    /// Uses `f64`'s `Display`, which writes the shortest decimal that round-trips (and never uses
+    /// an exponent), and errors for `NaN` and infinities.
+    ///
+    fn from_definition(value: f64) -> Result<Self, Error> {
+        let written = value.to_string();
+        let (integer, fraction) = written.split_once('.').unwrap_or((&written, ""));
+
+        format!("{integer}{fraction}")
+            .parse::<BigInt>()
+            .map_or_else(
+                |_| Self::from_float(value).ok_or_else(|| Error::ValueOutOfRange(written.clone())),
+                |numerator| {
+                    Ok(Self::new(
+                        numerator,
+                        num_traits::pow(BigInt::from(10_u8), fraction.len()),
+                    ))
+                },
+            )
+    }
+
+    fn try_mul(self, rhs: Self) -> Result<Self, Error> {
+        Ok(self * rhs)
+    }
+
+    fn try_powi(self, exponent: Exponent) -> Result<Self, Error> {
+        if exponent < 0 && self.is_zero() {
+            return Err(Error::DivideByZero);
+        }
+
+        Ok(self.pow(exponent))
+    }
+}
+
+impl Atom {
+    /// The exact version of `UcumUnit::scalar()`, or `None` if `self` is special.
+    ///
+    #[must_use]
+    pub fn exact_scalar(&self) -> Option<BigRational> {
+        if self.is_special() {
+            return None;
+        }
+
+        terms_scalar(&[Term::Atom(*self)]).ok()
+    }
+}
+
+impl Unit {
+    /// The exact version of `UcumUnit::scalar()`, or `None` if `self` is special, or raises a zero
+    /// factor to a negative exponent (ex. `m/0`).
+    ///
+    #[must_use]
+    pub fn exact_scalar(&self) -> Option<BigRational> {
+        if self.is_special() {
+            return None;
+        }
+
+        terms_scalar(self.terms()).ok()
+    }
+
+    /// The exact factor for converting a count of `self` to a count of `other`. Returns `None` if
+    /// the units aren't compatible, if either is special (and so has no such factor), or if either
+    /// has no exact scalar or `other`'s is zero (ex. `m/0`, `0.m`).
+    ///
+    /// Multiplying a value by this factor before rounding to `f64` (instead of by `f64` scalars)
+    /// gives the correctly rounded result:
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::{num_traits::ToPrimitive, rational::BigRational, Unit};
+    ///
+    /// let inch = Unit::from_str("[in_i]").unwrap();
+    /// let foot = Unit::from_str("[ft_i]").unwrap();
+    /// let factor = inch.exact_factor_to(&foot).unwrap();
+    ///
+    /// let feet = BigRational::from_float(3.0).unwrap() * factor;
+    /// assert_eq!(feet.to_f64(), Some(0.25));
+    /// ```
+    ///
+    #[must_use]
+    pub fn exact_factor_to(&self, other: &Self) -> Option<BigRational> {
+        if !self.is_compatible_with(other) {
+            return None;
+        }
+
+        let other_scalar = other.exact_scalar().filter(|scalar| !scalar.is_zero())?;
+
+        Some(self.exact_scalar()? / other_scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn ratio(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(numerator.into(), denominator.into())
+    }
+
+    fn exact_scalar(expression: &str) -> Option<BigRational> {
+        Unit::from_str(expression).unwrap().exact_scalar()
+    }
+
+    #[test]
+    fn validate_from_definition() {
+        assert_eq!(
+            BigRational::from_definition(64.798_91),
+            Ok(ratio(6_479_891, 100_000))
+        );
+        assert_eq!(BigRational::from_definition(-0.001), Ok(ratio(-1, 1000)));
+        assert_eq!(BigRational::from_definition(1000.0), Ok(ratio(1000, 1)));
+        assert_eq!(
+            BigRational::from_definition(1.0e-24),
+            Ok(BigRational::new(
+                BigInt::from(1),
+                num_traits::pow(BigInt::from(10), 24)
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_atom_exact_scalar() {
+        assert_eq!(Atom::Meter.exact_scalar(), Some(ratio(1, 1)));
+        assert_eq!(
+            Atom::InchInternational.exact_scalar(),
+            Some(ratio(127, 5000))
+        );
+        assert_eq!(
+            Atom::PoundAvoirdupois.exact_scalar(),
+            Some(ratio(45_359_237, 100_000))
+        );
+        assert_eq!(Atom::DegreeCelsius.exact_scalar(), None);
+    }
+
+    #[test]
+    fn validate_unit_exact_scalar() {
+        assert_eq!(exact_scalar("km"), Some(ratio(1000, 1)));
+        assert_eq!(exact_scalar("[ft_i]"), Some(ratio(381, 1250)));
+        assert_eq!(
+            exact_scalar("[acr_us]"),
+            Some(ratio(62_726_400_000, 15_499_969))
+        );
+        assert_eq!(exact_scalar("m2/{tree}"), Some(ratio(1, 1)));
+        assert_eq!(
+            exact_scalar("10*-3.[in_i]2"),
+            Some(ratio(16_129, 25_000_000_000))
+        );
+        assert_eq!(exact_scalar("Cel"), None);
+        assert_eq!(exact_scalar("0m"), Some(ratio(0, 1)));
+        assert_eq!(exact_scalar("m/0"), None);
+        assert_eq!(exact_scalar("0m-2"), None);
+    }
+
+    #[test]
+    fn validate_exact_factor_to() {
+        let factor = |lhs: &str, rhs: &str| {
+            Unit::from_str(lhs)
+                .unwrap()
+                .exact_factor_to(&Unit::from_str(rhs).unwrap())
+        };
+
+        let chained = factor("[in_i]", "cm").unwrap()
+            * factor("cm", "m").unwrap()
+            * factor("m", "[ft_i]").unwrap();
+        assert_eq!(chained, ratio(1, 12));
+
+        assert_eq!(
+            factor("kg", "[lb_av]"),
+            Some(ratio(100_000_000, 45_359_237))
+        );
+        assert_eq!(factor("m", "kg"), None);
+        assert_eq!(factor("Cel", "K"), None);
+        assert_eq!(factor("m/0", "m"), None);
+        assert_eq!(factor("m", "m/0"), None);
+        assert_eq!(factor("m", "0m"), None);
+        assert_eq!(factor("0m", "m"), Some(ratio(0, 1)));
+    }
+}
@@ -1,3 +1,7 @@
+use num_traits::Num;
+
+use crate::{term::Exponent, ucum_symbol::UcumSymbol, Atom, Error, Term};
+
 pub(crate) trait Reducible<V> {
     /// Calculates `value` count of `self` in terms of `self`'s base-unit.
     ///
@@ -5,3 +9,47 @@ pub(crate) trait Reducible<V> {
 
     fn calculate_magnitude(&self, value: V) -> V;
 }
+
+/// A numeric type, other than `f64`, that `terms_scalar()` can compute scalars in.
+///
+pub(crate) trait ScalarValue: Clone + Num {
+    /// Converts a definition or prefix constant (ex. `64.798_91`) to `Self`.
+    ///
+    fn from_definition(value: f64) -> Result<Self, Error>;
+
+    fn try_mul(self, rhs: Self) -> Result<Self, Error>;
+
+    fn try_powi(self, exponent: Exponent) -> Result<Self, Error>;
+}
+
+/// The scalar of non-special `terms`, computed from the atom definitions in `V` instead of from
+/// the `f64` scalars that `Atom`s keep. Fails if `V` can't represent a scalar along the way.
+///
+pub(crate) fn terms_scalar<V: ScalarValue>(terms: &[Term]) -> Result<V, Error> {
+    terms
+        .iter()
+        .try_fold(V::one(), |acc, term| acc.try_mul(term_scalar(term)?))
+}
+
+fn term_scalar<V: ScalarValue>(term: &Term) -> Result<V, Error> {
+    let factor = term.factor().map_or_else(
+        || Ok(V::one()),
+        |factor| V::from_definition(f64::from(factor)),
+    )?;
+    let prefix = term.prefix().map_or_else(
+        || Ok(V::one()),
+        |prefix| V::from_definition(prefix.definition_value()),
+    )?;
+    let atom = term.atom().map_or_else(|| Ok(V::one()), atom_scalar)?;
+
+    factor
+        .try_mul(prefix)?
+        .try_mul(atom)?
+        .try_powi(term.effective_exponent())
+}
+
+fn atom_scalar<V: ScalarValue>(atom: Atom) -> Result<V, Error> {
+    let definition = atom.definition();
+
+    V::from_definition(definition.value())?.try_mul(terms_scalar(definition.terms())?)
+}
@@ -612,6 +612,52 @@ impl Term {
             Self::FactorPrefixAtomExponentAnnotation(inner) => Cow::Owned(inner.to_string()),
         }
     }
+
+    /// Like `as_cow_str()`, but uses the case-insensitive codes of the `Prefix` and `Atom` (when
+    /// they have one).
+    ///
+    pub(crate) fn as_case_insensitive_cow_str(&self) -> Cow<'_, str> {
+        self.atom().map_or_else(
+            || self.as_cow_str(),
+            |atom| Cow::Owned(self.case_insensitive_string(atom)),
+        )
+    }
+
+    fn case_insensitive_string(&self, atom: Atom) -> String {
+        use crate::UcumSymbol;
+
+        let mut output = String::new();
+
+        if let Some(factor) = self.factor() {
+            if factor != 1 {
+                output.push_str(&factor.to_string());
+            }
+        }
+
+        if let Some(prefix) = self.prefix() {
+            output.push_str(
+                prefix
+                    .secondary_code()
+                    .unwrap_or_else(|| prefix.primary_code()),
+            );
+        }
+
+        output.push_str(atom.secondary_code().unwrap_or_else(|| atom.primary_code()));
+
+        if let Some(exponent) = self.exponent() {
+            if exponent != 1 {
+                output.push_str(&exponent.to_string());
+            }
+        }
+
+        if let Some(annotation) = self.annotation() {
+            output.push('{');
+            output.push_str(annotation);
+            output.push('}');
+        }
+
+        output
+    }
 }
 
 impl Default for Term {
@@ -38,13 +38,19 @@ impl Composable for Term {
     }
 }
 
-impl<'a> Composable for Cow<'a, [Term]> {
+impl Composable for [Term] {
     fn composition(&self) -> Composition {
         self.iter()
             .fold(Composition::default(), |acc, term| acc * term.composition())
     }
 }
 
+impl<'a> Composable for Cow<'a, [Term]> {
+    fn composition(&self) -> Composition {
+        (**self).composition()
+    }
+}
+
 impl ComposablyEq<Self> for Term {
     fn composably_eq(&self, rhs: &Self) -> Option<Exponent> {
         match self {
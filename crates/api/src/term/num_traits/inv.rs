@@ -163,9 +163,9 @@ impl<'a> Inv for &'a mut Term {
     }
 }
 
-// This solves not being able to `impl Inv for &mut Vec<Term>`.
+// This solves not being able to `impl Inv for &mut [Term]`.
 //
-pub(crate) fn inv_terms(terms: &mut Vec<Term>) {
+pub(crate) fn inv_terms(terms: &mut [Term]) {
     for term in terms {
         let _ = term.inv();
     }
@@ -103,7 +103,7 @@ where
     }
 }
 
-impl<'a> Reducible<f64> for Cow<'a, [Term]> {
+impl Reducible<f64> for [Term] {
     fn reduce_value(&self, value: f64) -> f64 {
         self.iter()
             .fold(One::one(), |acc, term| acc * term.reduce_value(value))
@@ -116,6 +116,16 @@ impl<'a> Reducible<f64> for Cow<'a, [Term]> {
     }
 }
 
+impl<'a> Reducible<f64> for Cow<'a, [Term]> {
+    fn reduce_value(&self, value: f64) -> f64 {
+        (**self).reduce_value(value)
+    }
+
+    fn calculate_magnitude(&self, value: f64) -> f64 {
+        (**self).calculate_magnitude(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::reducible::Reducible;
@@ -1,17 +1,25 @@
+mod aliases;
+mod approx_eq;
 mod as_fraction;
+pub mod cache;
 mod composable;
+mod converter;
 mod deref;
 mod display;
+mod extract;
 mod field_eq;
 mod from_str;
 mod invert;
 mod is_compatible_with;
+mod memo;
 mod num_traits;
 mod ops;
-mod parser;
+pub(crate) mod parser;
 mod partial_eq;
 mod partial_ord;
 mod reducible;
+mod storage;
+mod strict;
 mod term_reducing;
 mod to_reduced;
 #[cfg(feature = "v2")]
@@ -31,13 +39,23 @@ use std::{borrow::Cow, str::FromStr};
 #[cfg(feature = "cffi")]
 use ffi_common::derive::FFI;
 
-pub(crate) use self::parser::Error as ParserError;
+pub use self::{
+    aliases::UnitAliases,
+    converter::Converter,
+    display::CaseInsensitiveDisplay,
+    extract::ExtractedUnit,
+    parser::{
+        ast, Ambiguity, CaseMode, Diagnostic, DiagnosticKind, Error as ParserError, Limit,
+        NamedUnit, ParseLimits, ParseOptions,
+    },
+    strict::StrictFinding,
+};
 
 use crate::{term, Error, Term};
 
-pub const UNITY: Unit = Unit {
-    terms: Cow::Borrowed(term::UNITY_ARRAY_REF),
-};
+use self::{memo::Memo, storage::Terms};
+
+pub const UNITY: Unit = Unit::new_const(term::UNITY_ARRAY_REF);
 
 #[cfg_attr(
     feature = "cffi",
@@ -50,7 +68,8 @@ pub const UNITY: Unit = Unit {
 )]
 #[derive(Clone, Debug)]
 pub struct Unit {
-    pub(crate) terms: Cow<'static, [Term]>,
+    pub(crate) terms: Terms,
+    memo: Memo,
 }
 
 /// A `Unit` is the piece of data that represents a *valid* UCUM unit or
@@ -88,10 +107,27 @@ impl Unit {
         Cow<'static, [Term]>: From<T>,
     {
         Self {
-            terms: Cow::from(terms),
+            terms: Terms::from(Cow::from(terms)),
+            memo: Memo::new(),
+        }
+    }
+
+    /// For `const` units. These don't memoize their scalar, magnitude, or composition.
+    ///
+    pub(crate) const fn new_const(terms: &'static [Term]) -> Self {
+        Self {
+            terms: Terms::Static(terms),
+            memo: Memo::NONE,
         }
     }
 
+    /// For changing `self`'s terms in place; this forgets anything computed from the old ones.
+    ///
+    pub(crate) fn terms_mut(&mut self) -> &mut [Term] {
+        self.memo = Memo::new();
+        self.terms.to_mut()
+    }
+
     /// Creates a new `Unit` that's equivalent to "1".
     ///
     #[deprecated(since = "1.0.0", note = "Please use unit::UNITY instead")]
@@ -103,13 +139,13 @@ impl Unit {
     /// Accessor for the `Term`s used that make up this `Unit`.
     ///
     #[must_use]
-    pub const fn terms(&self) -> &Cow<'static, [Term]> {
+    pub fn terms(&self) -> &[Term] {
         &self.terms
     }
 
     #[must_use]
     pub fn into_terms(self) -> Cow<'static, [Term]> {
-        self.terms
+        Cow::from(self.terms)
     }
 
     /// A `Unit` is a unity `Unit` if represents "1", which technically means
@@ -229,7 +265,7 @@ impl Unit {
     ///
     #[cfg(test)]
     pub(crate) fn sort_terms(&mut self) -> &mut Self {
-        self.terms.to_mut().sort_by_key(ToString::to_string);
+        self.terms_mut().sort_by_key(ToString::to_string);
         self
     }
 }
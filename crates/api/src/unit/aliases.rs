@@ -0,0 +1,512 @@
+use std::{borrow::Cow, collections::HashMap, str::FromStr};
+
+use crate::{Error, Unit};
+
+use super::ParseOptions;
+
+/// Common (non-UCUM) labels for units used in agriculture and on equipment, and the UCUM
+/// expressions they stand for.
+///
+const AGRICULTURAL: &[(&str, &str)] = &[
+    // Mass
+    ("#", "[lb_av]"),
+    ("lb", "[lb_av]"),
+    ("lbs", "[lb_av]"),
+    ("pound", "[lb_av]"),
+    ("pounds", "[lb_av]"),
+    ("oz", "[oz_av]"),
+    ("ozs", "[oz_av]"),
+    ("cwt", "[scwt_av]"),
+    ("ton", "[ston_av]"),
+    ("tons", "[ston_av]"),
+    ("short ton", "[ston_av]"),
+    ("long ton", "[lton_av]"),
+    ("kgs", "kg"),
+    // Area
+    ("ac", "[acr_us]"),
+    ("acre", "[acr_us]"),
+    ("acres", "[acr_us]"),
+    ("ha", "har"),
+    ("hectare", "har"),
+    ("hectares", "har"),
+    ("sq ft", "[sft_i]"),
+    // Volume
+    ("gal", "[gal_us]"),
+    ("gals", "[gal_us]"),
+    ("gallon", "[gal_us]"),
+    ("gallons", "[gal_us]"),
+    ("qt", "[qt_us]"),
+    ("pt", "[pt_us]"),
+    ("cup", "[cup_us]"),
+    ("floz", "[foz_us]"),
+    ("fl oz", "[foz_us]"),
+    ("tbsp", "[tbs_us]"),
+    ("tsp", "[tsp_us]"),
+    ("bu", "[bu_us]"),
+    ("bushel", "[bu_us]"),
+    ("bushels", "[bu_us]"),
+    ("pk", "[pk_us]"),
+    ("cc", "cm3"),
+    // Length
+    ("in", "[in_i]"),
+    ("ft", "[ft_i]"),
+    ("yd", "[yd_i]"),
+    ("mi", "[mi_i]"),
+    // Time
+    ("sec", "s"),
+    ("secs", "s"),
+    ("mins", "min"),
+    ("hr", "h"),
+    ("hrs", "h"),
+    // Rates
+    ("gpa", "[gal_us]/[acr_us]"),
+    ("gpm", "[gal_us]/min"),
+    ("bpa", "[bu_us]/[acr_us]"),
+    ("mph", "[mi_i]/h"),
+    ("kph", "km/h"),
+    ("rpm", "{rev}/min"),
+    // Other
+    ("psi", "[psi]"),
+    ("hp", "[HP]"),
+    ("ppm", "[ppm]"),
+];
+
+/// A registry of free-form unit labels (aliases) and the UCUM expressions they stand for, applied
+/// before parsing.
+///
+/// Aliases are matched case-insensitively, against the whole expression or against each of its
+/// components. A component may have a leading factor or a trailing exponent (ex. with `ft` aliased
+/// to `[ft_i]`, `ft2` becomes `[ft_i]2`). Anything that doesn't match is left as is.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use wise_units::{unit::UnitAliases, Unit};
+///
+/// let mut aliases = UnitAliases::agricultural();
+/// aliases.insert("seeds", "{seed}").unwrap();
+///
+/// assert_eq!(aliases.resolve("lbs/ac"), "[lb_av]/[acr_us]");
+/// assert_eq!(aliases.resolve("1000seeds/ac"), "1000{seed}/[acr_us]");
+/// assert_eq!(
+///     aliases.parse("gpa").unwrap(),
+///     Unit::from_str("[gal_us]/[acr_us]").unwrap()
+/// );
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnitAliases {
+    aliases: HashMap<String, String>,
+}
+
+impl UnitAliases {
+    /// An empty registry.
+    ///
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry of common agricultural and equipment labels (ex. `lbs`, `ac`, `bu`, `gpa`).
+    ///
+    #[must_use]
+    pub fn agricultural() -> Self {
+        let aliases = AGRICULTURAL
+            .iter()
+            .map(|(alias, expression)| (alias.to_lowercase(), (*expression).to_string()))
+            .collect();
+
+        Self { aliases }
+    }
+
+    /// Adds (or replaces) `alias`, returning the expression it used to stand for, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidAlias` if `alias` is blank or `expression` isn't a valid UCUM
+    /// expression.
+    ///
+    #[allow(clippy::result_large_err)]
+    pub fn insert(&mut self, alias: &str, expression: &str) -> Result<Option<String>, Error> {
+        let alias = alias.trim();
+
+        if alias.is_empty() || Unit::from_str(expression).is_err() {
+            return Err(Error::InvalidAlias {
+                alias: alias.to_string(),
+                expression: expression.to_string(),
+            });
+        }
+
+        Ok(self
+            .aliases
+            .insert(alias.to_lowercase(), expression.to_string()))
+    }
+
+    /// Adds the aliases from `other`, replacing any that are already defined.
+    ///
+    pub fn extend(&mut self, other: Self) {
+        self.aliases.extend(other.aliases);
+    }
+
+    /// Removes `alias`, returning the expression it stood for, if any.
+    ///
+    pub fn remove(&mut self, alias: &str) -> Option<String> {
+        self.aliases.remove(&alias.trim().to_lowercase())
+    }
+
+    /// The UCUM expression that `alias` stands for, if any.
+    ///
+    #[must_use]
+    pub fn get(&self, alias: &str) -> Option<&str> {
+        self.aliases
+            .get(&alias.trim().to_lowercase())
+            .map(String::as_str)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.aliases.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    /// Adds the aliases in `toml`, a table of aliases to UCUM expressions (ex. `lbs = "[lb_av]"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidAliasTable` if `toml` isn't a table of strings, or
+    /// `Error::InvalidAlias` if one of the aliases is invalid (see `insert()`). In either case, no
+    /// aliases are added.
+    ///
+    #[cfg(feature = "toml")]
+    #[allow(clippy::result_large_err)]
+    pub fn extend_from_toml(&mut self, toml: &str) -> Result<(), Error> {
+        let table: HashMap<String, String> =
+            toml::from_str(toml).map_err(|error| Error::InvalidAliasTable(error.to_string()))?;
+
+        self.extend_from_table(table)
+    }
+
+    /// Adds the aliases in `json`, an object of aliases to UCUM expressions (ex.
+    /// `{"lbs": "[lb_av]"}`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidAliasTable` if `json` isn't an object of strings, or
+    /// `Error::InvalidAlias` if one of the aliases is invalid (see `insert()`). In either case, no
+    /// aliases are added.
+    ///
+    #[cfg(feature = "json")]
+    #[allow(clippy::result_large_err)]
+    pub fn extend_from_json(&mut self, json: &str) -> Result<(), Error> {
+        let table: HashMap<String, String> = serde_json::from_str(json)
+            .map_err(|error| Error::InvalidAliasTable(error.to_string()))?;
+
+        self.extend_from_table(table)
+    }
+
+    #[cfg(any(feature = "toml", feature = "json"))]
+    #[allow(clippy::result_large_err)]
+    fn extend_from_table(&mut self, table: HashMap<String, String>) -> Result<(), Error> {
+        let mut aliases = Self::new();
+
+        for (alias, expression) in table {
+            let _ = aliases.insert(&alias, &expression)?;
+        }
+
+        self.extend(aliases);
+
+        Ok(())
+    }
+
+    /// Replaces any aliases in `expression` with the UCUM expressions they stand for.
+    ///
+    #[must_use]
+    pub fn resolve<'a>(&self, expression: &'a str) -> Cow<'a, str> {
+        if let Some(resolved) = self.get(expression) {
+            return Cow::Owned(resolved.to_string());
+        }
+
+        let mut output = String::with_capacity(expression.len());
+        let mut changed = false;
+        let mut token_start = 0;
+        let mut chars = expression.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '.' | '/' | '(' | ')' | '{' => {
+                    let token = &expression[token_start..i];
+                    changed |= self.push_token(token, &mut output);
+
+                    if c == '{' {
+                        // Copy the annotation as is.
+                        let end = expression[i..]
+                            .find('}')
+                            .map_or(expression.len(), |offset| i + offset + 1);
+
+                        output.push_str(&expression[i..end]);
+
+                        while matches!(chars.peek(), Some(&(j, _)) if j < end) {
+                            let _ = chars.next();
+                        }
+
+                        token_start = end;
+                    } else {
+                        output.push(c);
+                        token_start = i + 1;
+                    }
+                }
+                '[' => {
+                    // Skip to the end of the bracketed atom; it's part of the current token.
+                    while matches!(chars.peek(), Some(&(_, c)) if c != ']') {
+                        let _ = chars.next();
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        changed |= self.push_token(&expression[token_start..], &mut output);
+
+        if changed {
+            Cow::Owned(output)
+        } else {
+            Cow::Borrowed(expression)
+        }
+    }
+
+    /// Pushes `token` to `output`, replacing it if it's an alias (possibly with a leading factor
+    /// and/or trailing exponent). Returns whether it was replaced.
+    ///
+    fn push_token(&self, token: &str, output: &mut String) -> bool {
+        if let Some(resolved) = self.get(token) {
+            push_resolved(output, "", resolved, "");
+            return true;
+        }
+
+        let token = token.trim();
+        let factor_end = token
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(token.len());
+        let exponent_start = token[factor_end..]
+            .rfind(|c: char| !c.is_ascii_digit())
+            .map_or(token.len(), |i| {
+                let i = factor_end + i + 1;
+
+                match token[..i].strip_suffix(['-', '+']) {
+                    Some(unsigned) if unsigned.len() > factor_end => unsigned.len(),
+                    _ => i,
+                }
+            });
+
+        let (factor, rest) = token.split_at(factor_end);
+        let (core, exponent) = rest.split_at(exponent_start - factor_end);
+
+        match self.get(core) {
+            Some(resolved)
+                if !core.is_empty()
+                    && (exponent.is_empty() || is_simple(resolved) && !resolved.contains('{')) =>
+            {
+                push_resolved(output, factor, resolved, exponent);
+                true
+            }
+            _ => {
+                output.push_str(token);
+                false
+            }
+        }
+    }
+
+    /// Resolves any aliases in `expression` (see `resolve()`), then parses it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resolved expression isn't a valid unit.
+    ///
+    #[allow(clippy::result_large_err)]
+    pub fn parse(&self, expression: &str) -> Result<Unit, Error> {
+        Unit::from_str(&self.resolve(expression))
+    }
+
+    /// Like `parse()`, but parses according to `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resolved expression isn't a valid unit.
+    ///
+    #[allow(clippy::result_large_err)]
+    pub fn parse_with_options(
+        &self,
+        expression: &str,
+        options: &ParseOptions,
+    ) -> Result<Unit, Error> {
+        Unit::from_str_with_options(&self.resolve(expression), options)
+    }
+}
+
+/// Whether `expression` is a single component (ex. `[lb_av]`, `{seed}`), as opposed to a product or
+/// quotient.
+///
+fn is_simple(expression: &str) -> bool {
+    !expression.contains(['.', '/', '(', ')'])
+}
+
+fn push_resolved(output: &mut String, factor: &str, resolved: &str, exponent: &str) {
+    output.push_str(factor);
+
+    if is_simple(resolved) {
+        output.push_str(resolved);
+        output.push_str(exponent);
+    } else {
+        output.push('(');
+        output.push_str(resolved);
+        output.push(')');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! validate_resolve {
+        ($test_name:ident, $expression:expr, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                let aliases = UnitAliases::agricultural();
+                assert_eq!(aliases.resolve($expression), $expected);
+            }
+        };
+    }
+
+    validate_resolve!(validate_resolve_whole, "gpa", "[gal_us]/[acr_us]");
+    validate_resolve!(validate_resolve_components, "lbs/ac", "[lb_av]/[acr_us]");
+    validate_resolve!(validate_resolve_case, "LBS/Ac", "[lb_av]/[acr_us]");
+    validate_resolve!(validate_resolve_factor, "100lbs", "100[lb_av]");
+    validate_resolve!(validate_resolve_exponent, "ft2", "[ft_i]2");
+    validate_resolve!(
+        validate_resolve_negative_exponent,
+        "lbs.ac-1",
+        "[lb_av].[acr_us]-1"
+    );
+    validate_resolve!(
+        validate_resolve_nested,
+        "gpa.(ac/hr)",
+        "([gal_us]/[acr_us]).([acr_us]/h)"
+    );
+    validate_resolve!(
+        validate_resolve_complex_factor,
+        "10gpa",
+        "10([gal_us]/[acr_us])"
+    );
+    validate_resolve!(validate_resolve_complex_exponent, "gpa2", "gpa2");
+    validate_resolve!(validate_resolve_spaces, "lbs / fl oz", "[lb_av]/[foz_us]");
+    validate_resolve!(
+        validate_resolve_annotation,
+        "bu{corn}/ac",
+        "[bu_us]{corn}/[acr_us]"
+    );
+    validate_resolve!(
+        validate_resolve_annotation_untouched,
+        "{lbs}/ac",
+        "{lbs}/[acr_us]"
+    );
+    validate_resolve!(
+        validate_resolve_ucum_untouched,
+        "kg/[acr_us]",
+        "kg/[acr_us]"
+    );
+    validate_resolve!(
+        validate_resolve_ucum_factor_untouched,
+        "10*3/ha",
+        "10*3/har"
+    );
+
+    #[test]
+    fn validate_resolve_borrows_unchanged() {
+        let aliases = UnitAliases::agricultural();
+
+        assert!(matches!(aliases.resolve("kg/m2"), Cow::Borrowed("kg/m2")));
+    }
+
+    #[test]
+    fn validate_agricultural_aliases_are_valid() {
+        for (alias, expression) in AGRICULTURAL {
+            assert!(
+                Unit::from_str(expression).is_ok(),
+                "{alias} => {expression}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_parse() {
+        let aliases = UnitAliases::agricultural();
+
+        assert_eq!(
+            aliases.parse("bu/ac").unwrap(),
+            Unit::from_str("[bu_us]/[acr_us]").unwrap()
+        );
+        assert!(aliases.parse("widgets").is_err());
+    }
+
+    #[test]
+    fn validate_insert() {
+        let mut aliases = UnitAliases::new();
+
+        assert_eq!(aliases.insert("T", "[ston_av]"), Ok(None));
+        assert_eq!(aliases.insert("t", "t"), Ok(Some("[ston_av]".to_string())));
+        assert_eq!(aliases.get("T"), Some("t"));
+        assert_eq!(
+            aliases.insert("bogus", "[nope]"),
+            Err(Error::InvalidAlias {
+                alias: "bogus".to_string(),
+                expression: "[nope]".to_string()
+            })
+        );
+        assert!(aliases.insert(" ", "m").is_err());
+        assert_eq!(aliases.remove("t"), Some("t".to_string()));
+        assert!(aliases.is_empty());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn validate_extend_from_toml() {
+        let mut aliases = UnitAliases::new();
+
+        aliases
+            .extend_from_toml("lbs = \"[lb_av]\"\n\"gal/ac\" = \"[gal_us]/[acr_us]\"\n")
+            .unwrap();
+
+        assert_eq!(aliases.resolve("gal/ac"), "[gal_us]/[acr_us]");
+        assert_eq!(aliases.resolve("lbs"), "[lb_av]");
+
+        assert!(matches!(
+            aliases.extend_from_toml("lbs = 1"),
+            Err(Error::InvalidAliasTable(_))
+        ));
+        assert!(matches!(
+            aliases.extend_from_toml("ac = \"[acr_us]\"\nbad = \"[nope]\""),
+            Err(Error::InvalidAlias { .. })
+        ));
+        assert_eq!(aliases.get("ac"), None);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn validate_extend_from_json() {
+        let mut aliases = UnitAliases::new();
+
+        aliases
+            .extend_from_json(r#"{"bu": "[bu_us]", "ac": "[acr_us]"}"#)
+            .unwrap();
+
+        assert_eq!(aliases.resolve("bu/ac"), "[bu_us]/[acr_us]");
+
+        assert!(matches!(
+            aliases.extend_from_json(r#"["bu"]"#),
+            Err(Error::InvalidAliasTable(_))
+        ));
+    }
+}
@@ -0,0 +1,103 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{is_compatible_with::IsCompatibleWith, ucum_unit::UcumUnit, unit::Unit};
+
+//-----------------------------------------------------------------------------
+// impl AbsDiffEq, RelativeEq, UlpsEq
+//-----------------------------------------------------------------------------
+/// Like `PartialEq`, `Unit`s are approximately equal if they're compatible and their `scalar()`
+/// values are (approximately) equal, but with a configurable tolerance.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use approx::{assert_relative_eq, relative_ne};
+/// use wise_units::Unit;
+///
+/// let unit = Unit::from_str("[ft_i]").unwrap();
+/// let other = Unit::from_str("12.[in_i]").unwrap();
+/// assert_relative_eq!(unit, other);
+///
+/// let unit = Unit::from_str("m").unwrap();
+/// let other = Unit::from_str("g").unwrap();
+/// assert!(relative_ne!(unit, other, max_relative = 1.0));
+/// ```
+///
+impl AbsDiffEq for Unit {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.is_compatible_with(other) && self.scalar().abs_diff_eq(&other.scalar(), epsilon)
+    }
+}
+
+impl RelativeEq for Unit {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.is_compatible_with(other)
+            && self
+                .scalar()
+                .relative_eq(&other.scalar(), epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for Unit {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.is_compatible_with(other) && self.scalar().ulps_eq(&other.scalar(), epsilon, max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use approx::{abs_diff_eq, relative_eq, ulps_eq};
+
+    use super::*;
+
+    fn unit(expression: &str) -> Unit {
+        Unit::from_str(expression).unwrap()
+    }
+
+    #[test]
+    fn validate_abs_diff_eq() {
+        assert!(abs_diff_eq!(unit("km"), unit("1000m")));
+        assert!(abs_diff_eq!(unit("m"), unit("1001mm"), epsilon = 0.01));
+        assert!(!abs_diff_eq!(unit("m"), unit("1001mm")));
+        assert!(!abs_diff_eq!(unit("m"), unit("g"), epsilon = 1.0));
+    }
+
+    #[test]
+    fn validate_relative_eq() {
+        assert!(relative_eq!(unit("[ft_i]"), unit("12.[in_i]")));
+        assert!(relative_eq!(
+            unit("[lb_av]"),
+            unit("454.g"),
+            max_relative = 0.001
+        ));
+        assert!(!relative_eq!(unit("[lb_av]"), unit("454.g")));
+        assert!(!relative_eq!(unit("m"), unit("g"), max_relative = 1.0));
+    }
+
+    #[test]
+    fn validate_ulps_eq() {
+        assert!(ulps_eq!(unit("km/h"), unit("1000m/h")));
+        assert!(!ulps_eq!(unit("km/h"), unit("1001m/h")));
+        assert!(!ulps_eq!(unit("m"), unit("g"), max_ulps = u32::MAX));
+    }
+}
@@ -0,0 +1,354 @@
+//! An optional, process-wide cache of parsed units, in front of `Unit::from_str()` (and so
+//! `Unit::try_from()`, `Measurement::try_new()`, and deserializing `Unit`s and `Measurement`s).
+//!
+//! It's disabled until given a capacity; this suits services that parse the same few hundred unit
+//! strings over and over:
+//!
+//! ```rust
+//! use std::str::FromStr;
+//! use wise_units::{unit::cache, Measurement, Unit};
+//!
+//! cache::set_capacity(1024);
+//!
+//! let kg = Unit::from_str("kg").unwrap();
+//! let mass = Measurement::try_new(2.0, "kg").unwrap();
+//! assert_eq!(mass.unit(), &kg);
+//!
+//! let stats = cache::stats();
+//! assert!(stats.hits >= 1);
+//! assert!(stats.len >= 1);
+//!
+//! cache::set_capacity(0);
+//! assert_eq!(cache::stats().len, 0);
+//! ```
+//!
+//! Only expressions parsed with the default `ParseOptions` are cached, and only if they're valid.
+//!
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        PoisonError, RwLock,
+    },
+};
+
+use crate::{Error, Unit};
+
+static CACHE: SharedCache = SharedCache::new();
+
+/// A snapshot of the cache's counters, from `cache::stats()`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CacheStats {
+    /// Lookups that found a cached unit.
+    ///
+    pub hits: u64,
+
+    /// Lookups that had to parse the expression.
+    ///
+    pub misses: u64,
+
+    /// The number of cached units.
+    ///
+    pub len: usize,
+
+    /// The most units the cache holds; `0` when it's disabled.
+    ///
+    pub capacity: usize,
+}
+
+/// Enables the cache, holding up to `capacity` units, or disables it (dropping all of its units)
+/// if `capacity` is `0`. When a full cache gets a new unit, it evicts the least recently used one.
+///
+pub fn set_capacity(capacity: usize) {
+    CACHE.set_capacity(capacity);
+}
+
+/// Removes all cached units and resets the hit and miss counters, without changing the capacity.
+///
+pub fn clear() {
+    CACHE.clear();
+}
+
+/// The cache's hit and miss counts (since the last `clear()`), size, and capacity.
+///
+#[must_use]
+pub fn stats() -> CacheStats {
+    CACHE.stats()
+}
+
+/// Gets the unit for `expression` from the cache, or parses it with `parse` (and caches it, if
+/// it's valid). Bypasses the cache entirely when it's disabled.
+///
+pub(super) fn get_or_parse<F>(expression: &str, parse: F) -> Result<Unit, Error>
+where
+    F: FnOnce(&str) -> Result<Unit, Error>,
+{
+    CACHE.get_or_parse(expression, parse)
+}
+
+/// The cache and its counters. `enabled` mirrors whether `cache` is `Some`, so that lookups can
+/// skip the lock while the cache is disabled.
+///
+struct SharedCache {
+    enabled: AtomicBool,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    cache: RwLock<Option<Cache>>,
+}
+
+impl SharedCache {
+    const fn new() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            cache: RwLock::new(None),
+        }
+    }
+
+    fn set_capacity(&self, capacity: usize) {
+        let mut units = self.cache.write().unwrap_or_else(PoisonError::into_inner);
+
+        if capacity == 0 {
+            *units = None;
+        } else if let Some(cache) = units.as_mut() {
+            cache.capacity = capacity;
+
+            while cache.units.len() > capacity {
+                cache.evict();
+            }
+        } else {
+            *units = Some(Cache::new(capacity));
+        }
+
+        // Still holding the lock, so this can't race with another `set_capacity()`.
+        self.enabled.store(capacity > 0, Ordering::Release);
+        drop(units);
+    }
+
+    fn clear(&self) {
+        if let Some(cache) = self
+            .cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            cache.units.clear();
+        }
+
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> CacheStats {
+        let units = self.cache.read().unwrap_or_else(PoisonError::into_inner);
+
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: units.as_ref().map_or(0, |cache| cache.units.len()),
+            capacity: units.as_ref().map_or(0, |cache| cache.capacity),
+        }
+    }
+
+    fn get_or_parse<F>(&self, expression: &str, parse: F) -> Result<Unit, Error>
+    where
+        F: FnOnce(&str) -> Result<Unit, Error>,
+    {
+        if !self.enabled.load(Ordering::Acquire) {
+            return parse(expression);
+        }
+
+        // The guard is dropped at the end of this statement, so `parse()` never runs under it.
+        let lookup = self
+            .cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .map(|cache| cache.get(expression));
+
+        match lookup {
+            None => return parse(expression),
+            Some(Some(unit)) => {
+                let _ = self.hits.fetch_add(1, Ordering::Relaxed);
+
+                return Ok(unit);
+            }
+            Some(None) => {}
+        }
+
+        let _ = self.misses.fetch_add(1, Ordering::Relaxed);
+        let unit = parse(expression)?;
+
+        if let Some(cache) = self
+            .cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            cache.insert(expression, unit.clone());
+        }
+
+        Ok(unit)
+    }
+}
+
+/// Units by expression, each stamped with when it was last used (by a `clock` that ticks on every
+/// lookup and insert), so a full cache can evict the least recently used one. Stamps are atomic so
+/// that hits only need the read lock.
+///
+struct Cache {
+    capacity: usize,
+    clock: AtomicU64,
+    units: HashMap<String, Entry>,
+}
+
+struct Entry {
+    unit: Unit,
+    last_used: AtomicU64,
+}
+
+impl Cache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clock: AtomicU64::new(0),
+            units: HashMap::new(),
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn get(&self, expression: &str) -> Option<Unit> {
+        let entry = self.units.get(expression)?;
+        let _ = entry.last_used.fetch_max(self.tick(), Ordering::Relaxed);
+
+        Some(entry.unit.clone())
+    }
+
+    fn insert(&mut self, expression: &str, unit: Unit) {
+        if self.units.contains_key(expression) {
+            return;
+        }
+
+        if self.units.len() >= self.capacity {
+            self.evict();
+        }
+
+        let last_used = AtomicU64::new(self.tick());
+        let _ = self
+            .units
+            .insert(expression.to_string(), Entry { unit, last_used });
+    }
+
+    /// Removes the least recently used unit. This scans every entry, which is cheap next to
+    /// parsing for the few hundred units the cache is meant for.
+    ///
+    fn evict(&mut self) {
+        let least_recently_used = self
+            .units
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+            .map(|(expression, _)| expression.clone());
+
+        if let Some(expression) = least_recently_used {
+            let _ = self.units.remove(&expression);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(expression: &str) -> Result<Unit, Error> {
+        Ok(Unit::new(crate::unit::parser::parse(expression)?))
+    }
+
+    #[test]
+    fn validate_insert() {
+        let mut cache = Cache::new(2);
+
+        cache.insert("m", Unit::new(vec![]));
+        cache.insert("g", Unit::new(vec![]));
+        cache.insert("m", Unit::new(vec![]));
+        assert_eq!(cache.units.len(), 2);
+
+        cache.insert("s", Unit::new(vec![]));
+        assert_eq!(cache.units.len(), 2);
+        assert!(cache.units.contains_key("s"));
+    }
+
+    #[test]
+    fn validate_evicts_least_recently_used() {
+        let mut cache = Cache::new(2);
+
+        cache.insert("m", Unit::new(vec![]));
+        cache.insert("g", Unit::new(vec![]));
+        assert!(cache.get("m").is_some());
+
+        cache.insert("s", Unit::new(vec![]));
+        assert!(cache.units.contains_key("m"));
+        assert!(!cache.units.contains_key("g"));
+        assert!(cache.units.contains_key("s"));
+
+        cache.insert("L", Unit::new(vec![]));
+        assert!(!cache.units.contains_key("m"));
+    }
+
+    #[test]
+    fn validate_get_or_parse() {
+        let cache = SharedCache::new();
+        cache.set_capacity(10);
+
+        let first = cache.get_or_parse("[lb_av]/[acr_us]", parse).unwrap();
+        let second = cache
+            .get_or_parse("[lb_av]/[acr_us]", |_| unreachable!("expected a hit"))
+            .unwrap();
+        assert_eq!(first, second);
+        assert_eq!(second.to_string(), "[lb_av]/[acr_us]");
+
+        assert!(cache.get_or_parse("not_a_unit", parse).is_err());
+        assert!(cache.get_or_parse("not_a_unit", parse).is_err());
+
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 3,
+                len: 1,
+                capacity: 10,
+            }
+        );
+
+        cache.clear();
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                capacity: 10,
+                ..CacheStats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn validate_disabled() {
+        let cache = SharedCache::new();
+
+        assert!(cache.get_or_parse("m", parse).is_ok());
+        assert!(cache.get_or_parse("m", parse).is_ok());
+        assert_eq!(cache.stats(), CacheStats::default());
+
+        cache.set_capacity(1);
+        assert!(cache.get_or_parse("m", parse).is_ok());
+        assert_eq!(cache.stats().len, 1);
+
+        cache.set_capacity(0);
+        assert!(cache.get_or_parse("m", parse).is_ok());
+        assert_eq!(cache.stats().len, 0);
+        assert_eq!(cache.stats().misses, 1);
+    }
+}
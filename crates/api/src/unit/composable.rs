@@ -6,7 +6,7 @@ use crate::{Composable, Composition, Unit};
 impl Composable for Unit {
     #[inline]
     fn composition(&self) -> Composition {
-        self.terms.composition()
+        self.memo.composition(|| self.terms.composition())
     }
 }
 
@@ -0,0 +1,447 @@
+#![allow(clippy::result_large_err)]
+
+use num_traits::One;
+
+use crate::{
+    reducible::Reducible, term::Exponent, Atom, Error, IsCompatibleWith, UcumSymbol, UcumUnit, Unit,
+};
+
+/// Converts values from one `Unit` to another.
+///
+/// `Unit::converter_to()` works out the conversion once, so use this for converting many values
+/// (ex. a column of readings); each call to `Measurement::convert_to()` checks compatibility and
+/// walks both units' `Term`s again.
+///
+/// Results match `Measurement::convert_to()`'s, except that conversions between temperature
+/// scales (ex. `Cel` to `[degF]`) are done as a single scale and offset, which can differ from it
+/// in the last few bits.
+///
+#[derive(Clone, Debug)]
+pub struct Converter(Conversion);
+
+#[derive(Clone, Debug)]
+enum Conversion {
+    /// Neither unit is special: `value * from_scalar / to_scalar`.
+    ///
+    Linear { from_scalar: f64, to_scalar: f64 },
+
+    /// `value * scale + offset` (ex. `Cel` to `K`).
+    ///
+    Affine { scale: f64, offset: f64 },
+
+    /// The special unit's functions (ex. `[pH]` to `mol/L`). `from` is `None` if the source unit
+    /// isn't special; `to` is `None` if the destination unit isn't.
+    ///
+    Special {
+        from: Option<Chain>,
+        to: Option<Chain>,
+    },
+}
+
+impl Converter {
+    fn new(from: &Unit, to: &Unit) -> Self {
+        let (from_special, to_special) = (from.is_special(), to.is_special());
+
+        if !from_special && !to_special {
+            return Self(Conversion::Linear {
+                from_scalar: from.scalar(),
+                to_scalar: to.scalar(),
+            });
+        }
+
+        let from_chain = from_special.then(|| Chain::reducing(from));
+        let to_chain = to_special.then(|| Chain::magnitude(to));
+
+        // Not being special is the same as being `value * 1 + 0`.
+        let affine = |chain: &Option<Chain>| chain.as_ref().map_or(Some((1.0, 0.0)), Chain::affine);
+
+        match (affine(&from_chain), affine(&to_chain)) {
+            (Some((from_scale, from_offset)), Some((to_scale, to_offset))) => {
+                Self(Conversion::Affine {
+                    scale: to_scale * from_scale,
+                    offset: to_scale.mul_add(from_offset, to_offset),
+                })
+            }
+            _ => Self(Conversion::Special {
+                from: from_chain,
+                to: to_chain,
+            }),
+        }
+    }
+
+    /// Converts `value` from the source unit to the destination unit.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let meters = Unit::from_str("m").unwrap();
+    /// let kilometers = Unit::from_str("km").unwrap();
+    /// let converter = meters.converter_to(&kilometers).unwrap();
+    ///
+    /// assert_eq!(converter.convert(1500.0), 1.5);
+    /// ```
+    ///
+    #[must_use]
+    pub fn convert(&self, value: f64) -> f64 {
+        match &self.0 {
+            Conversion::Linear {
+                from_scalar,
+                to_scalar,
+            } => value * from_scalar / to_scalar,
+            Conversion::Affine { scale, offset } => value.mul_add(*scale, *offset),
+            Conversion::Special { from, to } => {
+                let value = from.as_ref().map_or(value, |chain| chain.apply(value));
+
+                to.as_ref().map_or(value, |chain| chain.apply(value))
+            }
+        }
+    }
+
+    /// Converts each of `values` in place.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let celsius = Unit::from_str("Cel").unwrap();
+    /// let kelvin = Unit::from_str("K").unwrap();
+    /// let converter = celsius.converter_to(&kelvin).unwrap();
+    ///
+    /// let mut readings = [0.0, 25.0, -273.15];
+    /// converter.convert_slice(&mut readings);
+    ///
+    /// assert_eq!(readings, [273.15, 298.15, 0.0]);
+    /// ```
+    ///
+    pub fn convert_slice(&self, values: &mut [f64]) {
+        match &self.0 {
+            Conversion::Linear {
+                from_scalar,
+                to_scalar,
+            } => {
+                for value in values {
+                    *value = *value * from_scalar / to_scalar;
+                }
+            }
+            Conversion::Affine { scale, offset } => {
+                for value in values {
+                    *value = value.mul_add(*scale, *offset);
+                }
+            }
+            Conversion::Special { .. } => {
+                for value in values {
+                    *value = self.convert(*value);
+                }
+            }
+        }
+    }
+}
+
+impl Unit {
+    /// Checks that `self` can be converted to `other`, then works out how to convert values
+    /// between them, for converting many values with the returned `Converter`.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let bushels_per_acre = Unit::from_str("[bu_us]/[acr_us]").unwrap();
+    /// let liters_per_hectare = Unit::from_str("L/har").unwrap();
+    /// let converter = bushels_per_acre.converter_to(&liters_per_hectare).unwrap();
+    ///
+    /// assert!((converter.convert(1.0) - 87.077_3).abs() < 0.000_1);
+    ///
+    /// let kilograms = Unit::from_str("kg").unwrap();
+    /// assert!(kilograms.converter_to(&liters_per_hectare).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IncompatibleUnitTypes` if `self` and `other` aren't compatible.
+    ///
+    pub fn converter_to(&self, other: &Self) -> Result<Converter, Error> {
+        if self.is_compatible_with(other) {
+            Ok(Converter::new(self, other))
+        } else {
+            Err(Error::IncompatibleUnitTypes {
+                lhs: self.expression(),
+                rhs: other.expression(),
+            })
+        }
+    }
+}
+
+/// A special unit's `reduce_value()` or `calculate_magnitude()`: the product of a `Step` for each
+/// of its `Term`s, in order.
+///
+#[derive(Clone, Debug)]
+struct Chain(Vec<Step>);
+
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    /// A `Term` without a special atom, whose value doesn't depend on the value being converted.
+    ///
+    Constant(f64),
+
+    /// A `Term` with a special atom: `(coefficient * function(value))^exponent`. `affine` is the
+    /// function's `(scale, offset)`, if it's affine.
+    ///
+    Function {
+        coefficient: f64,
+        function: fn(f64) -> f64,
+        exponent: Exponent,
+        affine: Option<(f64, f64)>,
+    },
+}
+
+impl Chain {
+    fn reducing(unit: &Unit) -> Self {
+        Self::new(unit, Direction::Reducing)
+    }
+
+    fn magnitude(unit: &Unit) -> Self {
+        Self::new(unit, Direction::Magnitude)
+    }
+
+    fn new(unit: &Unit, direction: Direction) -> Self {
+        let steps = unit
+            .terms()
+            .iter()
+            .map(|term| {
+                let special = term.atom().filter(UcumUnit::is_special).and_then(|atom| {
+                    atom.definition()
+                        .function_set()
+                        .map(|function_set| match direction {
+                            Direction::Reducing => (atom, function_set.convert_to),
+                            Direction::Magnitude => (atom, function_set.convert_from),
+                        })
+                });
+
+                match special {
+                    Some((atom, function)) => Step::Function {
+                        coefficient: term.factor().map_or(1.0, f64::from)
+                            * term
+                                .prefix()
+                                .map_or(1.0, |prefix| prefix.definition_value()),
+                        function,
+                        exponent: term.exponent().unwrap_or(1),
+                        affine: direction.affine(atom),
+                    },
+                    None => Step::Constant(match direction {
+                        Direction::Reducing => term.reduce_value(One::one()),
+                        Direction::Magnitude => term.calculate_magnitude(One::one()),
+                    }),
+                }
+            })
+            .collect();
+
+        Self(steps)
+    }
+
+    fn apply(&self, value: f64) -> f64 {
+        self.0.iter().fold(One::one(), |acc, step| match *step {
+            Step::Constant(constant) => acc * constant,
+            Step::Function {
+                coefficient,
+                function,
+                exponent,
+                ..
+            } => acc * (coefficient * function(value)).powi(exponent),
+        })
+    }
+
+    /// `Some((scale, offset))` if this is `value * scale + offset`: it has one `Step::Function`,
+    /// which is affine and has no exponent.
+    ///
+    fn affine(&self) -> Option<(f64, f64)> {
+        let mut constant = 1.0;
+        let mut affine = None;
+
+        for step in &self.0 {
+            match *step {
+                Step::Constant(value) => constant *= value,
+                Step::Function {
+                    coefficient,
+                    exponent: 1,
+                    affine: Some((scale, offset)),
+                    ..
+                } if affine.is_none() => affine = Some((coefficient * scale, coefficient * offset)),
+                Step::Function { .. } => return None,
+            }
+        }
+
+        affine.map(|(scale, offset)| (constant * scale, constant * offset))
+    }
+}
+
+const FAHRENHEIT_TO_KELVIN_SCALE: f64 = 5.0 / 9.0;
+const FAHRENHEIT_TO_KELVIN_OFFSET: f64 = FAHRENHEIT_TO_KELVIN_SCALE * 459.67;
+
+#[derive(Clone, Copy)]
+enum Direction {
+    /// `reduce_value()`, which uses each special atom's `convert_to` function.
+    ///
+    Reducing,
+
+    /// `calculate_magnitude()`, which uses each special atom's `convert_from` function.
+    ///
+    Magnitude,
+}
+
+impl Direction {
+    /// The temperature scales' functions, which are affine, as `(scale, offset)`.
+    ///
+    const fn affine(self, atom: Atom) -> Option<(f64, f64)> {
+        match (self, atom) {
+            (Self::Reducing, Atom::DegreeCelsius) => Some((1.0, 273.15)),
+            (Self::Reducing, Atom::DegreeFahrenheit) => {
+                Some((FAHRENHEIT_TO_KELVIN_SCALE, FAHRENHEIT_TO_KELVIN_OFFSET))
+            }
+            (Self::Reducing, Atom::DegreeReaumur) => Some((1.25, 273.15)),
+            (Self::Magnitude, Atom::DegreeCelsius) => Some((1.0, -273.15)),
+            (Self::Magnitude, Atom::DegreeFahrenheit) => Some((1.8, -459.67)),
+            (Self::Magnitude, Atom::DegreeReaumur) => Some((0.8, -218.52)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use approx::assert_relative_eq;
+
+    use crate::{Convertible, Measurement};
+
+    use super::*;
+
+    fn converter(from: &str, to: &str) -> Converter {
+        Unit::from_str(from)
+            .unwrap()
+            .converter_to(&Unit::from_str(to).unwrap())
+            .unwrap()
+    }
+
+    macro_rules! validate_convert {
+        ($test_name:ident, $from:expr, $to:expr, $variant:pat, $($value:expr),+) => {
+            #[test]
+            fn $test_name() {
+                let converter = converter($from, $to);
+                assert!(matches!(converter.0, $variant), "{converter:?}");
+
+                let mut values = [$($value),+];
+                converter.convert_slice(&mut values);
+
+                for (value, converted) in [$($value),+].into_iter().zip(values) {
+                    let expected = Measurement::try_new(value, $from)
+                        .unwrap()
+                        .convert_to($to)
+                        .unwrap()
+                        .value();
+
+                    assert_relative_eq!(converter.convert(value), expected, max_relative = 1e-12);
+                    assert_relative_eq!(converted, expected, max_relative = 1e-12);
+                }
+            }
+        };
+    }
+
+    validate_convert!(
+        validate_m_to_km,
+        "m",
+        "km",
+        Conversion::Linear { .. },
+        0.0,
+        1.0,
+        1500.0
+    );
+    validate_convert!(
+        validate_bu_per_acr_to_l_per_har,
+        "[bu_us]/[acr_us]",
+        "L/har",
+        Conversion::Linear { .. },
+        1.0,
+        182.5
+    );
+    validate_convert!(
+        validate_cel_to_k,
+        "Cel",
+        "K",
+        Conversion::Affine { .. },
+        -40.0,
+        25.0
+    );
+    validate_convert!(
+        validate_k_to_cel,
+        "K",
+        "Cel",
+        Conversion::Affine { .. },
+        0.0,
+        298.15
+    );
+    validate_convert!(
+        validate_degf_to_cel,
+        "[degF]",
+        "Cel",
+        Conversion::Affine { .. },
+        -40.0,
+        98.6
+    );
+    validate_convert!(
+        validate_cel_to_degre,
+        "Cel",
+        "[degRe]",
+        Conversion::Affine { .. },
+        -40.0,
+        125.0
+    );
+    validate_convert!(
+        validate_ph_to_mol_per_l,
+        "[pH]",
+        "mol/l",
+        Conversion::Special { .. },
+        1.0,
+        7.0
+    );
+    validate_convert!(
+        validate_mol_per_l_to_ph,
+        "mol/l",
+        "[pH]",
+        Conversion::Special { .. },
+        0.1,
+        1e-7
+    );
+    validate_convert!(
+        validate_b_w_to_w,
+        "B[W]",
+        "W",
+        Conversion::Special { .. },
+        1.0,
+        3.0
+    );
+
+    #[test]
+    fn validate_special_matches_convert_to_exactly() {
+        let converter = converter("[pH]", "mol/l");
+        let expected = Measurement::try_new(7.0, "[pH]")
+            .unwrap()
+            .convert_to("mol/l")
+            .unwrap()
+            .value();
+
+        assert_eq!(converter.convert(7.0).to_bits(), expected.to_bits());
+    }
+
+    #[test]
+    fn validate_incompatible() {
+        let meter = Unit::from_str("m").unwrap();
+        let second = Unit::from_str("s").unwrap();
+
+        assert!(matches!(
+            meter.converter_to(&second),
+            Err(Error::IncompatibleUnitTypes { lhs, rhs }) if lhs == "m" && rhs == "s"
+        ));
+    }
+}
@@ -7,7 +7,41 @@ use crate::{Term, Unit};
 //-----------------------------------------------------------------------------
 impl fmt::Display for Unit {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (numerators, denominators) = decompose(&self.terms);
+        let (numerators, denominators) = decompose(&self.terms, Term::as_cow_str);
+
+        write!(f, "{}", recompose(&numerators, &denominators))
+    }
+}
+
+impl Unit {
+    /// Displays `self` using UCUM's case-insensitive codes (ex. `MG/DL` instead of `mg/dL`), for
+    /// systems that can't preserve case. Atoms that don't have a case-insensitive code fall back to
+    /// their case-sensitive one.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let unit = Unit::from_str("mg/dL").unwrap();
+    /// assert_eq!(unit.display_case_insensitive().to_string(), "MG/DL");
+    /// ```
+    ///
+    #[must_use]
+    pub const fn display_case_insensitive(&self) -> CaseInsensitiveDisplay<'_> {
+        CaseInsensitiveDisplay(self)
+    }
+}
+
+/// Helper for displaying a `Unit` with case-insensitive codes; see
+/// `Unit::display_case_insensitive()`.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct CaseInsensitiveDisplay<'a>(&'a Unit);
+
+impl fmt::Display for CaseInsensitiveDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (numerators, denominators) =
+            decompose(&self.0.terms, Term::as_case_insensitive_cow_str);
 
         write!(f, "{}", recompose(&numerators, &denominators))
     }
@@ -16,25 +50,25 @@ impl fmt::Display for Unit {
 /// Turns `terms` into two groups of strings: one for numerator terms, one for denominator terms.
 /// These just need to be properly jointed by dots and slashes.
 ///
-fn decompose(terms: &[Term]) -> (Vec<Cow<'_, str>>, Vec<String>) {
+fn decompose(
+    terms: &[Term],
+    render: for<'t> fn(&'t Term) -> Cow<'t, str>,
+) -> (Vec<Cow<'_, str>>, Vec<String>) {
     let mut numerators = Vec::new();
     let mut denominators = Vec::new();
 
     for term in terms {
         match term.exponent() {
             Some(exponent) if exponent.is_positive() => {
-                numerators.push(term.as_cow_str());
+                numerators.push(render(term));
             }
             Some(exponent) => {
                 let mut positive_exponent_term = term.clone();
 
-                denominators.push(
-                    positive_exponent_term
-                        .set_exponent(exponent.abs())
-                        .to_string(),
-                );
+                let _ = positive_exponent_term.set_exponent(exponent.abs());
+                denominators.push(render(&positive_exponent_term).into_owned());
             }
-            None => numerators.push(term.as_cow_str()),
+            None => numerators.push(render(term)),
         }
     }
 
@@ -191,4 +225,24 @@ mod tests {
     test_display!(test_pa_per_ae_dot_pa: parse_unit!("km/m2.cm") => "km/m2.cm");
     test_display!(test_pa_per_anegativee_dot_pa: parse_unit!("km/m-2.cm") => "km.m2/cm");
     test_display!(test_pa_negative_1_per_ae_dot_pa: parse_unit!("km-1/m2.cm") => "/km.m2.cm");
+
+    macro_rules! test_display_case_insensitive {
+        ($test_name:ident: $unit:expr => $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                assert_eq!($unit.display_case_insensitive().to_string(), $expected);
+            }
+        };
+    }
+
+    test_display_case_insensitive!(test_ci_unity: UNITY => "1");
+    test_display_case_insensitive!(test_ci_atom: METER => "M");
+    test_display_case_insensitive!(test_ci_prefix_atom: parse_unit!("mg") => "MG");
+    test_display_case_insensitive!(test_ci_bracketed_atom: parse_unit!("[lb_av]") => "[LB_AV]");
+    test_display_case_insensitive!(test_ci_factor_prefix_atom_exponent_annotation:
+        parse_unit!("10km3{stuff}") => "10KM3{stuff}");
+    test_display_case_insensitive!(test_ci_per: parse_unit!("mg/dL") => "MG/DL");
+    test_display_case_insensitive!(test_ci_negative_exponent: parse_unit!("m.s-2") => "M/S2");
+    test_display_case_insensitive!(test_ci_factor: parse_unit!("/42") => "/42");
+    test_display_case_insensitive!(test_ci_annotation: parse_unit!("{Stuff}") => "{Stuff}");
 }
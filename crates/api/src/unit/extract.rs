@@ -0,0 +1,308 @@
+use std::ops::Range;
+
+use crate::Unit;
+
+use super::UnitAliases;
+
+/// A unit expression found in a label (ex. a spreadsheet column header), by `Unit::extract()` or
+/// `UnitAliases::extract()`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtractedUnit {
+    /// The byte range of the unit expression in the label (ex. `7..12` in `Yield (bu/ac)`). This
+    /// doesn't include any brackets or delimiters around the expression.
+    ///
+    pub span: Range<usize>,
+
+    pub unit: Unit,
+
+    /// What's left of the label without the unit expression and the brackets or delimiter around
+    /// it (ex. `Yield`).
+    ///
+    pub label: String,
+}
+
+impl Unit {
+    /// Finds and parses a unit expression in `label`, such as a column header.
+    ///
+    /// Looks for, in order: an expression in parentheses or square brackets (ex. `Rate (kg/har)`),
+    /// an expression after the last comma, colon, or semicolon (ex. `N applied, [lb_av]/[acr_us]`),
+    /// then any whitespace-separated word that has an operator, digit, or bracket in it (ex.
+    /// `Rate kg/m2`). Candidates must parse as UCUM, and must include at least one atom or
+    /// annotation. Use `UnitAliases::extract()` to also match labels like `bu/ac`.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let extracted = Unit::extract("Rate [kg/har]").unwrap();
+    ///
+    /// assert_eq!(extracted.span, 6..12);
+    /// assert_eq!(extracted.unit, Unit::from_str("kg/har").unwrap());
+    /// assert_eq!(extracted.label, "Rate");
+    ///
+    /// assert!(Unit::extract("Field name").is_none());
+    /// ```
+    ///
+    #[must_use]
+    pub fn extract(label: &str) -> Option<ExtractedUnit> {
+        extract(label, |expression| expression.parse().ok())
+    }
+}
+
+impl UnitAliases {
+    /// Like `Unit::extract()`, but resolves aliases in each candidate expression before parsing it.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::{unit::UnitAliases, Unit};
+    ///
+    /// let aliases = UnitAliases::agricultural();
+    /// let extracted = aliases.extract("N applied, lb/acre").unwrap();
+    ///
+    /// assert_eq!(extracted.span, 11..18);
+    /// assert_eq!(extracted.unit, Unit::from_str("[lb_av]/[acr_us]").unwrap());
+    /// assert_eq!(extracted.label, "N applied");
+    /// ```
+    ///
+    #[must_use]
+    pub fn extract(&self, label: &str) -> Option<ExtractedUnit> {
+        extract(label, |expression| self.parse(expression).ok())
+    }
+}
+
+fn extract<F>(label: &str, parse: F) -> Option<ExtractedUnit>
+where
+    F: Fn(&str) -> Option<Unit>,
+{
+    let parse = |span: Range<usize>| {
+        let expression = &label[span.clone()];
+
+        parse(expression)
+            .filter(has_unit)
+            .map(|unit| (trim_span(label, span), unit))
+    };
+
+    // 1. An expression in parentheses or square brackets, starting with the last group. For
+    //    square brackets, also try the group as a whole, since UCUM uses them for some atoms (ex.
+    //    `[lb_av]`).
+    for (open, close) in groups(label).into_iter().rev() {
+        let inner = trim_span(label, open + 1..close);
+
+        if let Some((span, unit)) = parse(inner) {
+            return Some(build(label, span, open..close + 1, unit));
+        }
+
+        if label.as_bytes()[open] == b'[' {
+            if let Some((span, unit)) = parse(open..close + 1) {
+                return Some(build(label, span.clone(), span, unit));
+            }
+        }
+    }
+
+    // 2. An expression after the last delimiter.
+    if let Some(delimiter) = label.rfind([',', ':', ';']) {
+        if let Some((span, unit)) = parse(trim_span(label, delimiter + 1..label.len())) {
+            return Some(build(label, span.clone(), delimiter..span.end, unit));
+        }
+    }
+
+    // 3. A word that looks like a unit expression, starting with the last one.
+    words(label)
+        .into_iter()
+        .rev()
+        .filter(|word| label[word.clone()].contains(is_expression_char))
+        .find_map(parse)
+        .map(|(span, unit)| build(label, span.clone(), span, unit))
+}
+
+/// Whether `unit` has anything besides numbers in it, so that ex. years aren't extracted.
+///
+fn has_unit(unit: &Unit) -> bool {
+    unit.terms
+        .iter()
+        .any(|term| term.atom().is_some() || term.annotation().is_some())
+}
+
+const fn is_expression_char(c: char) -> bool {
+    matches!(c, '/' | '.' | '[' | '{' | '0'..='9')
+}
+
+/// The byte offsets of each top-level pair of matching parentheses or square brackets.
+///
+fn groups(label: &str) -> Vec<(usize, usize)> {
+    let mut groups = Vec::new();
+    let mut stack: Vec<(usize, u8)> = Vec::new();
+
+    for (i, byte) in label.bytes().enumerate() {
+        match byte {
+            b'(' | b'[' => stack.push((i, byte)),
+            b')' | b']' => {
+                let expected = if byte == b')' { b'(' } else { b'[' };
+
+                match stack.pop() {
+                    Some((start, open)) if open == expected => {
+                        if stack.is_empty() {
+                            groups.push((start, i));
+                        }
+                    }
+                    _ => stack.clear(),
+                }
+            }
+            _ => (),
+        }
+    }
+
+    groups
+}
+
+/// The byte ranges of each whitespace-separated word, without any trailing punctuation.
+///
+fn words(label: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (i, c) in label
+        .char_indices()
+        .chain(std::iter::once((label.len(), ' ')))
+    {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(i),
+            (Some(word_start), true) => {
+                let word = &label[word_start..i];
+                let end = word_start + word.trim_end_matches(['!', '?', ',', ';', ':']).len();
+
+                words.push(word_start..end);
+                start = None;
+            }
+            _ => (),
+        }
+    }
+
+    words
+}
+
+fn trim_span(label: &str, span: Range<usize>) -> Range<usize> {
+    let s = &label[span.clone()];
+    let start = span.start + (s.len() - s.trim_start().len());
+    let end = span.end - (s.len() - s.trim_end().len());
+
+    start..end.max(start)
+}
+
+/// Builds the result, removing `removed` (the expression plus any brackets or delimiter) from the
+/// label.
+///
+fn build(label: &str, span: Range<usize>, removed: Range<usize>, unit: Unit) -> ExtractedUnit {
+    let before = label[..removed.start].trim_end();
+    let after = label[removed.end..].trim_start();
+
+    let label = match (before.is_empty(), after.is_empty()) {
+        (_, true) => before.to_string(),
+        (true, false) => after.to_string(),
+        (false, false) => format!("{before} {after}"),
+    };
+
+    ExtractedUnit { span, unit, label }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    macro_rules! validate_extract {
+        ($test_name:ident, $label:expr, $span:expr, $expression:expr, $rest:expr) => {
+            #[test]
+            fn $test_name() {
+                let extracted = UnitAliases::agricultural().extract($label).unwrap();
+
+                assert_eq!(extracted.span, $span);
+                assert_eq!(extracted.unit, Unit::from_str($expression).unwrap());
+                assert_eq!(extracted.label, $rest);
+            }
+        };
+    }
+
+    validate_extract!(
+        validate_parentheses,
+        "Yield (bu/ac)",
+        7..12,
+        "[bu_us]/[acr_us]",
+        "Yield"
+    );
+    validate_extract!(
+        validate_square_brackets,
+        "Rate [kg/ha]",
+        6..11,
+        "kg/har",
+        "Rate"
+    );
+    validate_extract!(
+        validate_delimiter,
+        "N applied, lb/acre",
+        11..18,
+        "[lb_av]/[acr_us]",
+        "N applied"
+    );
+    validate_extract!(
+        validate_colon,
+        "Seed rate: 1000{seed}/ac",
+        11..24,
+        "1000{seed}/[acr_us]",
+        "Seed rate"
+    );
+    validate_extract!(
+        validate_word,
+        "Applied kg/ha total",
+        8..13,
+        "kg/har",
+        "Applied total"
+    );
+    validate_extract!(
+        validate_last_group,
+        "Moisture (2019) (%)",
+        17..18,
+        "%",
+        "Moisture (2019)"
+    );
+    validate_extract!(
+        validate_ucum_brackets,
+        "Weight [lb_av]",
+        7..14,
+        "[lb_av]",
+        "Weight"
+    );
+    validate_extract!(
+        validate_padded_group,
+        "Depth ( in )",
+        8..10,
+        "[in_i]",
+        "Depth"
+    );
+    validate_extract!(validate_unit_only, "(gpa)", 1..4, "[gal_us]/[acr_us]", "");
+
+    #[test]
+    fn validate_not_found() {
+        let aliases = UnitAliases::agricultural();
+
+        assert_eq!(aliases.extract("Field name"), None);
+        assert_eq!(aliases.extract("Harvest (2019)"), None);
+        assert_eq!(aliases.extract(""), None);
+    }
+
+    #[test]
+    fn validate_ucum_only() {
+        let extracted = Unit::extract("Density (g/cm3)").unwrap();
+
+        assert_eq!(extracted.span, 9..14);
+        assert_eq!(extracted.unit, Unit::from_str("g/cm3").unwrap());
+        assert_eq!(extracted.label, "Density");
+
+        // Words without operators, digits, or brackets aren't matched, since many are valid UCUM
+        // (ex. `N`, `a`, `Pa`).
+        assert_eq!(Unit::extract("N applied"), None);
+        assert_eq!(Unit::extract("Yield (bu/ac)"), None);
+    }
+}
@@ -2,6 +2,8 @@ use std::str::FromStr;
 
 use crate::{Error, Unit};
 
+use super::{NamedUnit, ParseOptions};
+
 //-----------------------------------------------------------------------------
 // impl FromStr
 //-----------------------------------------------------------------------------
@@ -10,13 +12,102 @@ impl FromStr for Unit {
 
     #[inline]
     fn from_str(expression: &str) -> Result<Self, Self::Err> {
-        Ok(Self::new(super::parser::parse(expression)?))
+        super::cache::get_or_parse(expression, |expression| {
+            Ok(Self::new(super::parser::parse(expression)?))
+        })
+    }
+}
+
+impl Unit {
+    /// Like `Unit::from_str()`, but parses `expression` according to `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `expression` isn't a valid unit under `options`.
+    ///
+    #[allow(clippy::result_large_err)]
+    #[inline]
+    pub fn from_str_with_options(expression: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(Self::new(super::parser::parse_with_options(
+            expression, *options,
+        )?))
+    }
+
+    /// Like `Unit::from_str()`, but also accepts "print" notation, like what gets copied from PDFs
+    /// and spreadsheets: print symbols (ex. `°C`, `µg`, `Å`), superscript exponents (ex. `m²`,
+    /// `s⁻¹`), `·` for multiplication, and `^`/`**` exponents (ex. `m^2`).
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// assert_eq!(Unit::from_print_str("µg·m⁻³").unwrap(), Unit::from_str("ug.m-3").unwrap());
+    /// assert_eq!(Unit::from_print_str("°C").unwrap(), Unit::from_str("Cel").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `expression` isn't a valid unit after normalizing.
+    ///
+    #[allow(clippy::result_large_err)]
+    #[inline]
+    pub fn from_print_str(expression: &str) -> Result<Self, Error> {
+        Self::from_str_with_options(expression, &ParseOptions::default().print_notation(true))
+    }
+
+    /// Parses `expression` into a syntax tree that keeps its structure and source spans, for
+    /// tools like syntax highlighters and editors. See `unit::ast`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `expression` isn't a valid unit.
+    ///
+    #[allow(clippy::result_large_err)]
+    #[inline]
+    pub fn parse_ast(expression: &str) -> Result<super::ast::Expression, Error> {
+        Ok(super::parser::parse_ast(expression)?)
+    }
+
+    /// Parses an English unit expression, like "kilograms per hectare", "pounds per acre", or "cubic
+    /// meters", using each `Atom`'s and `Prefix`'s `names()`.
+    ///
+    /// Names may be plural, and may be combined with "per" (or `/`), "times", "square"/"cubic", and
+    /// "squared"/"cubed". Since some names are used by more than one atom (ex. "ounce", "foot"),
+    /// the result lists those ambiguities along with the unit.
+    ///
+    /// Like UCUM's `/` and `.`, operators apply left to right: "per" divides by the names after it
+    /// (ex. "watts per meter kelvin" is `W/(m.K)`) until a "times" (ex. "pounds per acre times
+    /// hours" is `[lb_av].h/[acr_us]`).
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::Unit;
+    ///
+    /// let named = Unit::from_name("kilograms per hectare").unwrap();
+    /// assert_eq!(named.unit, Unit::from_str("kg/har").unwrap());
+    /// assert!(named.ambiguities.is_empty());
+    ///
+    /// let named = Unit::from_name("pounds per acre").unwrap();
+    /// assert_eq!(named.unit, Unit::from_str("[lb_av]/[acr_us]").unwrap());
+    /// assert_eq!(named.ambiguities[0].name, "pounds");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `expression` contains a word that isn't a unit name, or a dangling "per".
+    ///
+    #[allow(clippy::result_large_err)]
+    #[inline]
+    pub fn from_name(expression: &str) -> Result<NamedUnit, Error> {
+        Ok(super::parser::parse_names(expression)?)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{FromStr, Unit};
+    use crate::{unit::CaseMode, Atom, Term, UcumSymbol};
+
+    use super::{Error, FromStr, ParseOptions, Unit};
 
     #[test]
     fn validate_from_str_error() {
@@ -24,6 +115,15 @@ mod tests {
         assert!(unit.is_err());
     }
 
+    #[test]
+    fn validate_pico_prefix() {
+        let unit = Unit::from_str("pg").unwrap();
+        assert_eq!(unit.expression(), "pg");
+
+        let options = ParseOptions::default().case_mode(CaseMode::Insensitive);
+        assert_eq!(Unit::from_str_with_options("PG", &options).unwrap(), unit);
+    }
+
     #[test]
     fn validate_annotation() {
         let unit = Unit::from_str("{foo}").unwrap();
@@ -31,4 +131,173 @@ mod tests {
 
         assert_eq!(term.annotation(), Some("foo"));
     }
+
+    #[test]
+    fn validate_case_mode_both() {
+        let options = ParseOptions::default();
+
+        assert_eq!(
+            Unit::from_str_with_options("mg", &options).unwrap(),
+            parse_unit!("mg")
+        );
+        assert_eq!(
+            Unit::from_str_with_options("L", &options).unwrap(),
+            parse_unit!("L")
+        );
+        assert_eq!(
+            Unit::from_str_with_options("[LB_AV]", &options).unwrap(),
+            parse_unit!("[lb_av]")
+        );
+    }
+
+    #[test]
+    fn validate_case_mode_sensitive() {
+        let options = ParseOptions::default().case_mode(CaseMode::Sensitive);
+
+        assert_eq!(
+            Unit::from_str_with_options("mg/dL", &options).unwrap(),
+            parse_unit!("mg/dL")
+        );
+        assert_eq!(
+            Unit::from_str_with_options("MG", &options).unwrap(),
+            parse_unit!("MG")
+        );
+        assert!(Unit::from_str_with_options("[LB_AV]", &options).is_err());
+    }
+
+    #[test]
+    fn validate_case_mode_insensitive() {
+        let options = ParseOptions::default().case_mode(CaseMode::Insensitive);
+
+        assert_eq!(
+            Unit::from_str_with_options("MG", &options).unwrap(),
+            parse_unit!("mg")
+        );
+        assert_eq!(
+            Unit::from_str_with_options("mg/dl", &options).unwrap(),
+            parse_unit!("mg/dL")
+        );
+        assert_eq!(
+            Unit::from_str_with_options("[lb_Av]", &options).unwrap(),
+            parse_unit!("[lb_av]")
+        );
+        assert_eq!(
+            Unit::from_str_with_options("10*3{Cells}/UL", &options).unwrap(),
+            parse_unit!("10*3{Cells}/uL")
+        );
+    }
+
+    #[test]
+    fn validate_case_insensitive_round_trip() {
+        let options = ParseOptions::default().case_mode(CaseMode::Insensitive);
+
+        for expression in ["kg/m2", "mmol/L", "[in_i]", "km/h", "10*3/uL", "Cel", "%"] {
+            let unit = Unit::from_str(expression).unwrap();
+            let rendered = unit.display_case_insensitive().to_string();
+
+            assert_eq!(
+                Unit::from_str_with_options(&rendered, &options).unwrap(),
+                unit,
+                "{expression} -> {rendered}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_case_insensitive_round_trip_every_atom() {
+        let options = ParseOptions::default().case_mode(CaseMode::Insensitive);
+
+        for atom in Atom::ALL {
+            // Some codes with digits (ex. `[eps_0]`, `cal_[15]`) don't parse in any case mode.
+            if Unit::from_str(atom.primary_code()).is_err() {
+                continue;
+            }
+
+            let unit = Unit::new(vec![Term::Atom(atom)]);
+            let rendered = unit.display_case_insensitive().to_string();
+
+            assert_eq!(
+                Unit::from_str_with_options(&rendered, &options).unwrap(),
+                unit,
+                "{atom:?} -> {rendered}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_from_print_str() {
+        assert_eq!(
+            Unit::from_print_str("kg·m⁻²").unwrap(),
+            parse_unit!("kg.m-2")
+        );
+        assert_eq!(
+            Unit::from_print_str("10³/µL").unwrap(),
+            parse_unit!("10*3/uL")
+        );
+        assert_eq!(Unit::from_print_str("m^2").unwrap(), parse_unit!("m2"));
+        assert_eq!(Unit::from_print_str("Å").unwrap(), parse_unit!("Ao"));
+        assert!(Unit::from_str("°C").is_err());
+
+        match Unit::from_print_str("2²/L") {
+            Err(Error::ParsingFailed(error)) => assert_eq!(
+                error.diagnostic().map(|diagnostic| diagnostic.kind),
+                Some(crate::unit::DiagnosticKind::BadExponent)
+            ),
+            other => panic!("expected a BadExponent error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn validate_from_print_str_diagnostic_span() {
+        for (expression, span, fragment) in [
+            ("m²·s⁻¹·kgg", 13..16, "kgg"),
+            ("µgg/L", 0..4, "µgg"),
+            ("m²/", 3..4, "/"),
+        ] {
+            match Unit::from_print_str(expression) {
+                Err(Error::ParsingFailed(error)) => {
+                    let diagnostic = error.diagnostic().unwrap();
+
+                    assert_eq!(diagnostic.span, span, "{expression}");
+                    assert_eq!(diagnostic.fragment, fragment, "{expression}");
+                    assert_eq!(&expression[diagnostic.span.clone()], fragment);
+                }
+                other => panic!("expected {expression} to fail, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn validate_decimal_factors() {
+        use approx::assert_ulps_eq;
+
+        use crate::UcumUnit;
+
+        let options = ParseOptions::default().decimal_factors(true);
+        let parse = |expression| Unit::from_str_with_options(expression, &options).unwrap();
+
+        assert_eq!(parse("0.5L").expression(), "5L/10*");
+        assert_ulps_eq!(parse("0.5L").scalar(), 0.0005);
+        assert_eq!(parse("/0.1har").expression(), "10*/har");
+        assert_eq!(parse("1e6/L").expression(), "1000000/L");
+        assert_eq!(parse("1e12/L").expression(), "1000000000.10*3/L");
+        assert_ulps_eq!(parse("1e12/L").scalar(), 1e15);
+        assert_ulps_eq!(parse("0.5m2").scalar(), 0.25);
+        assert_ulps_eq!(parse("2.5kg").scalar(), 2500.0);
+        assert_eq!(parse("2eV"), parse_unit!("2eV"));
+        assert_eq!(parse("10*3/uL"), parse_unit!("10*3/uL"));
+        assert_eq!(parse("m.(0.5s)"), parse_unit!("m.5s/10*"));
+    }
+
+    #[test]
+    fn validate_decimal_factors_are_opt_in() {
+        use approx::assert_ulps_eq;
+
+        use crate::UcumUnit;
+
+        assert!(Unit::from_str("1e6/L").is_err());
+
+        // Without the option, this is "2 times 5kg".
+        assert_ulps_eq!(Unit::from_str("2.5kg").unwrap().scalar(), 10_000.0);
+    }
 }
@@ -6,13 +6,13 @@ use crate::{IsCompatibleWith, Measurement, Term, Unit};
 impl IsCompatibleWith for Unit {
     #[inline]
     fn is_compatible_with(&self, rhs: &Self) -> bool {
-        self.is_compatible_with(&rhs.terms)
+        self.is_compatible_with(&Cow::Borrowed(&*rhs.terms))
     }
 }
 
 impl<'a> IsCompatibleWith<Cow<'a, [Term]>> for Unit {
     fn is_compatible_with(&self, rhs: &Cow<'a, [Term]>) -> bool {
-        self.terms.is_compatible_with(rhs)
+        Cow::Borrowed(&*self.terms).is_compatible_with(rhs)
     }
 }
 
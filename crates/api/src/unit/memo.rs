@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use once_cell::sync::OnceCell;
+
+use crate::Composition;
+
+/// The values that `Unit`s derive from their `Term`s (which means recursing through `Atom`
+/// definitions), computed the first time each is needed. They're allocated on that first call, so
+/// units that are only built and combined (ex. the intermediate units of `Mul` or `inv()`) never
+/// allocate one; clones made after that share them.
+///
+/// `const` units (ex. `unit::UNITY`) are copied fresh at each use, so they'd allocate on every
+/// call; they compute these on every call instead, like all units used to.
+///
+#[derive(Clone, Debug)]
+pub(super) struct Memo(Option<OnceCell<Arc<Values>>>);
+
+#[derive(Debug, Default)]
+struct Values {
+    scalar: OnceCell<f64>,
+    magnitude: OnceCell<f64>,
+    composition: OnceCell<Composition>,
+}
+
+impl Memo {
+    pub(super) const NONE: Self = Self(None);
+
+    pub(super) const fn new() -> Self {
+        Self(Some(OnceCell::new()))
+    }
+
+    fn values(&self) -> Option<&Values> {
+        self.0
+            .as_ref()
+            .map(|values| &**values.get_or_init(Arc::default))
+    }
+
+    pub(super) fn scalar<F>(&self, f: F) -> f64
+    where
+        F: FnOnce() -> f64,
+    {
+        match self.values() {
+            Some(values) => *values.scalar.get_or_init(f),
+            None => f(),
+        }
+    }
+
+    pub(super) fn magnitude<F>(&self, f: F) -> f64
+    where
+        F: FnOnce() -> f64,
+    {
+        match self.values() {
+            Some(values) => *values.magnitude.get_or_init(f),
+            None => f(),
+        }
+    }
+
+    pub(super) fn composition<F>(&self, f: F) -> Composition
+    where
+        F: FnOnce() -> Composition,
+    {
+        match self.values() {
+            Some(values) => *values.composition.get_or_init(f),
+            None => f(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use approx::assert_ulps_eq;
+
+    use crate::{Composable, UcumUnit, Unit};
+
+    use super::*;
+
+    #[test]
+    fn validate_computed_once() {
+        let memo = Memo::new();
+
+        assert_ulps_eq!(memo.scalar(|| 2.0), 2.0);
+        assert_ulps_eq!(memo.scalar(|| unreachable!("already computed")), 2.0);
+
+        let shared = memo.clone();
+        assert_ulps_eq!(shared.scalar(|| unreachable!("shared")), 2.0);
+        assert_ulps_eq!(memo.magnitude(|| 3.0), 3.0);
+        assert_ulps_eq!(memo.magnitude(|| unreachable!("already computed")), 3.0);
+    }
+
+    #[test]
+    fn validate_allocated_lazily() {
+        let memo = Memo::new();
+        let unshared = memo.clone();
+        assert!(memo.0.as_ref().unwrap().get().is_none());
+
+        assert_ulps_eq!(memo.scalar(|| 2.0), 2.0);
+        assert!(memo.0.as_ref().unwrap().get().is_some());
+        assert_ulps_eq!(unshared.scalar(|| 3.0), 3.0);
+    }
+
+    #[test]
+    fn validate_none() {
+        assert_ulps_eq!(Memo::NONE.scalar(|| 2.0), 2.0);
+        assert_ulps_eq!(Memo::NONE.scalar(|| 3.0), 3.0);
+    }
+
+    #[test]
+    fn validate_reset_after_mutation() {
+        use num_traits::{Inv, Pow};
+
+        let mut unit = Unit::from_str("km").unwrap();
+        let composition = unit.composition();
+        assert_ulps_eq!(unit.scalar(), 1000.0);
+
+        let _ = Pow::pow(&mut unit, 2);
+        assert_ulps_eq!(unit.scalar(), 1_000_000.0);
+        assert_ne!(unit.composition(), composition);
+
+        let _ = Inv::inv(&mut unit);
+        assert_ulps_eq!(unit.scalar(), 0.000_001);
+        assert_ulps_eq!(unit.magnitude(), 0.000_001);
+    }
+
+    #[test]
+    fn validate_send_sync() {
+        fn assert_send_sync<T: Clone + Send + Sync>() {}
+
+        assert_send_sync::<Unit>();
+    }
+}
@@ -1,5 +1,3 @@
-use std::borrow::Cow;
-
 use num_traits::Inv;
 
 use crate::{term, Unit};
@@ -8,9 +6,7 @@ impl Inv for Unit {
     type Output = Self;
 
     fn inv(self) -> Self::Output {
-        Self {
-            terms: Cow::Owned(term::num_traits::inv::inv_terms_into(self.terms.to_vec())),
-        }
+        Self::new(term::num_traits::inv::inv_terms_into(self.terms.to_vec()))
     }
 }
 
@@ -18,9 +14,7 @@ impl<'a> Inv for &'a Unit {
     type Output = Unit;
 
     fn inv(self) -> Self::Output {
-        Unit {
-            terms: Cow::Owned(term::num_traits::inv::inv_terms_into(self.terms.to_vec())),
-        }
+        Unit::new(term::num_traits::inv::inv_terms_into(self.terms.to_vec()))
     }
 }
 
@@ -28,7 +22,7 @@ impl<'a> Inv for &'a mut Unit {
     type Output = Self;
 
     fn inv(self) -> Self::Output {
-        term::num_traits::inv::inv_terms(self.terms.to_mut());
+        term::num_traits::inv::inv_terms(self.terms_mut());
 
         self
     }
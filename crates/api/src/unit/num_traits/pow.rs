@@ -32,7 +32,7 @@ impl<'a> Pow<Exponent> for &'a mut Unit {
     type Output = Self;
 
     fn pow(self, rhs: Exponent) -> Self::Output {
-        self.terms.to_mut().iter_mut().for_each(|term| {
+        self.terms_mut().iter_mut().for_each(|term| {
             let _ = Pow::pow(term, rhs);
         });
         self
@@ -0,0 +1,602 @@
+//! A syntax tree for unit expressions that, unlike `Unit`, keeps the expression's structure
+//! (parentheses, operator order) and the source span of each node. Get one via
+//! `Unit::parse_ast()`.
+//!
+//! ```rust
+//! use std::str::FromStr;
+//! use wise_units::{
+//!     unit::ast::{BasicComponent, Operator},
+//!     Unit,
+//! };
+//!
+//! let expression = Unit::parse_ast("kg/(10.m2)").unwrap();
+//! let (operator, rest) = expression.term.rest.as_ref().unwrap();
+//!
+//! assert_eq!(*operator, Operator::Slash { span: 2..3 });
+//! assert!(matches!(
+//!     rest.component.body,
+//!     BasicComponent::Parenthesized { span: std::ops::Range { start: 3, end: 10 }, .. }
+//! ));
+//! assert_eq!(expression.to_unit(), Unit::from_str("kg/(10.m2)").unwrap());
+//! ```
+//!
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::result_large_err)]
+
+use std::ops::Range;
+
+use num_traits::Inv;
+use pest::{iterators::Pair, Parser};
+
+use crate::{term, Atom, Prefix, Term, Unit};
+
+use super::{
+    terms::{
+        mapper::{push_power_of_ten, DecimalFactor, Exponent as MapperExponent, SimpleUnit},
+        term_parser::{Rule, TermParser},
+    },
+    Diagnostic, Error, ParseOptions, Visit, VisitWith,
+};
+
+/// A byte range in the source expression.
+///
+pub type Span = Range<usize>;
+
+/// The root of a unit expression: `<main-term>` in the UCUM grammar.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expression {
+    pub span: Span,
+
+    /// The span of the leading slash, for expressions like `/m`.
+    ///
+    pub leading_slash: Option<Span>,
+
+    pub term: TermNode,
+}
+
+/// A component, optionally followed by an operator and the rest of the term: `<term>`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TermNode {
+    pub span: Span,
+    pub component: Component,
+    pub rest: Option<(Operator, Box<Self>)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Dot { span: Span },
+    Slash { span: Span },
+}
+
+/// A `BasicComponent` with an optional leading factor (ex. the `10` in `10m`): `<component>`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Component {
+    pub span: Span,
+    pub factor: Option<Factor>,
+    pub body: BasicComponent,
+}
+
+/// `<basic-component>`
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BasicComponent {
+    Annotatable {
+        annotatable: Annotatable,
+        annotation: Option<Annotation>,
+    },
+    Annotation(Annotation),
+    Factor(Factor),
+    Parenthesized {
+        span: Span,
+        term: Box<TermNode>,
+    },
+}
+
+/// A unit symbol and its optional exponent (ex. `m2`): `<annotatable>`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Annotatable {
+    pub span: Span,
+    pub simple_unit: SimpleUnitNode,
+    pub exponent: Option<ExponentNode>,
+}
+
+/// A unit symbol (ex. `km`), resolved to its `Prefix` and `Atom`: `<simple-unit>`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimpleUnitNode {
+    pub span: Span,
+    pub prefix: Option<Prefix>,
+
+    /// `None` for the unity symbol, `1`.
+    ///
+    pub atom: Option<Atom>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExponentNode {
+    pub span: Span,
+    pub value: term::Exponent,
+}
+
+/// A factor. With `ParseOptions::decimal_factors()`, this may have been written as a decimal or
+/// in scientific notation, in which case its value is `value × 10^power_of_ten`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Factor {
+    pub span: Span,
+    pub value: term::Factor,
+    pub power_of_ten: term::Exponent,
+}
+
+/// An annotation (ex. `{tbl}`). The span includes the curly braces; `text` doesn't.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Annotation {
+    pub span: Span,
+    pub text: String,
+}
+
+impl Expression {
+    /// Builds the `Unit` that this expression describes; this is the same `Unit` that
+    /// `Unit::from_str()` would return for the source expression.
+    ///
+    #[must_use]
+    pub fn to_unit(&self) -> Unit {
+        let terms = self.term.to_terms();
+
+        if self.leading_slash.is_some() {
+            Unit::new(terms).inv()
+        } else {
+            Unit::new(terms)
+        }
+    }
+}
+
+impl TermNode {
+    fn to_terms(&self) -> Vec<Term> {
+        let mut terms = self.component.to_terms();
+
+        if let Some((operator, rest)) = &self.rest {
+            let mut rest_terms = rest.to_terms();
+
+            if let Operator::Slash { .. } = operator {
+                term::num_traits::inv::inv_terms(&mut rest_terms);
+            }
+
+            terms.extend(rest_terms);
+        }
+
+        terms
+    }
+}
+
+impl Component {
+    fn to_terms(&self) -> Vec<Term> {
+        let mut terms = self.body.to_terms();
+
+        if let Some(factor) = &self.factor {
+            if factor.value != 1 {
+                if let Some(first_term) = terms.first_mut() {
+                    let _ = first_term.set_factor(factor.value);
+                }
+            }
+
+            push_power_of_ten(&mut terms, factor.power_of_ten);
+        }
+
+        terms
+    }
+}
+
+impl BasicComponent {
+    fn to_terms(&self) -> Vec<Term> {
+        match self {
+            Self::Annotatable {
+                annotatable,
+                annotation,
+            } => {
+                let mut term = annotatable.to_term();
+
+                if let Some(annotation) = annotation {
+                    let _ = term.set_annotation(annotation.text.as_str());
+                }
+
+                vec![term]
+            }
+            Self::Annotation(annotation) => {
+                vec![Term::Annotation(crate::Annotation::from(
+                    annotation.text.as_str(),
+                ))]
+            }
+            Self::Factor(factor) => {
+                let mut terms = vec![Term::Factor(factor.value)];
+                push_power_of_ten(&mut terms, factor.power_of_ten);
+                terms
+            }
+            Self::Parenthesized { term, .. } => {
+                let mut terms = vec![term::UNITY];
+                terms.extend(term.to_terms());
+                terms
+            }
+        }
+    }
+}
+
+impl Annotatable {
+    fn to_term(&self) -> Term {
+        self.simple_unit.atom.map_or(term::UNITY, |atom| {
+            let mut term = Term::new(self.simple_unit.prefix, Some(atom));
+
+            if let Some(exponent) = &self.exponent {
+                let _ = term.set_exponent(exponent.value);
+            }
+
+            term
+        })
+    }
+}
+
+//-----------------------------------------------------------------------------
+// Building from pest pairs
+//-----------------------------------------------------------------------------
+pub(crate) fn parse(expression: &str, options: ParseOptions) -> Result<Expression, Error> {
+    options.limits.check_expression(expression)?;
+
+    let rule = if options.decimal_factors {
+        Rule::lenient_main_term
+    } else {
+        Rule::main_term
+    };
+
+    let main_term = TermParser::parse(rule, expression)
+        .map_err(|error| Error::InvalidExpression(Diagnostic::from_term_error(expression, &error)))?
+        .next()
+        .expect("BUG! main_term must produce a pair");
+
+    let expression = Expression::visit_with(main_term, options)?;
+    options.limits.check_terms(&expression.term.to_terms())?;
+
+    Ok(expression)
+}
+
+fn span_of(pair: &Pair<'_, Rule>) -> Span {
+    let span = pair.as_span();
+    span.start()..span.end()
+}
+
+impl VisitWith<'_, Rule> for Expression {
+    fn visit_with(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Self, Error> {
+        let span = span_of(&pair);
+        let mut leading_slash = None;
+
+        for inner in pair.into_inner() {
+            match inner.as_rule() {
+                Rule::slash => leading_slash = Some(span_of(&inner)),
+                Rule::term | Rule::lenient_term => {
+                    return Ok(Self {
+                        span,
+                        leading_slash,
+                        term: TermNode::visit_with(inner, options)?,
+                    });
+                }
+                _ => (),
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+impl VisitWith<'_, Rule> for TermNode {
+    fn visit_with(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Self, Error> {
+        let span = span_of(&pair);
+        let mut pairs = pair.into_inner();
+
+        let component = match pairs.next() {
+            Some(first) => Component::visit_with(first, options)?,
+            None => unreachable!(),
+        };
+
+        let rest = match (pairs.next(), pairs.next()) {
+            (Some(operator), Some(term)) => {
+                let operator = match operator.as_rule() {
+                    Rule::dot => Operator::Dot {
+                        span: span_of(&operator),
+                    },
+                    Rule::slash => Operator::Slash {
+                        span: span_of(&operator),
+                    },
+                    _ => unreachable!(),
+                };
+
+                Some((operator, Box::new(Self::visit_with(term, options)?)))
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            span,
+            component,
+            rest,
+        })
+    }
+}
+
+impl VisitWith<'_, Rule> for Component {
+    fn visit_with(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Self, Error> {
+        let span = span_of(&pair);
+        let mut factor = None;
+
+        for inner in pair.into_inner() {
+            match inner.as_rule() {
+                Rule::factor | Rule::decimal_factor => factor = Some(Factor::visit(inner)?),
+                Rule::basic_component | Rule::lenient_basic_component => {
+                    return Ok(Self {
+                        span,
+                        factor,
+                        body: BasicComponent::visit_with(inner, options)?,
+                    });
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+impl VisitWith<'_, Rule> for BasicComponent {
+    fn visit_with(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Self, Error> {
+        let span = span_of(&pair);
+        let mut pairs = pair.into_inner();
+
+        let first = pairs.next().unwrap_or_else(|| unreachable!());
+
+        match first.as_rule() {
+            Rule::annotatable => Ok(Self::Annotatable {
+                annotatable: Annotatable::visit_with(first, options)?,
+                annotation: pairs.next().as_ref().map(Annotation::from_pair),
+            }),
+            Rule::annotation => Ok(Self::Annotation(Annotation::from_pair(&first))),
+            Rule::factor | Rule::decimal_factor => Ok(Self::Factor(Factor::visit(first)?)),
+            Rule::term | Rule::lenient_term => Ok(Self::Parenthesized {
+                span,
+                term: Box::new(TermNode::visit_with(first, options)?),
+            }),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl VisitWith<'_, Rule> for Annotatable {
+    fn visit_with(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Self, Error> {
+        let span = span_of(&pair);
+        let mut pairs = pair.into_inner();
+
+        let simple_unit = match pairs.next() {
+            Some(first) => {
+                let simple_unit_span = span_of(&first);
+                let SimpleUnit(term) = SimpleUnit::visit_with(first, options)?;
+
+                SimpleUnitNode {
+                    span: simple_unit_span,
+                    prefix: term.prefix(),
+                    atom: term.atom(),
+                }
+            }
+            None => unreachable!(),
+        };
+
+        let exponent = match pairs.next() {
+            Some(second) => {
+                let exponent_span = span_of(&second);
+                let MapperExponent(value) = MapperExponent::visit(second)?;
+
+                Some(ExponentNode {
+                    span: exponent_span,
+                    value,
+                })
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            span,
+            simple_unit,
+            exponent,
+        })
+    }
+}
+
+impl Visit<'_, Rule> for Factor {
+    fn visit(pair: Pair<'_, Rule>) -> Result<Self, Error> {
+        let span = span_of(&pair);
+
+        let DecimalFactor {
+            factor,
+            power_of_ten,
+        } = match pair.as_rule() {
+            Rule::factor => DecimalFactor::from(term::Factor::visit(pair)?),
+            _ => DecimalFactor::visit(pair)?,
+        };
+
+        Ok(Self {
+            span,
+            value: factor,
+            power_of_ten,
+        })
+    }
+}
+
+impl Annotation {
+    fn from_pair(pair: &Pair<'_, Rule>) -> Self {
+        let span = pair.as_span();
+
+        // Include the curly braces.
+        Self {
+            span: span.start() - 1..span.end() + 1,
+            text: span.as_str().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::unit::{Limit, ParseLimits};
+
+    use super::*;
+
+    #[test]
+    fn validate_structure() {
+        let expression = parse("/{tot}.10km2", ParseOptions::default()).unwrap();
+
+        assert_eq!(
+            expression,
+            Expression {
+                span: 0..12,
+                leading_slash: Some(0..1),
+                term: TermNode {
+                    span: 1..12,
+                    component: Component {
+                        span: 1..6,
+                        factor: None,
+                        body: BasicComponent::Annotation(Annotation {
+                            span: 1..6,
+                            text: "tot".to_string(),
+                        }),
+                    },
+                    rest: Some((
+                        Operator::Dot { span: 6..7 },
+                        Box::new(TermNode {
+                            span: 7..12,
+                            component: Component {
+                                span: 7..12,
+                                factor: Some(Factor {
+                                    span: 7..9,
+                                    value: 10,
+                                    power_of_ten: 0,
+                                }),
+                                body: BasicComponent::Annotatable {
+                                    annotatable: Annotatable {
+                                        span: 9..12,
+                                        simple_unit: SimpleUnitNode {
+                                            span: 9..11,
+                                            prefix: Some(Prefix::Kilo),
+                                            atom: Some(Atom::Meter),
+                                        },
+                                        exponent: Some(ExponentNode {
+                                            span: 11..12,
+                                            value: 2,
+                                        }),
+                                    },
+                                    annotation: None,
+                                },
+                            },
+                            rest: None,
+                        }),
+                    )),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn validate_parenthesized() {
+        let expression = parse("m/(s.g)", ParseOptions::default()).unwrap();
+        let (_, rest) = expression.term.rest.unwrap();
+
+        match rest.component.body {
+            BasicComponent::Parenthesized { span, term } => {
+                assert_eq!(span, 2..7);
+                assert_eq!(term.span, 3..6);
+                assert_eq!(term.rest.unwrap().0, Operator::Dot { span: 4..5 });
+            }
+            other => panic!("expected parentheses, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn validate_decimal_factor() {
+        let options = ParseOptions::default().decimal_factors(true);
+        let expression = parse("0.5L", options).unwrap();
+
+        assert_eq!(
+            expression.term.component.factor,
+            Some(Factor {
+                span: 0..3,
+                value: 5,
+                power_of_ten: -1,
+            })
+        );
+        assert_eq!(expression.to_unit().expression(), "5L/10*");
+    }
+
+    #[test]
+    fn validate_errors() {
+        assert!(parse("m/", ParseOptions::default()).is_err());
+        assert!(parse("kgg", ParseOptions::default()).is_err());
+    }
+
+    #[test]
+    fn validate_limits() {
+        let options = ParseOptions::default().limits(ParseLimits::default().max_terms(2));
+        assert!(parse("m.s", options).is_ok());
+        assert!(matches!(
+            parse("m.s.g", options),
+            Err(Error::LimitExceeded {
+                limit: Limit::Terms,
+                max: 2
+            })
+        ));
+
+        let options = ParseOptions::default().limits(ParseLimits::default().max_exponent(3));
+        assert!(parse("m3/s-3", options).is_ok());
+        assert!(matches!(
+            parse("m/s-4", options),
+            Err(Error::LimitExceeded {
+                limit: Limit::Exponent,
+                max: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn validate_to_unit_matches_from_str() {
+        for expression in [
+            "1",
+            "/1",
+            "m",
+            "/m",
+            "10m",
+            "km2",
+            "km-2{foo}",
+            "{tbl}/d",
+            "42",
+            "/42{foo}",
+            "kg.m/s2",
+            "m/s/s",
+            "m/(s.g)",
+            "((m))",
+            "[lb_av]/[acr_us]",
+            "10*3/uL",
+            "mg/dL",
+            "Cel",
+            "10.(m.s)",
+            "2(m)",
+            "m2.(km/s)",
+        ] {
+            let from_ast = parse(expression, ParseOptions::default())
+                .unwrap()
+                .to_unit();
+            let from_str = Unit::from_str(expression).unwrap();
+
+            assert_eq!(from_ast.terms(), from_str.terms(), "{expression}");
+        }
+    }
+}
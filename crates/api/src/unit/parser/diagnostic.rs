@@ -0,0 +1,560 @@
+use std::{fmt, ops::Range};
+
+use pest::error::{Error as PestError, ErrorVariant, InputLocation};
+
+use super::terms::term_parser::Rule as TermRule;
+
+/// Describes the spot in a unit expression that caused parsing to fail.
+///
+/// `span` is a byte range into the original expression, so callers can underline exactly the bad
+/// part (ex. the trailing `.` in `kg/(ha.`).
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub span: Range<usize>,
+    pub fragment: String,
+    pub expected: Vec<&'static str>,
+
+    /// For `DiagnosticKind::UnknownSymbol`, the closest valid (possibly prefixed) atom codes,
+    /// closest first.
+    ///
+    pub suggestions: Vec<String>,
+}
+
+/// The category of problem a `Diagnostic` describes.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum DiagnosticKind {
+    /// Ex. the `foo` in `kg/foo`.
+    ///
+    UnknownSymbol,
+
+    /// A `.` or `/` that isn't followed by anything it could operate on; ex. `m/`.
+    ///
+    DanglingOperator,
+
+    /// Ex. `kg/(ha` or `m)`.
+    ///
+    UnbalancedParenthesis,
+
+    /// A sign with no digits, or digits that don't fit in an `Exponent`; ex. `m-`. In print
+    /// notation, also an exponent on a number other than `10`; ex. `2²`.
+    ///
+    BadExponent,
+
+    /// Digits that don't fit in a `Factor`.
+    ///
+    BadFactor,
+
+    /// Ex. `m{}`.
+    ///
+    EmptyAnnotation,
+
+    /// Ex. `m{stuff`.
+    ///
+    UnterminatedAnnotation,
+
+    /// A character that isn't valid at this point in the expression; ex. the space in `kg m`.
+    ///
+    UnexpectedCharacter,
+
+    /// The expression is empty.
+    ///
+    EmptyExpression,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(
+        kind: DiagnosticKind,
+        expression: &str,
+        span: Range<usize>,
+        expected: Vec<&'static str>,
+    ) -> Self {
+        Self {
+            kind,
+            fragment: expression.get(span.clone()).unwrap_or_default().to_string(),
+            span,
+            expected,
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Builds a `Diagnostic` for a chunk of the expression that the grammar accepted, but that
+    /// couldn't be mapped to a `Term` (ex. an unknown symbol).
+    ///
+    pub(crate) fn from_span(
+        kind: DiagnosticKind,
+        span: &pest::Span<'_>,
+        expected: Vec<&'static str>,
+    ) -> Self {
+        Self {
+            kind,
+            span: span.start()..span.end(),
+            fragment: span.as_str().to_string(),
+            expected,
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Builds an `UnknownSymbol` `Diagnostic`, including suggestions for what the symbol may
+    /// have been meant to be.
+    ///
+    pub(crate) fn unknown_symbol(span: &pest::Span<'_>) -> Self {
+        let mut diagnostic = Self::from_span(DiagnosticKind::UnknownSymbol, span, vec!["unit"]);
+        diagnostic.suggestions = super::suggestions::suggest(span.as_str());
+
+        diagnostic
+    }
+
+    /// Builds a `Diagnostic` from a failed run of the term grammar, using the position that pest
+    /// reports along with a bit of scanning of `expression` to figure out what actually went wrong.
+    ///
+    pub(crate) fn from_term_error(expression: &str, error: &PestError<TermRule>) -> Self {
+        let position = match error.location {
+            InputLocation::Pos(position) | InputLocation::Span((position, _)) => position,
+        };
+
+        let expected = match &error.variant {
+            ErrorVariant::ParsingError { positives, .. } => describe_rules(positives),
+            ErrorVariant::CustomError { .. } => Vec::new(),
+        };
+
+        diagnose(expression, position, expected)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} \"{}\" at {}..{}",
+            self.kind, self.fragment, self.span.start, self.span.end
+        )?;
+
+        if let Some((last, rest)) = self.expected.split_last() {
+            if rest.is_empty() {
+                write!(f, "; expected {last}")?;
+            } else {
+                write!(f, "; expected {} or {last}", rest.join(", "))?;
+            }
+        }
+
+        if let Some((last, rest)) = self.suggestions.split_last() {
+            if rest.is_empty() {
+                write!(f, "; did you mean {last}?")?;
+            } else {
+                write!(f, "; did you mean {} or {last}?", rest.join(", "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::UnknownSymbol => "unknown unit symbol",
+            Self::DanglingOperator => "dangling operator",
+            Self::UnbalancedParenthesis => "unbalanced parenthesis",
+            Self::BadExponent => "bad exponent",
+            Self::BadFactor => "bad factor",
+            Self::EmptyAnnotation => "empty annotation",
+            Self::UnterminatedAnnotation => "unterminated annotation",
+            Self::UnexpectedCharacter => "unexpected character",
+            Self::EmptyExpression => "empty expression",
+        };
+
+        f.write_str(s)
+    }
+}
+
+const UNIT: &str = "unit";
+const CLOSE_PAREN: &str = "')'";
+const CLOSE_BRACE: &str = "'}'";
+
+fn describe_rules(rules: &[TermRule]) -> Vec<&'static str> {
+    let mut descriptions: Vec<&'static str> = Vec::new();
+
+    for rule in rules {
+        let description = match rule {
+            TermRule::main_term
+            | TermRule::lenient_main_term
+            | TermRule::term
+            | TermRule::lenient_term
+            | TermRule::component
+            | TermRule::lenient_component
+            | TermRule::basic_component
+            | TermRule::lenient_basic_component
+            | TermRule::annotatable
+            | TermRule::simple_unit
+            | TermRule::symbol_char => UNIT,
+            TermRule::dot => "'.'",
+            TermRule::slash => "'/'",
+            TermRule::exponent | TermRule::digits | TermRule::sign => "exponent",
+            TermRule::factor | TermRule::decimal_factor | TermRule::not_factor => "factor",
+            TermRule::annotation_group | TermRule::annotation | TermRule::annotation_char => {
+                "annotation"
+            }
+            TermRule::EOI => "end of input",
+        };
+
+        if !descriptions.contains(&description) {
+            descriptions.push(description);
+        }
+    }
+
+    descriptions
+}
+
+fn char_span(expression: &str, position: usize) -> Range<usize> {
+    let len = expression[position..]
+        .chars()
+        .next()
+        .map_or(0, char::len_utf8);
+
+    position..position + len
+}
+
+fn diagnose(expression: &str, position: usize, mut expected: Vec<&'static str>) -> Diagnostic {
+    use DiagnosticKind as K;
+
+    if expression.is_empty() {
+        return Diagnostic::new(K::EmptyExpression, expression, 0..0, vec![UNIT]);
+    }
+
+    // Annotations are opaque to everything else, so check the ones that start at or before the
+    // failure first.
+    //
+    if let Some(diagnostic) = check_annotations(expression, position) {
+        return diagnostic;
+    }
+
+    let current = expression[position..].chars().next();
+    let previous = expression[..position].chars().next_back();
+
+    if expected.contains(&"exponent") && matches!(previous, Some('+' | '-')) {
+        let sign = position - 1;
+        let end = char_span(expression, position).end;
+
+        return Diagnostic::new(K::BadExponent, expression, sign..end, vec!["exponent"]);
+    }
+
+    match current {
+        Some(c @ ('+' | '-')) => {
+            // Include any exponent digits right before the sign (ex. the `2` in `m2-`).
+            let start = expression[..position]
+                .rfind(|ch: char| !ch.is_ascii_digit())
+                .map_or(position, |i| i + 1);
+
+            Diagnostic::new(
+                K::BadExponent,
+                expression,
+                start..position + c.len_utf8(),
+                expected,
+            )
+        }
+        Some('.' | '/') if position == 0 || matches!(previous, Some('.' | '/')) => Diagnostic::new(
+            K::DanglingOperator,
+            expression,
+            char_span(expression, position),
+            vec![UNIT],
+        ),
+        None | Some(')') if matches!(previous, Some('.' | '/')) => Diagnostic::new(
+            K::DanglingOperator,
+            expression,
+            position - 1..position,
+            vec![UNIT],
+        ),
+        Some(')') if previous == Some('(') => Diagnostic::new(
+            K::UnexpectedCharacter,
+            expression,
+            char_span(expression, position),
+            vec![UNIT],
+        ),
+        Some(')') if unmatched_open_parens(&expression[..position]).is_empty() => {
+            expected.retain(|e| *e != UNIT);
+
+            Diagnostic::new(
+                K::UnbalancedParenthesis,
+                expression,
+                char_span(expression, position),
+                expected,
+            )
+        }
+        None => match unmatched_open_parens(expression).last() {
+            Some(&open) => {
+                if !expected.contains(&CLOSE_PAREN) {
+                    expected.insert(0, CLOSE_PAREN);
+                }
+
+                Diagnostic::new(
+                    K::UnbalancedParenthesis,
+                    expression,
+                    open..open + 1,
+                    expected,
+                )
+            }
+            None => Diagnostic::new(
+                K::UnexpectedCharacter,
+                expression,
+                position..position,
+                expected,
+            ),
+        },
+        Some(_) => {
+            if !unmatched_open_parens(&expression[..position]).is_empty()
+                && !expected.contains(&CLOSE_PAREN)
+            {
+                expected.push(CLOSE_PAREN);
+            }
+
+            Diagnostic::new(
+                K::UnexpectedCharacter,
+                expression,
+                char_span(expression, position),
+                expected,
+            )
+        }
+    }
+}
+
+/// Looks for problems with any `{...}` groups that start at or before `position`.
+///
+fn check_annotations(expression: &str, position: usize) -> Option<Diagnostic> {
+    let mut search_from = 0;
+
+    while let Some(offset) = expression[search_from..].find('{') {
+        let open = search_from + offset;
+
+        if open > position {
+            return None;
+        }
+
+        let body_start = open + 1;
+
+        let close = match expression[body_start..].find('}') {
+            Some(close_offset) => body_start + close_offset,
+            None => {
+                return Some(Diagnostic::new(
+                    DiagnosticKind::UnterminatedAnnotation,
+                    expression,
+                    open..expression.len(),
+                    vec![CLOSE_BRACE],
+                ));
+            }
+        };
+
+        if close == body_start {
+            return Some(Diagnostic::new(
+                DiagnosticKind::EmptyAnnotation,
+                expression,
+                open..close + 1,
+                vec!["annotation"],
+            ));
+        }
+
+        if let Some((i, _)) = expression[body_start..close]
+            .char_indices()
+            .find(|(_, c)| !is_annotation_char(*c))
+        {
+            return Some(Diagnostic::new(
+                DiagnosticKind::UnexpectedCharacter,
+                expression,
+                char_span(expression, body_start + i),
+                vec!["annotation", CLOSE_BRACE],
+            ));
+        }
+
+        search_from = close + 1;
+    }
+
+    None
+}
+
+/// Mirrors `annotation_char` in `term.pest`.
+///
+const fn is_annotation_char(c: char) -> bool {
+    matches!(c, '!'..='z' | '|' | '~')
+}
+
+/// Returns the positions of all `(` that haven't been closed, skipping over annotations.
+///
+fn unmatched_open_parens(expression: &str) -> Vec<usize> {
+    let mut stack = Vec::new();
+    let mut in_annotation = false;
+
+    for (i, c) in expression.char_indices() {
+        match c {
+            '{' => in_annotation = true,
+            '}' => in_annotation = false,
+            '(' if !in_annotation => stack.push(i),
+            ')' if !in_annotation => {
+                let _ = stack.pop();
+            }
+            _ => (),
+        }
+    }
+
+    stack
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit::parser::{parse, Error};
+
+    fn diagnose(expression: &str) -> Diagnostic {
+        match parse(expression) {
+            Err(Error::InvalidExpression(diagnostic)) => diagnostic,
+            other => panic!("expected InvalidExpression for {expression:?}, got {other:?}"),
+        }
+    }
+
+    macro_rules! validate_diagnostic {
+        ($test_name:ident, $input:expr, $kind:ident, $span:expr, $fragment:expr) => {
+            #[test]
+            fn $test_name() {
+                let diagnostic = diagnose($input);
+
+                assert_eq!(diagnostic.kind, DiagnosticKind::$kind);
+                assert_eq!(diagnostic.span, $span);
+                assert_eq!(diagnostic.fragment, $fragment);
+            }
+        };
+    }
+
+    validate_diagnostic!(
+        validate_dangling_dot_in_group,
+        "kg/(ha.",
+        DanglingOperator,
+        6..7,
+        "."
+    );
+    validate_diagnostic!(validate_dangling_slash, "m/", DanglingOperator, 1..2, "/");
+    validate_diagnostic!(validate_lone_slash, "/", DanglingOperator, 0..1, "/");
+    validate_diagnostic!(validate_double_dot, "m..s", DanglingOperator, 2..3, ".");
+    validate_diagnostic!(validate_leading_dot, "./m", DanglingOperator, 0..1, ".");
+    validate_diagnostic!(
+        validate_dot_before_close,
+        "(m.)",
+        DanglingOperator,
+        2..3,
+        "."
+    );
+    validate_diagnostic!(
+        validate_unclosed_paren,
+        "kg/(ha",
+        UnbalancedParenthesis,
+        3..4,
+        "("
+    );
+    validate_diagnostic!(
+        validate_unopened_paren,
+        "m)",
+        UnbalancedParenthesis,
+        1..2,
+        ")"
+    );
+    validate_diagnostic!(
+        validate_open_paren_at_end,
+        "m/(",
+        UnbalancedParenthesis,
+        2..3,
+        "("
+    );
+    validate_diagnostic!(validate_empty_group, "()", UnexpectedCharacter, 1..2, ")");
+    validate_diagnostic!(validate_sign_without_digits, "m-", BadExponent, 1..2, "-");
+    validate_diagnostic!(validate_sign_then_letter, "m+x", BadExponent, 1..3, "+x");
+    validate_diagnostic!(validate_trailing_sign, "m2-", BadExponent, 1..3, "2-");
+    validate_diagnostic!(
+        validate_exponent_overflow,
+        "m99999999999",
+        BadExponent,
+        1..12,
+        "99999999999"
+    );
+    validate_diagnostic!(
+        validate_factor_overflow,
+        "99999999999m",
+        BadFactor,
+        0..11,
+        "99999999999"
+    );
+    validate_diagnostic!(
+        validate_empty_annotation,
+        "m{}",
+        EmptyAnnotation,
+        1..3,
+        "{}"
+    );
+    validate_diagnostic!(
+        validate_unterminated_annotation,
+        "m{foo",
+        UnterminatedAnnotation,
+        1..5,
+        "{foo"
+    );
+    validate_diagnostic!(
+        validate_bad_annotation_char,
+        "m{a b}",
+        UnexpectedCharacter,
+        3..4,
+        " "
+    );
+    validate_diagnostic!(
+        validate_unknown_symbol,
+        "kg/foo",
+        UnknownSymbol,
+        3..6,
+        "foo"
+    );
+    validate_diagnostic!(
+        validate_unknown_symbol_in_group,
+        "kg/(ha.zzz)",
+        UnknownSymbol,
+        7..10,
+        "zzz"
+    );
+    validate_diagnostic!(validate_space, "kg m", UnexpectedCharacter, 2..3, " ");
+    validate_diagnostic!(validate_non_ascii, "kgµ", UnexpectedCharacter, 2..4, "µ");
+    validate_diagnostic!(validate_empty, "", EmptyExpression, 0..0, "");
+
+    #[test]
+    fn validate_expected() {
+        assert_eq!(
+            diagnose("kg/(ha").expected,
+            vec!["')'", "'.'", "'/'", "exponent"]
+        );
+        assert_eq!(diagnose("m/").expected, vec!["unit"]);
+        assert_eq!(diagnose("m{foo").expected, vec!["'}'"]);
+    }
+
+    #[test]
+    fn validate_suggestions() {
+        assert_eq!(
+            diagnose("kg/[lb_avd]").suggestions.first().unwrap(),
+            "[lb_av]"
+        );
+        assert!(diagnose("kg/(ha.").suggestions.is_empty());
+    }
+
+    #[test]
+    fn validate_display() {
+        assert_eq!(
+            diagnose("kg/(ha.").to_string(),
+            "dangling operator \".\" at 6..7; expected unit"
+        );
+        assert_eq!(
+            diagnose("kg/(ha").to_string(),
+            "unbalanced parenthesis \"(\" at 3..4; expected ')', '.', '/' or exponent"
+        );
+        assert_eq!(
+            diagnose("[lb_avd]").to_string(),
+            "unknown unit symbol \"[lb_avd]\" at 0..8; expected unit; did you mean [lb_av], [lb_ap] or [lbf_av]?"
+        );
+    }
+}
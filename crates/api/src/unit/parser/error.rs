@@ -1,8 +1,6 @@
 use pest::error::Error as PestError;
 
-use crate::unit::parser::{
-    symbols::symbol_parser::Rule as SymbolRule, terms::term_parser::Rule as TermRule,
-};
+use crate::unit::parser::{terms::term_parser::Rule as TermRule, Diagnostic, Limit};
 
 /// Errors when trying to convert between types that aren't commensurable.
 ///
@@ -13,10 +11,6 @@ pub enum Error {
     #[cfg_attr(feature = "serde", serde(serialize_with = "stringify"))]
     UnableToParseTerm(#[from] PestError<TermRule>),
 
-    #[error(transparent)]
-    #[cfg_attr(feature = "serde", serde(serialize_with = "stringify"))]
-    UnableToParseSymbol(#[from] PestError<SymbolRule>),
-
     #[error(transparent)]
     #[cfg_attr(feature = "serde", serde(serialize_with = "stringify"))]
     UnableToParseInteger(#[from] std::num::ParseIntError),
@@ -28,6 +22,42 @@ pub enum Error {
     ///
     #[error("Unknown unit string: {0}")]
     UnknownUnitString(String),
+
+    /// Indicates the unit string/expression is bad, and says which part of it is bad.
+    ///
+    #[error("Invalid unit string: {0}")]
+    InvalidExpression(Diagnostic),
+
+    /// Indicates the unit string/expression is larger or more complex than the `ParseLimits` in
+    /// use allow.
+    ///
+    #[error("Unit string exceeds the maximum {limit} ({max})")]
+    LimitExceeded { limit: Limit, max: usize },
+}
+
+impl Error {
+    /// Returns the `Diagnostic` describing where and why parsing failed, if there is one.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::{unit::DiagnosticKind, Error, Unit};
+    ///
+    /// let diagnostic = match Unit::from_str("kg/(ha.") {
+    ///     Err(Error::ParsingFailed(error)) => error.diagnostic().cloned().unwrap(),
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// assert_eq!(diagnostic.kind, DiagnosticKind::DanglingOperator);
+    /// assert_eq!(diagnostic.span, 6..7);
+    /// ```
+    ///
+    #[must_use]
+    pub const fn diagnostic(&self) -> Option<&Diagnostic> {
+        match self {
+            Self::InvalidExpression(diagnostic) => Some(diagnostic),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -0,0 +1,418 @@
+//! A hand-written, single-pass parser for the `main_term` grammar in `terms/term.pest`, used
+//! instead of pest when the `handwritten-parser` feature is enabled.
+//!
+//! It only handles the success path: when it can't parse an expression (or is asked to use
+//! `ParseOptions::decimal_factors()`), it returns `None`, and the pest parser takes over, so that
+//! errors and their `Diagnostic`s are the same either way.
+//!
+use num_traits::Inv;
+
+use crate::{
+    term::{self, UNITY},
+    Annotation, Term,
+};
+
+use super::{symbols, CaseMode, ParseOptions};
+
+pub(super) fn parse(expression: &str, options: ParseOptions) -> Option<Vec<Term>> {
+    if options.decimal_factors {
+        return None;
+    }
+
+    let mut parser = Parser {
+        input: expression,
+        pos: 0,
+        case_mode: options.case_mode,
+        terms: Vec::with_capacity(estimate_term_count(expression.as_bytes())),
+    };
+
+    let leading_slash = parser.eat(b'/');
+    parser.term()?;
+
+    if parser.pos != expression.len() {
+        return None;
+    }
+
+    let mut terms = parser.terms;
+
+    if leading_slash {
+        term::num_traits::inv::inv_terms(&mut terms);
+    }
+
+    Some(terms)
+}
+
+/// One term per component, plus one for each pair of parentheses, so that the output only has to
+/// be allocated once. Other than annotations (which each need their own copy), that's the only
+/// allocation: symbols are looked up (and, for `CaseMode::Insensitive`, upper-cased) in place. The
+/// output isn't shrunk to fit, since `Unit::new()` copies it into inline storage or an `Arc`
+/// anyway.
+///
+fn estimate_term_count(bytes: &[u8]) -> usize {
+    1 + bytes
+        .iter()
+        .filter(|byte| matches!(byte, b'.' | b'/' | b'('))
+        .count()
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    case_mode: CaseMode,
+    terms: Vec<Term>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn peek_is(&self, f: fn(u8) -> bool) -> bool {
+        matches!(self.peek(), Some(byte) if f(byte))
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    /// `term = { component ~ ((dot | slash) ~ term)? }`
+    ///
+    /// The grammar nests to the right, and each slash inverts everything after it (so `m/s/g` is
+    /// `m.s-1.g`). This does the same without recursing: each component is inverted once for every
+    /// slash before it.
+    ///
+    fn term(&mut self) -> Option<()> {
+        let mut slashes = 0;
+
+        loop {
+            let start = self.terms.len();
+            self.component()?;
+
+            for _ in 0..slashes {
+                for term in &mut self.terms[start..] {
+                    let _ = term.inv();
+                }
+            }
+
+            match self.peek() {
+                Some(b'.') => self.pos += 1,
+                Some(b'/') => {
+                    self.pos += 1;
+                    slashes += 1;
+                }
+                _ => return Some(()),
+            }
+        }
+    }
+
+    /// `component = { factor ~ basic_component | basic_component }`
+    ///
+    fn component(&mut self) -> Option<()> {
+        let start = self.terms.len();
+
+        if let Some(factor) = self.factor()? {
+            if !self.peek_is(starts_basic_component) {
+                self.terms.push(Term::Factor(factor));
+                return Some(());
+            }
+
+            self.basic_component()?;
+
+            if factor != 1 {
+                let _ = self.terms[start].set_factor(factor);
+            }
+
+            return Some(());
+        }
+
+        self.basic_component()
+    }
+
+    /// ```text
+    /// basic_component = {
+    ///     annotatable ~ annotation_group? | annotation_group | factor | "(" ~ term ~ ")"
+    /// }
+    /// ```
+    ///
+    fn basic_component(&mut self) -> Option<()> {
+        match self.peek()? {
+            b'{' => {
+                let annotation = self.annotation()?;
+                self.terms
+                    .push(Term::Annotation(Annotation::from(annotation)));
+            }
+            b'(' => {
+                self.pos += 1;
+                self.terms.push(UNITY);
+                self.term()?;
+
+                if !self.eat(b')') {
+                    return None;
+                }
+            }
+            b'0'..=b'9' if !self.rest().starts_with("10*") && !self.rest().starts_with("10^") => {
+                let factor = self.factor()??;
+                self.terms.push(Term::Factor(factor));
+            }
+            _ => {
+                let mut term = self.annotatable()?;
+
+                if self.peek() == Some(b'{') {
+                    let _ = term.set_annotation(self.annotation()?);
+                }
+
+                self.terms.push(term);
+            }
+        }
+
+        Some(())
+    }
+
+    /// `annotatable = { simple_unit ~ exponent? }`
+    ///
+    fn annotatable(&mut self) -> Option<Term> {
+        let start = self.pos;
+
+        if self.rest().starts_with("10*") || self.rest().starts_with("10^") {
+            self.pos += 3;
+        } else {
+            while self.peek_is(is_symbol_char) {
+                self.pos += 1;
+            }
+        }
+
+        if self.pos == start {
+            return None;
+        }
+
+        let mut term = symbols::lookup(&self.input[start..self.pos], self.case_mode)?;
+
+        if let Some(exponent) = self.exponent()? {
+            let _ = term.set_exponent(exponent);
+        }
+
+        Some(term)
+    }
+
+    /// `exponent = { sign? ~ digits }`. `Some(None)` if there's no exponent here; `None` if there
+    /// is, but it's out of range.
+    ///
+    #[allow(clippy::option_option)]
+    fn exponent(&mut self) -> Option<Option<term::Exponent>> {
+        let negative = match self.peek() {
+            Some(b'-') => true,
+            Some(b'+') => false,
+            Some(b'0'..=b'9') => {
+                return self
+                    .digits()
+                    .map_or(Some(None), |digits| digits.parse().ok().map(Some));
+            }
+            _ => return Some(None),
+        };
+
+        self.pos += 1;
+
+        if let Some(digits) = self.digits() {
+            let exponent: term::Exponent = digits.parse().ok()?;
+            Some(Some(if negative { -exponent } else { exponent }))
+        } else {
+            // A sign without digits isn't an exponent.
+            self.pos -= 1;
+            Some(None)
+        }
+    }
+
+    /// `factor = { ASCII_DIGIT+ ~ !("*" | "^") }`. `Some(None)` if there's no factor here; `None`
+    /// if there is, but it's out of range.
+    ///
+    #[allow(clippy::option_option)]
+    fn factor(&mut self) -> Option<Option<term::Factor>> {
+        let start = self.pos;
+
+        match self.digits() {
+            Some(_) if matches!(self.peek(), Some(b'*' | b'^')) => {
+                self.pos = start;
+                Some(None)
+            }
+            Some(digits) => digits.parse().ok().map(Some),
+            None => Some(None),
+        }
+    }
+
+    fn digits(&mut self) -> Option<&'a str> {
+        let start = self.pos;
+
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+
+        (self.pos > start).then(|| &self.input[start..self.pos])
+    }
+
+    /// `annotation_group = _{ "{" ~ annotation ~ "}" }`
+    ///
+    fn annotation(&mut self) -> Option<&'a str> {
+        if !self.eat(b'{') {
+            return None;
+        }
+
+        let start = self.pos;
+
+        while self.peek_is(is_annotation_char) {
+            self.pos += 1;
+        }
+
+        let annotation = &self.input[start..self.pos];
+
+        (!annotation.is_empty() && self.eat(b'}')).then_some(annotation)
+    }
+}
+
+const fn starts_basic_component(byte: u8) -> bool {
+    matches!(byte, b'{' | b'(' | b'0'..=b'9') || is_symbol_char(byte)
+}
+
+const fn is_symbol_char(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'!' | b'#'..=b'\'' | b'*' | b',' | b':'..=b'<' | b'>'..=b'z' | b'|' | b'~'
+    )
+}
+
+const fn is_annotation_char(byte: u8) -> bool {
+    matches!(byte, b'!'..=b'z' | b'|' | b'~')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_pest(expression: &str, options: ParseOptions) -> Option<Vec<Term>> {
+        super::super::parse_with_pest(expression, options).ok()
+    }
+
+    const CASE_MODES: [CaseMode; 3] = [CaseMode::Sensitive, CaseMode::Insensitive, CaseMode::Both];
+
+    /// This parser gives exactly the same terms as the pest parser (`Term`'s `PartialEq` only
+    /// compares what they mean, hence comparing their `Debug` output).
+    ///
+    #[test]
+    fn validate_expressions_match_pest() {
+        let expressions = [
+            "1",
+            "/1",
+            "m",
+            "/m",
+            "2m",
+            "1m",
+            "km2",
+            "m-2",
+            "m+2",
+            "10*3/uL",
+            "10^3",
+            "10*-3",
+            "m/s/g",
+            "m/s.g/h",
+            "/m/s",
+            "kg.m/s2",
+            "m/(s.g)",
+            "m/(s/g)",
+            "((m))",
+            "/((m)/s)",
+            "2(m)",
+            "2(m)/3(s)",
+            "{tot}",
+            "/{tot}",
+            "2{tot}",
+            "{tot}/d",
+            "mg{tot}/dL",
+            "2mg{tot}2",
+            "g{sucrose}-1",
+            "42",
+            "/42",
+            "4294967295",
+            "[lb_av]/[acr_us]",
+            "[in_i'H2O]",
+            "Cel",
+            "%",
+            "[ppth]",
+            "MG/DL",
+            "Kibby",
+            "[LB_AV]",
+            "daa",
+            "dar",
+            "m.s.g.h.min",
+            "10*3.{RBCs}/uL",
+            "0.5L",
+            "pg/mL",
+        ];
+
+        for case_mode in CASE_MODES {
+            let options = ParseOptions::default().case_mode(case_mode);
+
+            for expression in expressions {
+                assert_eq!(
+                    format!("{:?}", parse(expression, options)),
+                    format!("{:?}", with_pest(expression, options)),
+                    "{expression} ({case_mode:?})"
+                );
+            }
+        }
+    }
+
+    /// Invalid expressions are left to the pest parser, so it can describe what's wrong.
+    ///
+    #[test]
+    fn validate_invalid_expressions() {
+        for expression in [
+            "",
+            "/",
+            "m/",
+            "m.",
+            "m//s",
+            "(m",
+            "m)",
+            "()",
+            "{}",
+            "m{}",
+            "m{tot",
+            "m-",
+            "m2-",
+            "kgg",
+            "2 m",
+            "m2m",
+            "100*3",
+            "4294967296",
+            "m2147483648",
+            "m-2147483648",
+            "µg",
+        ] {
+            assert_eq!(
+                parse(expression, ParseOptions::default()),
+                None,
+                "{expression}"
+            );
+            assert_eq!(
+                with_pest(expression, ParseOptions::default()),
+                None,
+                "{expression}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_decimal_factors_uses_pest() {
+        let options = ParseOptions::default().decimal_factors(true);
+
+        assert_eq!(parse("2m", options), None);
+    }
+}
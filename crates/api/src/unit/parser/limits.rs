@@ -0,0 +1,366 @@
+use std::fmt;
+
+use crate::Term;
+
+use super::Error;
+
+/// Bounds on the size and complexity of a unit expression.
+///
+/// These make parsing an untrusted (or corrupted) expression fail fast with
+/// `unit::ParserError::LimitExceeded`, instead of exhausting the stack or memory.
+///
+/// The defaults are far beyond anything a real unit needs, but small enough to keep parsing cheap.
+/// Nothing enforces them unless they're set via `ParseOptions::limits()`; `Unit::from_str()` has no
+/// limits.
+///
+/// ```rust
+/// use wise_units::{
+///     unit::{Limit, ParseLimits, ParseOptions, ParserError},
+///     Unit,
+/// };
+///
+/// let options = ParseOptions::default().limits(ParseLimits::default().max_depth(2));
+///
+/// assert!(Unit::from_str_with_options("((m))", &options).is_ok());
+/// assert!(matches!(
+///     Unit::from_str_with_options("(((m)))", &options),
+///     Err(wise_units::Error::ParsingFailed(ParserError::LimitExceeded {
+///         limit: Limit::Depth,
+///         max: 2
+///     }))
+/// ));
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_field_names)]
+pub struct ParseLimits {
+    pub(crate) max_length: usize,
+    pub(crate) max_depth: usize,
+    pub(crate) max_terms: usize,
+    pub(crate) max_annotation_length: usize,
+    pub(crate) max_exponent: u32,
+}
+
+impl ParseLimits {
+    pub const DEFAULT_MAX_LENGTH: usize = 1024;
+    pub const DEFAULT_MAX_DEPTH: usize = 32;
+    pub const DEFAULT_MAX_TERMS: usize = 128;
+    pub const DEFAULT_MAX_ANNOTATION_LENGTH: usize = 256;
+    pub const DEFAULT_MAX_EXPONENT: u32 = 1000;
+
+    /// No limits at all; only use this for trusted input.
+    ///
+    #[must_use]
+    pub const fn unlimited() -> Self {
+        Self {
+            max_length: usize::MAX,
+            max_depth: usize::MAX,
+            max_terms: usize::MAX,
+            max_annotation_length: usize::MAX,
+            max_exponent: u32::MAX,
+        }
+    }
+
+    /// The maximum length of the expression, in bytes.
+    ///
+    #[must_use]
+    pub const fn max_length(self, max_length: usize) -> Self {
+        Self { max_length, ..self }
+    }
+
+    /// The maximum nesting depth of parentheses (ex. `m/(s.(g))` has a depth of 2).
+    ///
+    #[must_use]
+    pub const fn max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// The maximum number of `Term`s in the parsed unit.
+    ///
+    #[must_use]
+    pub const fn max_terms(self, max_terms: usize) -> Self {
+        Self { max_terms, ..self }
+    }
+
+    /// The maximum length of each annotation, in bytes, not counting the curly braces.
+    ///
+    #[must_use]
+    pub const fn max_annotation_length(self, max_annotation_length: usize) -> Self {
+        Self {
+            max_annotation_length,
+            ..self
+        }
+    }
+
+    /// The maximum absolute value of each term's exponent.
+    ///
+    #[must_use]
+    pub const fn max_exponent(self, max_exponent: u32) -> Self {
+        Self {
+            max_exponent,
+            ..self
+        }
+    }
+
+    /// Checks the limits that can be checked before parsing: the expression's length, its
+    /// parenthesis depth, and the length of its annotations. This is a single pass over the bytes,
+    /// so that the (recursive) parser never sees an expression that's too deep.
+    ///
+    pub(crate) fn check_expression(self, expression: &str) -> Result<(), Error> {
+        if expression.len() > self.max_length {
+            return Err(self.exceeded(Limit::Length));
+        }
+
+        let mut depth = 0_usize;
+        let mut annotation_start = None;
+
+        for (i, byte) in expression.bytes().enumerate() {
+            match (annotation_start, byte) {
+                (None, b'{') => annotation_start = Some(i + 1),
+                (None, b'(') => {
+                    depth += 1;
+
+                    if depth > self.max_depth {
+                        return Err(self.exceeded(Limit::Depth));
+                    }
+                }
+                (None, b')') => depth = depth.saturating_sub(1),
+                (Some(start), b'}') => {
+                    if i - start > self.max_annotation_length {
+                        return Err(self.exceeded(Limit::AnnotationLength));
+                    }
+
+                    annotation_start = None;
+                }
+                _ => (),
+            }
+        }
+
+        // An unclosed annotation is a syntax error, but make sure it's not a huge one first.
+        match annotation_start {
+            Some(start) if expression.len() - start > self.max_annotation_length => {
+                Err(self.exceeded(Limit::AnnotationLength))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the limits that can only be checked after parsing: the number of terms and the size
+    /// of their exponents.
+    ///
+    pub(crate) fn check_terms(self, terms: &[Term]) -> Result<(), Error> {
+        if terms.len() > self.max_terms {
+            return Err(self.exceeded(Limit::Terms));
+        }
+
+        let too_large = terms.iter().any(|term| {
+            matches!(term.exponent(), Some(exponent) if exponent.unsigned_abs() > self.max_exponent)
+        });
+
+        if too_large {
+            Err(self.exceeded(Limit::Exponent))
+        } else {
+            Ok(())
+        }
+    }
+
+    const fn exceeded(self, limit: Limit) -> Error {
+        let max = match limit {
+            Limit::Length => self.max_length,
+            Limit::Depth => self.max_depth,
+            Limit::Terms => self.max_terms,
+            Limit::AnnotationLength => self.max_annotation_length,
+            Limit::Exponent => self.max_exponent as usize,
+        };
+
+        Error::LimitExceeded { limit, max }
+    }
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_length: Self::DEFAULT_MAX_LENGTH,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_terms: Self::DEFAULT_MAX_TERMS,
+            max_annotation_length: Self::DEFAULT_MAX_ANNOTATION_LENGTH,
+            max_exponent: Self::DEFAULT_MAX_EXPONENT,
+        }
+    }
+}
+
+/// Which of the `ParseLimits` an expression exceeded.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Limit {
+    Length,
+    Depth,
+    Terms,
+    AnnotationLength,
+    Exponent,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Length => "length",
+            Self::Depth => "parenthesis depth",
+            Self::Terms => "number of terms",
+            Self::AnnotationLength => "annotation length",
+            Self::Exponent => "exponent",
+        };
+
+        f.write_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        unit::{ParseOptions, ParserError},
+        Unit,
+    };
+
+    use super::*;
+
+    fn parse(expression: &str, limits: ParseLimits) -> Result<Unit, ParserError> {
+        let options = ParseOptions::default().limits(limits);
+
+        match Unit::from_str_with_options(expression, &options) {
+            Ok(unit) => Ok(unit),
+            Err(crate::Error::ParsingFailed(error)) => Err(error),
+            Err(error) => panic!("unexpected error: {error}"),
+        }
+    }
+
+    macro_rules! validate_exceeded {
+        ($test_name:ident, $expression:expr, $limits:expr, $limit:expr, $max:expr) => {
+            #[test]
+            fn $test_name() {
+                assert_eq!(
+                    parse($expression, $limits),
+                    Err(ParserError::LimitExceeded {
+                        limit: $limit,
+                        max: $max
+                    })
+                );
+            }
+        };
+    }
+
+    validate_exceeded!(
+        validate_length,
+        "kg/m2",
+        ParseLimits::default().max_length(4),
+        Limit::Length,
+        4
+    );
+    validate_exceeded!(
+        validate_depth,
+        "m/(s.(g))",
+        ParseLimits::default().max_depth(1),
+        Limit::Depth,
+        1
+    );
+    validate_exceeded!(
+        validate_terms,
+        "m.s.g",
+        ParseLimits::default().max_terms(2),
+        Limit::Terms,
+        2
+    );
+    validate_exceeded!(
+        validate_annotation_length,
+        "{tbl}.{tablets}",
+        ParseLimits::default().max_annotation_length(5),
+        Limit::AnnotationLength,
+        5
+    );
+    validate_exceeded!(
+        validate_unclosed_annotation_length,
+        "{tablets",
+        ParseLimits::default().max_annotation_length(5),
+        Limit::AnnotationLength,
+        5
+    );
+    validate_exceeded!(
+        validate_exponent,
+        "m/s-4",
+        ParseLimits::default().max_exponent(3),
+        Limit::Exponent,
+        3
+    );
+
+    #[test]
+    fn validate_within_limits() {
+        let limits = ParseLimits::default()
+            .max_length(15)
+            .max_depth(1)
+            .max_terms(4)
+            .max_annotation_length(7)
+            .max_exponent(2);
+
+        assert!(parse("{tablets}/(m2)", limits).is_ok());
+        assert!(parse("m.(s.g)", limits).is_ok());
+
+        // Parentheses inside annotations don't count toward the depth.
+        assert!(parse("{((()))}", limits).is_ok());
+    }
+
+    #[test]
+    fn validate_defaults() {
+        let limits = ParseLimits::default();
+        let deep = format!("{}m{}", "(".repeat(100_000), ")".repeat(100_000));
+
+        assert!(matches!(
+            parse(&deep, limits),
+            Err(ParserError::LimitExceeded {
+                limit: Limit::Length,
+                ..
+            })
+        ));
+
+        let deep = format!("{}m{}", "(".repeat(33), ")".repeat(33));
+
+        assert!(matches!(
+            parse(&deep, limits),
+            Err(ParserError::LimitExceeded {
+                limit: Limit::Depth,
+                max: ParseLimits::DEFAULT_MAX_DEPTH
+            })
+        ));
+
+        let many = vec!["m"; 129].join(".");
+
+        assert!(matches!(
+            parse(&many, limits),
+            Err(ParserError::LimitExceeded {
+                limit: Limit::Terms,
+                ..
+            })
+        ));
+
+        assert!(parse("10*1001", limits).is_err());
+        assert!(parse("10*1000", limits).is_ok());
+    }
+
+    #[test]
+    fn validate_unlimited() {
+        let deep = format!("{}m{}", "(".repeat(40), ")".repeat(40));
+
+        assert!(parse(&deep, ParseLimits::unlimited()).is_ok());
+    }
+
+    #[test]
+    fn validate_from_str_is_unlimited() {
+        let deep = format!("{}m{}", "(".repeat(40), ")".repeat(40));
+        let many = vec!["m"; 129].join(".");
+
+        assert!(Unit::from_str(&deep).is_ok());
+        assert!(Unit::from_str(&many).is_ok());
+        assert!(Unit::from_str("10*1001").is_ok());
+    }
+}
@@ -0,0 +1,435 @@
+use std::ops::Range;
+
+use crate::{Atom, Prefix, Term, UcumSymbol, UcumUnit, Unit};
+
+use super::{Diagnostic, DiagnosticKind, Error};
+
+const EXPECTED_NAME: &str = "unit name";
+
+/// The result of parsing an English unit expression (ex. "kilograms per hectare") with
+/// `Unit::from_name()`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedUnit {
+    /// The parsed unit. Where a name was ambiguous, this uses the first of its candidates.
+    ///
+    pub unit: Unit,
+
+    /// Each name in the expression that could refer to more than one atom. If this isn't empty,
+    /// `unit` may not be what the writer meant.
+    ///
+    pub ambiguities: Vec<Ambiguity>,
+}
+
+/// A name that could refer to more than one atom (ex. "ounce" is used for the avoirdupois, troy,
+/// and apothecaries' ounces).
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ambiguity {
+    /// The name, as written in the expression.
+    ///
+    pub name: String,
+
+    /// The byte range of `name` in the expression.
+    ///
+    pub span: Range<usize>,
+
+    /// Every unit `name` could refer to, in `Atoms.toml` order; the first is the one used in
+    /// `NamedUnit::unit`.
+    ///
+    pub candidates: Vec<Unit>,
+}
+
+/// A single word of the expression, along with the forms it could have when singular.
+///
+struct Word {
+    forms: Vec<String>,
+    span: Range<usize>,
+}
+
+impl Word {
+    fn new(word: &str, span: Range<usize>) -> Self {
+        let word = word.to_lowercase();
+        let mut forms = vec![word.clone()];
+
+        if word == "feet" {
+            forms.push("foot".to_string());
+        }
+
+        if let Some(stem) = word.strip_suffix("ies") {
+            forms.push(format!("{stem}y"));
+        }
+
+        if let Some(stem) = word.strip_suffix("es") {
+            forms.push(stem.to_string());
+        }
+
+        if let Some(stem) = word.strip_suffix('s') {
+            forms.push(stem.to_string());
+        }
+
+        // British spellings: "metre", "litre"
+        for index in 0..forms.len() {
+            if let Some(stem) = forms[index].strip_suffix("tre") {
+                forms.push(format!("{stem}ter"));
+            }
+        }
+
+        Self { forms, span }
+    }
+
+    fn is(&self, form: &str) -> bool {
+        self.forms.iter().any(|f| f == form)
+    }
+
+    fn is_any(&self, forms: &[&str]) -> bool {
+        forms.iter().any(|form| self.is(form))
+    }
+}
+
+pub(crate) fn parse(expression: &str) -> Result<NamedUnit, Error> {
+    let words = split_words(expression);
+
+    if words.is_empty() {
+        return Err(Error::InvalidExpression(Diagnostic::new(
+            DiagnosticKind::EmptyExpression,
+            expression,
+            0..expression.len(),
+            vec![EXPECTED_NAME],
+        )));
+    }
+
+    let mut terms: Vec<Term> = Vec::new();
+    let mut ambiguities: Vec<Ambiguity> = Vec::new();
+    let mut position = 0;
+    let mut in_denominator = false;
+    let mut expecting_unit = true;
+
+    while let Some(word) = words.get(position) {
+        if word.is_any(&["per", "/", "times"]) {
+            if expecting_unit {
+                return Err(dangling_operator(expression, word));
+            }
+
+            // Like UCUM's `/` and `.`, each operator applies to the unit after it, left to right:
+            // "per" divides by it, and "times" multiplies by it.
+            in_denominator = !word.is("times");
+            expecting_unit = true;
+            position += 1;
+            continue;
+        }
+
+        let (mut term, consumed, candidates) = parse_term(expression, &words[position..])?;
+        let span = word.span.start..words[position + consumed - 1].span.end;
+
+        if candidates.len() > 1 {
+            ambiguities.push(Ambiguity {
+                name: expression[span.clone()].to_string(),
+                span,
+                candidates,
+            });
+        }
+
+        if in_denominator {
+            let exponent = term.exponent().unwrap_or(1);
+            let _ = term.set_exponent(-exponent);
+        }
+
+        terms.push(term);
+        expecting_unit = false;
+        position += consumed;
+    }
+
+    if expecting_unit {
+        return Err(dangling_operator(expression, &words[words.len() - 1]));
+    }
+
+    Ok(NamedUnit {
+        unit: Unit::new(terms),
+        ambiguities,
+    })
+}
+
+/// Splits `expression` into lowercase words; `/` is treated as its own word.
+///
+fn split_words(expression: &str) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for (index, c) in expression.char_indices() {
+        if c.is_alphanumeric() || c == '\'' {
+            if start.is_none() {
+                start = Some(index);
+            }
+
+            continue;
+        }
+
+        if let Some(word_start) = start.take() {
+            words.push(Word::new(&expression[word_start..index], word_start..index));
+        }
+
+        if c == '/' {
+            words.push(Word::new("/", index..index + 1));
+        }
+    }
+
+    if let Some(word_start) = start {
+        words.push(Word::new(
+            &expression[word_start..],
+            word_start..expression.len(),
+        ));
+    }
+
+    words
+}
+
+/// Parses a single, optionally-powered, unit name at the start of `words`. Returns the `Term`, the
+/// number of words it took up, and the candidate units for the name (when there's more than one).
+///
+fn parse_term(expression: &str, words: &[Word]) -> Result<(Term, usize, Vec<Unit>), Error> {
+    let mut consumed = 0;
+    let mut candidates = match_names(words);
+
+    if candidates.is_empty() {
+        let leading_exponent = if words[0].is_any(&["square", "sq"]) {
+            Some(2)
+        } else if words[0].is_any(&["cubic", "cu"]) {
+            Some(3)
+        } else {
+            None
+        };
+
+        if let Some(exponent) = leading_exponent {
+            candidates = match_names(&words[1..])
+                .into_iter()
+                .map(|(length, prefix, atom, _)| (length + 1, prefix, atom, exponent))
+                .collect();
+        }
+    }
+
+    let (length, prefix, atom, mut exponent) = candidates.first().copied().ok_or_else(|| {
+        Error::InvalidExpression(Diagnostic::new(
+            DiagnosticKind::UnknownSymbol,
+            expression,
+            words[0].span.clone(),
+            vec![EXPECTED_NAME],
+        ))
+    })?;
+
+    consumed += length;
+
+    if let Some(next) = words.get(consumed) {
+        if next.is("squared") {
+            exponent *= 2;
+            consumed += 1;
+        } else if next.is("cubed") {
+            exponent *= 3;
+            consumed += 1;
+        }
+    }
+
+    let mut term = Term::new(prefix, Some(atom));
+
+    if exponent != 1 {
+        let _ = term.set_exponent(exponent);
+    }
+
+    let mut units: Vec<Unit> = Vec::with_capacity(candidates.len());
+
+    for (_, prefix, atom, _) in candidates {
+        let unit = Unit::new(vec![Term::new(prefix, Some(atom))]);
+
+        if !units.contains(&unit) {
+            units.push(unit);
+        }
+    }
+
+    if units.len() == 1 {
+        units.clear();
+    }
+
+    Ok((term, consumed, units))
+}
+
+type Candidate = (usize, Option<Prefix>, Atom, i32);
+
+/// Finds the atoms whose name is the longest match for the start of `words`. If no atom matches,
+/// tries to match the first word as a prefix name followed by a metric atom name (ex. "kilogram",
+/// "hectare").
+///
+fn match_names(words: &[Word]) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = Vec::new();
+
+    for atom in Atom::ALL {
+        for name in atom.names() {
+            let name = name.to_lowercase();
+            let name_words: Vec<&str> = name.split(' ').collect();
+
+            if name_words.len() > words.len()
+                || !name_words
+                    .iter()
+                    .zip(words)
+                    .all(|(name_word, word)| word.is(name_word))
+            {
+                continue;
+            }
+
+            match candidates.first() {
+                Some(&(length, ..)) if length > name_words.len() => continue,
+                Some(&(length, ..)) if length < name_words.len() => candidates.clear(),
+                _ => (),
+            }
+
+            if !candidates.iter().any(|&(_, _, a, _)| a == atom) {
+                candidates.push((name_words.len(), None, atom, 1));
+            }
+        }
+    }
+
+    if candidates.is_empty() {
+        if let Some(word) = words.first() {
+            for form in &word.forms {
+                candidates
+                    .extend(match_prefixed(form).map(|(prefix, atom)| (1, Some(prefix), atom, 1)));
+            }
+        }
+    }
+
+    candidates
+}
+
+fn match_prefixed(word: &str) -> impl Iterator<Item = (Prefix, Atom)> + '_ {
+    Prefix::ALL.into_iter().flat_map(move |prefix| {
+        Atom::ALL.into_iter().filter_map(move |atom| {
+            if !atom.is_metric() {
+                return None;
+            }
+
+            let is_match = prefix.names().iter().any(|prefix_name| {
+                atom.names().iter().any(|atom_name| {
+                    let atom_name = atom_name.to_lowercase();
+
+                    // "hecto" + "are" is written "hectare"
+                    let elided = prefix_name
+                        .strip_suffix(|c: char| "aeiou".contains(c))
+                        .filter(|_| atom_name.starts_with(|c: char| "aeiou".contains(c)));
+
+                    [Some(*prefix_name), elided]
+                        .into_iter()
+                        .flatten()
+                        .any(|p| word.strip_prefix(p) == Some(atom_name.as_str()))
+                })
+            });
+
+            is_match.then_some((prefix, atom))
+        })
+    })
+}
+
+fn dangling_operator(expression: &str, word: &Word) -> Error {
+    Error::InvalidExpression(Diagnostic::new(
+        DiagnosticKind::DanglingOperator,
+        expression,
+        word.span.clone(),
+        vec![EXPECTED_NAME],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    macro_rules! validate_parse {
+        ($test_name:ident, $input:expr, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                let named = parse($input).unwrap();
+                assert_eq!(named.unit.expression(), $expected);
+            }
+        };
+    }
+
+    validate_parse!(validate_single, "meter", "m");
+    validate_parse!(validate_plural, "meters", "m");
+    validate_parse!(validate_british_spelling, "litres", "l");
+    validate_parse!(validate_irregular_plural, "feet", "[ft_i]");
+    validate_parse!(validate_prefixed, "kilogram", "kg");
+    validate_parse!(validate_prefixed_elided, "hectares", "har");
+    validate_parse!(validate_per, "kilogram per hectare", "kg/har");
+    validate_parse!(validate_slash, "kilograms / hectare", "kg/har");
+    validate_parse!(validate_per_per, "meters per second per second", "m/s.s");
+    validate_parse!(
+        validate_pounds_per_acre,
+        "pounds per acre",
+        "[lb_av]/[acr_us]"
+    );
+    validate_parse!(validate_cubic, "cubic meters", "m3");
+    validate_parse!(validate_square, "square kilometers", "km2");
+    validate_parse!(validate_squared, "meters per second squared", "m/s2");
+    validate_parse!(validate_multiword_atom, "square feet", "[sft_i]");
+    validate_parse!(
+        validate_multiword_atom_with_per,
+        "parts per million",
+        "[ppm]"
+    );
+    validate_parse!(validate_capitalized, "Degrees Celsius", "Cel");
+    validate_parse!(validate_product, "newton meter", "N.m");
+    validate_parse!(validate_times, "newton times meter", "N.m");
+    validate_parse!(
+        validate_per_then_times,
+        "pounds per acre times hours",
+        "[lb_av].h/[acr_us]"
+    );
+    validate_parse!(validate_per_then_product, "watts per meter kelvin", "W/m.K");
+
+    #[test]
+    fn validate_ambiguity() {
+        let named = parse("ounces per hectare").unwrap();
+
+        assert!(!named.ambiguities.is_empty());
+        assert_eq!(named.unit.expression(), "[oz_av]/har");
+        assert_eq!(named.ambiguities.len(), 1);
+
+        let ambiguity = &named.ambiguities[0];
+        assert_eq!(ambiguity.name, "ounces");
+        assert_eq!(ambiguity.span, 0..6);
+        assert_eq!(
+            ambiguity.candidates,
+            vec![
+                Unit::from_str("[oz_av]").unwrap(),
+                // The apothecaries' ounce is equal to the troy ounce, so isn't listed separately.
+                Unit::from_str("[oz_tr]").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_equivalent_candidates_arent_ambiguous() {
+        assert!(parse("liter").unwrap().ambiguities.is_empty());
+    }
+
+    #[test]
+    fn validate_errors() {
+        let diagnostic = |input| match parse(input) {
+            Err(Error::InvalidExpression(diagnostic)) => diagnostic,
+            other => panic!("expected a diagnostic, got {other:?}"),
+        };
+
+        let unknown = diagnostic("kilograms per blorp");
+        assert_eq!(unknown.kind, DiagnosticKind::UnknownSymbol);
+        assert_eq!(unknown.span, 14..19);
+
+        assert_eq!(
+            diagnostic("kilograms per").kind,
+            DiagnosticKind::DanglingOperator
+        );
+        assert_eq!(
+            diagnostic("per meter").kind,
+            DiagnosticKind::DanglingOperator
+        );
+        assert_eq!(diagnostic("  ").kind, DiagnosticKind::EmptyExpression);
+    }
+}
@@ -0,0 +1,104 @@
+use super::ParseLimits;
+
+/// Settings that control how a unit expression is parsed.
+///
+/// The default settings are what `Unit::from_str()` uses.
+///
+/// ```rust
+/// use wise_units::{
+///     unit::{CaseMode, ParseOptions},
+///     Unit,
+/// };
+///
+/// let options = ParseOptions::default().case_mode(CaseMode::Insensitive);
+/// let unit = Unit::from_str_with_options("MG/[LB_AV]", &options).unwrap();
+///
+/// assert_eq!(unit.expression(), "mg/[lb_av]");
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) case_mode: CaseMode,
+    pub(crate) print_notation: bool,
+    pub(crate) decimal_factors: bool,
+    pub(crate) limits: ParseLimits,
+}
+
+impl ParseOptions {
+    /// Set which of UCUM's code systems to accept for atoms and prefixes.
+    ///
+    #[must_use]
+    pub const fn case_mode(self, case_mode: CaseMode) -> Self {
+        Self { case_mode, ..self }
+    }
+
+    /// Also accept "print" notation: print symbols (ex. `°C`, `µg`, `Å`), superscript exponents
+    /// (ex. `m²`, `s⁻¹`), `·` for multiplication, and `^`/`**` exponents (ex. `m^2`). This is
+    /// normalized to UCUM notation before parsing, but the spans of any `Diagnostic` still refer to
+    /// the expression as given.
+    ///
+    #[must_use]
+    pub const fn print_notation(self, print_notation: bool) -> Self {
+        Self {
+            print_notation,
+            ..self
+        }
+    }
+
+    /// Also accept factors that are decimal or in scientific notation (ex. `0.5L`, `2.5kg`,
+    /// `1e6/L`), including ones too large for `term::Factor`. These are represented exactly, as an
+    /// integer factor times a power of the `10*` atom (ex. `0.5L` becomes `5.L/10*`).
+    ///
+    /// Note that this changes the meaning of some valid UCUM expressions: normally, `2.5kg` is
+    /// parsed as `2` times `5kg`.
+    ///
+    #[must_use]
+    pub const fn decimal_factors(self, decimal_factors: bool) -> Self {
+        Self {
+            decimal_factors,
+            ..self
+        }
+    }
+
+    /// Set the `ParseLimits` to enforce. By default (as with `Unit::from_str()`), there are none;
+    /// use `ParseLimits::default()` when parsing untrusted input.
+    ///
+    #[must_use]
+    pub const fn limits(self, limits: ParseLimits) -> Self {
+        Self { limits, ..self }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            case_mode: CaseMode::default(),
+            print_notation: false,
+            decimal_factors: false,
+            limits: ParseLimits::unlimited(),
+        }
+    }
+}
+
+/// Which of UCUM's two code systems to accept when parsing atoms and prefixes.
+///
+/// UCUM defines a case-sensitive code (`UcumSymbol::primary_code()`, ex. `mg`) and a
+/// case-insensitive one (`UcumSymbol::secondary_code()`, ex. `MG`) for each atom and prefix. Since
+/// the two systems overlap (ex. `MG` is "megagauss" in the former, "milligram" in the latter), this picks
+/// which to use.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CaseMode {
+    /// Only accept case-sensitive codes.
+    ///
+    Sensitive,
+
+    /// Only accept case-insensitive codes, regardless of the case they're written in.
+    ///
+    Insensitive,
+
+    /// Accept either, preferring the case-sensitive interpretation.
+    ///
+    #[default]
+    Both,
+}
@@ -0,0 +1,330 @@
+#![allow(clippy::non_ascii_literal)]
+
+use std::{borrow::Cow, ops::Range};
+
+use once_cell::sync::Lazy;
+
+use crate::{Atom, Prefix, UcumSymbol};
+
+use super::{Diagnostic, DiagnosticKind, Error};
+
+/// Characters that commonly show up in text copied from documents and spreadsheets in place of
+/// the ones used in `Atoms.toml`'s print symbols, mapped to the code they stand for.
+///
+const ALIASES: [(&str, &str); 7] = [
+    // MICRO SIGN, vs. the print symbol's GREEK SMALL LETTER MU
+    ("µ", "u"),
+    // OHM SIGN, vs. GREEK CAPITAL LETTER OMEGA
+    ("Ω", "Ohm"),
+    // ANGSTROM SIGN, vs. LATIN CAPITAL LETTER A WITH RING ABOVE
+    ("Å", "Ao"),
+    ("℃", "Cel"),
+    ("℉", "[degF]"),
+    // PRIME and DOUBLE PRIME, vs. apostrophes
+    ("′", "'"),
+    ("″", "''"),
+];
+
+/// Plain-text, non-ASCII print symbols (and `ALIASES`), with the code each maps to. Print symbols
+/// that start with an ASCII character are skipped: those are either also valid codes or would be
+/// ambiguous with codes (ex. `at`, the print symbol for the tropical year, is the code for the
+/// technical atmosphere).
+///
+static PRINT_SYMBOLS: Lazy<Vec<(String, &'static str)>> = Lazy::new(|| {
+    let atoms = Atom::ALL
+        .iter()
+        .filter_map(|atom| Some((atom.print_symbol()?, atom.primary_code())));
+    let prefixes = Prefix::ALL
+        .iter()
+        .filter_map(|prefix| Some((prefix.print_symbol()?, prefix.primary_code())));
+
+    ALIASES
+        .iter()
+        .copied()
+        .chain(atoms)
+        .chain(prefixes)
+        .filter(|(print_symbol, _)| !print_symbol.starts_with(|c: char| c.is_ascii()))
+        .map(|(print_symbol, code)| (plain_text(print_symbol), code))
+        .collect()
+});
+
+/// An expression that `normalize()` rewrote into UCUM notation, along with where in the original
+/// expression each of its bytes came from.
+///
+#[derive(Debug)]
+pub(super) struct Normalized<'a> {
+    original: &'a str,
+    pub(super) expression: Cow<'a, str>,
+
+    /// For each byte of `expression`, the span of `original` that it was rewritten from. Empty if
+    /// `expression` is `original`.
+    ///
+    sources: Vec<Range<usize>>,
+}
+
+impl Normalized<'_> {
+    /// Points the `Diagnostic` in `error` (which describes `expression`) at the corresponding part
+    /// of the original expression.
+    ///
+    pub(super) fn remap(&self, error: Error) -> Error {
+        match error {
+            Error::InvalidExpression(mut diagnostic) if !self.sources.is_empty() => {
+                let span = &diagnostic.span;
+                let start = self
+                    .sources
+                    .get(span.start)
+                    .map_or(self.original.len(), |source| source.start);
+                let end = if span.end > span.start {
+                    self.sources
+                        .get(span.end - 1)
+                        .map_or(self.original.len(), |source| source.end)
+                } else {
+                    start
+                };
+
+                diagnostic.fragment = self.original[start..end].to_string();
+                diagnostic.span = start..end;
+
+                Error::InvalidExpression(diagnostic)
+            }
+            error => error,
+        }
+    }
+}
+
+/// Rewrites `expression` from "print" notation to UCUM notation:
+///
+/// * print symbols that aren't valid codes (ex. `°C`, `μg`, `Å`) become their codes,
+/// * superscript exponents (ex. `m²`, `s⁻¹`) become regular exponents,
+/// * `·` becomes `.`,
+/// * `^` and `**` exponent notation (ex. `m^2`, `m**2`) is dropped, except after a `10`, where
+///   it's turned into the `10^`/`10*` atom.
+///
+/// Annotations are left as-is.
+///
+/// # Errors
+///
+/// Returns a `BadExponent` `Diagnostic` for an exponent on a numeric factor other than `10` (ex.
+/// `2²`), since UCUM can't express that (and gluing the digits together would change the number).
+///
+pub(super) fn normalize(expression: &str) -> Result<Normalized<'_>, Diagnostic> {
+    if expression.is_ascii() && !expression.contains('^') && !expression.contains("**") {
+        return Ok(Normalized {
+            original: expression,
+            expression: Cow::Borrowed(expression),
+            sources: Vec::new(),
+        });
+    }
+
+    let mut output = String::with_capacity(expression.len());
+    let mut sources = Vec::with_capacity(expression.len());
+    let mut rest = expression;
+
+    while let Some(c) = rest.chars().next() {
+        let offset = expression.len() - rest.len();
+
+        if c == '{' {
+            let end = rest.find('}').map_or(rest.len(), |index| index + 1);
+            output.push_str(&rest[..end]);
+            sources.extend((offset..offset + end).map(|i| i..i + 1));
+            rest = &rest[end..];
+            continue;
+        }
+
+        let symbol = if c.is_ascii() {
+            None
+        } else {
+            print_symbol_at(rest)
+        };
+
+        let length = if rest.starts_with("**") {
+            if check_exponent_base(&output, expression, offset..offset + 2)? {
+                output.push('*');
+            }
+
+            2
+        } else if let Some((length, code)) = symbol {
+            output.push_str(code);
+            length
+        } else {
+            match c {
+                '^' => {
+                    if check_exponent_base(&output, expression, offset..offset + 1)? {
+                        output.push('^');
+                    }
+                }
+                '·' | '⋅' => output.push('.'),
+                _ => match from_superscript(c) {
+                    Some(ascii) => {
+                        let span = offset..offset + c.len_utf8();
+
+                        if check_exponent_base(&output, expression, span)? {
+                            output.push('*');
+                        }
+
+                        output.push(ascii);
+                    }
+                    None => output.push(c),
+                },
+            }
+
+            c.len_utf8()
+        };
+
+        sources.resize(output.len(), offset..offset + length);
+        rest = &rest[length..];
+    }
+
+    Ok(Normalized {
+        original: expression,
+        expression: Cow::Owned(output),
+        sources,
+    })
+}
+
+/// Checks what an exponent at `span` applies to. Returns `true` if it's the number `10` (so the
+/// exponent belongs to the `10*`/`10^` atom), `false` if it's an atom, or a `Diagnostic` if it's
+/// any other number.
+///
+fn check_exponent_base(
+    output: &str,
+    expression: &str,
+    span: Range<usize>,
+) -> Result<bool, Diagnostic> {
+    let base = current_term(output);
+
+    if base == "10" {
+        return Ok(true);
+    }
+
+    if !base.is_empty() && base.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Diagnostic::new(
+            DiagnosticKind::BadExponent,
+            expression,
+            span,
+            vec!["unit"],
+        ));
+    }
+
+    Ok(false)
+}
+
+/// The part of `output` after the last operator or parenthesis.
+///
+fn current_term(output: &str) -> &str {
+    output
+        .rsplit(['.', '/', '(', ')'])
+        .next()
+        .unwrap_or_default()
+}
+
+const fn from_superscript(c: char) -> Option<char> {
+    let ascii = match c {
+        '⁰' => '0',
+        '¹' => '1',
+        '²' => '2',
+        '³' => '3',
+        '⁴' => '4',
+        '⁵' => '5',
+        '⁶' => '6',
+        '⁷' => '7',
+        '⁸' => '8',
+        '⁹' => '9',
+        '⁺' => '+',
+        '⁻' => '-',
+        _ => return None,
+    };
+
+    Some(ascii)
+}
+
+/// Finds the longest non-ASCII print symbol (or alias) that `rest` starts with, returning its
+/// length and the code it maps to.
+///
+fn print_symbol_at(rest: &str) -> Option<(usize, &'static str)> {
+    PRINT_SYMBOLS
+        .iter()
+        .filter(|(print_symbol, _)| rest.starts_with(print_symbol.as_str()))
+        .max_by_key(|(print_symbol, _)| print_symbol.len())
+        .map(|(print_symbol, code)| (print_symbol.len(), *code))
+}
+
+/// Print symbols can contain HTML markup (ex. `<i>μ<sub><r>0</r></sub></i>`); this strips that
+/// out, along with non-breaking spaces.
+///
+fn plain_text(print_symbol: &str) -> String {
+    let mut output = String::with_capacity(print_symbol.len());
+    let mut in_tag = false;
+
+    for c in print_symbol.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if in_tag => (),
+            '\u{a0}' => output.push(' '),
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! validate_normalize {
+        ($test_name:ident, $input:expr, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                assert_eq!(normalize($input).unwrap().expression, $expected);
+            }
+        };
+    }
+
+    validate_normalize!(validate_ucum, "kg/m2", "kg/m2");
+    validate_normalize!(validate_degree_celsius, "°C", "Cel");
+    validate_normalize!(validate_degree_fahrenheit, "°F", "[degF]");
+    validate_normalize!(validate_degree_reaumur, "°Ré", "[degRe]");
+    validate_normalize!(validate_degree, "°", "deg");
+    validate_normalize!(validate_greek_mu, "μg", "ug");
+    validate_normalize!(validate_micro_sign, "µg", "ug");
+    validate_normalize!(validate_angstrom, "Å", "Ao");
+    validate_normalize!(validate_prefixed_ohm, "kΩ", "kOhm");
+    validate_normalize!(validate_prime, "′", "'");
+    validate_normalize!(validate_double_prime, "″", "''");
+    validate_normalize!(validate_superscript, "m²", "m2");
+    validate_normalize!(validate_negative_superscript, "s⁻¹", "s-1");
+    validate_normalize!(validate_middle_dot, "kg·m⁻²", "kg.m-2");
+    validate_normalize!(validate_caret, "m^2/s^-1", "m2/s-1");
+    validate_normalize!(validate_double_star, "m**2", "m2");
+    validate_normalize!(validate_ten_caret, "10^3/uL", "10^3/uL");
+    validate_normalize!(validate_ten_double_star, "10**3/uL", "10*3/uL");
+    validate_normalize!(validate_ten_superscript, "10³/µL", "10*3/uL");
+    validate_normalize!(validate_ten_negative_superscript, "10⁻³", "10*-3");
+    validate_normalize!(validate_factor_then_atom, "2m²", "2m2");
+    validate_normalize!(validate_annotation, "°C{°C²}", "Cel{°C²}");
+
+    #[test]
+    fn validate_borrows_ucum() {
+        assert!(matches!(
+            normalize("mg/dL").unwrap().expression,
+            Cow::Borrowed("mg/dL")
+        ));
+    }
+
+    #[test]
+    fn validate_exponent_on_factor() {
+        for (input, span) in [
+            ("2²", 1..3),
+            ("110²", 3..5),
+            ("m/2^3", 3..4),
+            ("kg.3**2", 4..6),
+        ] {
+            let diagnostic = normalize(input).unwrap_err();
+
+            assert_eq!(diagnostic.kind, DiagnosticKind::BadExponent, "{input}");
+            assert_eq!(diagnostic.span, span, "{input}");
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{Atom, Prefix, UcumSymbol, UcumUnit};
+
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Finds the atom codes (optionally prefixed) that are closest to `fragment`, an unknown
+/// `simple_unit`. `fragment` is compared against each `Atom`'s primary code, secondary code, and
+/// names; suggestions are always returned as primary codes, closest first.
+///
+pub(crate) fn suggest(fragment: &str) -> Vec<String> {
+    let max_distance = (fragment.chars().count() / 4).clamp(1, 3);
+    let lowercase_fragment = fragment.to_lowercase();
+    let mut candidates: Vec<(usize, String)> = Vec::new();
+
+    let mut consider = |distance: usize, code: String| {
+        if distance <= max_distance {
+            candidates.push((distance, code));
+        }
+    };
+
+    for atom in Atom::ALL {
+        let code = atom.primary_code();
+
+        consider(distance(fragment, code), code.to_string());
+
+        if let Some(secondary_code) = atom.secondary_code() {
+            consider(distance(fragment, secondary_code), code.to_string());
+        }
+
+        for name in atom.names() {
+            consider(distance(&lowercase_fragment, name), code.to_string());
+        }
+
+        if !atom.is_metric() {
+            continue;
+        }
+
+        for prefix in Prefix::ALL {
+            let prefix_code = prefix.primary_code();
+
+            if let Some(rest) = fragment.strip_prefix(prefix_code) {
+                consider(distance(rest, code), format!("{prefix_code}{code}"));
+            }
+
+            if let (Some(secondary_prefix), Some(secondary_code)) =
+                (prefix.secondary_code(), atom.secondary_code())
+            {
+                if let Some(rest) = fragment.strip_prefix(secondary_prefix) {
+                    consider(
+                        distance(rest, secondary_code),
+                        format!("{prefix_code}{code}"),
+                    );
+                }
+            }
+
+            for name in prefix.names() {
+                if let Some(rest) = lowercase_fragment.strip_prefix(name) {
+                    for atom_name in atom.names() {
+                        consider(distance(rest, atom_name), format!("{prefix_code}{code}"));
+                    }
+                }
+            }
+        }
+    }
+
+    candidates.sort();
+
+    let mut suggestions: Vec<String> = Vec::with_capacity(MAX_SUGGESTIONS);
+
+    for (_, code) in candidates {
+        if code != fragment && !suggestions.contains(&code) {
+            suggestions.push(code);
+
+            if suggestions.len() == MAX_SUGGESTIONS {
+                break;
+            }
+        }
+    }
+
+    suggestions
+}
+
+/// Optimal string alignment distance: like Levenshtein distance, but also counts swapping two
+/// adjacent characters as a single edit.
+///
+fn distance(lhs: &str, rhs: &str) -> usize {
+    let lhs: Vec<char> = lhs.chars().collect();
+    let rhs: Vec<char> = rhs.chars().collect();
+
+    if lhs.is_empty() || rhs.is_empty() {
+        return lhs.len().max(rhs.len());
+    }
+
+    let width = rhs.len() + 1;
+    let mut table: Vec<usize> = vec![0; (lhs.len() + 1) * width];
+
+    for (i, cell) in table.iter_mut().step_by(width).enumerate() {
+        *cell = i;
+    }
+
+    for (j, cell) in table.iter_mut().take(width).enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=lhs.len() {
+        for j in 1..=rhs.len() {
+            let cost = usize::from(lhs[i - 1] != rhs[j - 1]);
+
+            let mut value = (table[(i - 1) * width + j] + 1)
+                .min(table[i * width + j - 1] + 1)
+                .min(table[(i - 1) * width + j - 1] + cost);
+
+            if i > 1 && j > 1 && lhs[i - 1] == rhs[j - 2] && lhs[i - 2] == rhs[j - 1] {
+                value = value.min(table[(i - 2) * width + j - 2] + 1);
+            }
+
+            table[i * width + j] = value;
+        }
+    }
+
+    table[lhs.len() * width + rhs.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_distance() {
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(distance("m", ""), 1);
+        assert_eq!(distance("[lb_avd]", "[lb_av]"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("[ni_i]", "[in_i]"), 1);
+    }
+
+    #[test]
+    fn validate_suggest_typo() {
+        assert_eq!(suggest("[lb_avd]").first().unwrap(), "[lb_av]");
+        assert_eq!(suggest("[ni_i]").first().unwrap(), "[in_i]");
+    }
+
+    #[test]
+    fn validate_suggest_name() {
+        let suggestions = suggest("acre");
+
+        assert!(suggestions.contains(&"[acr_us]".to_string()));
+        assert!(suggestions.contains(&"[acr_br]".to_string()));
+    }
+
+    #[test]
+    fn validate_suggest_secondary_code() {
+        assert_eq!(suggest("[LB_AVD]").first().unwrap(), "[lb_av]");
+    }
+
+    #[test]
+    fn validate_suggest_prefixed() {
+        assert_eq!(suggest("kgg").first().unwrap(), "kg");
+        assert!(suggest("kilogram").contains(&"kg".to_string()));
+    }
+
+    #[test]
+    fn validate_suggest_nothing_close() {
+        assert!(suggest("ZZZXXXXXXXXXXXXx").is_empty());
+    }
+}
@@ -1,9 +1,265 @@
-#[allow(clippy::too_many_lines)]
-pub(super) mod mapper;
-pub(super) mod symbol;
-pub(crate) mod symbol_parser;
+//! Looks up the atom (and prefix) that a simple unit's symbol (ex. `km`) stands for, using the
+//! perfect-hash tables that `atom_generator` generates from `Atoms.toml`.
+//!
+mod symbol_table;
+
+use phf::Map;
+
+use crate::{term::variants::PrefixAtom, Atom, Prefix, Term};
+
+use self::symbol_table::{
+    MAX_PREFIX_LENGTH, PRIMARY_ATOMS, PRIMARY_PREFIXES, SECONDARY_ATOMS, SECONDARY_PREFIXES,
+};
+
+use super::CaseMode;
+
+/// No code, including its prefix, is longer than this (ex. `k[ANTI'XA'U]` is 14 bytes), so longer
+/// symbols can't be valid, and shorter ones can be upper-cased without allocating.
+///
+const MAX_SYMBOL_LENGTH: usize = 32;
+
+/// The `Term` that `symbol` is the code for, with or without a prefix.
+///
+/// With `CaseMode::Insensitive`, `symbol` is upper-cased, then looked up by case-insensitive codes;
+/// with `CaseMode::Both`, case-sensitive codes are tried first, then (as is) case-insensitive ones.
+///
+pub(super) fn lookup(symbol: &str, case_mode: CaseMode) -> Option<Term> {
+    match case_mode {
+        CaseMode::Sensitive => lookup_in(symbol, &PRIMARY_ATOMS, &PRIMARY_PREFIXES),
+        CaseMode::Insensitive => {
+            let mut buffer = [0_u8; MAX_SYMBOL_LENGTH];
+            let upper = buffer.get_mut(..symbol.len())?;
+            upper.copy_from_slice(symbol.as_bytes());
+            upper.make_ascii_uppercase();
+
+            lookup_in(
+                std::str::from_utf8(upper).ok()?,
+                &SECONDARY_ATOMS,
+                &SECONDARY_PREFIXES,
+            )
+        }
+        CaseMode::Both => lookup_in(symbol, &PRIMARY_ATOMS, &PRIMARY_PREFIXES)
+            .or_else(|| lookup_in(symbol, &SECONDARY_ATOMS, &SECONDARY_PREFIXES)),
+    }
+}
+
+/// An atom code takes precedence over a prefix and atom code (ex. `cal` is a calorie, not a
+/// centi-year). Otherwise, the longest prefix code that leaves a valid atom code wins (ex. `dam` is
+/// a dekameter, and `dar` a deciare).
+///
+fn lookup_in(
+    symbol: &str,
+    atoms: &Map<&'static str, Atom>,
+    prefixes: &Map<&'static str, Prefix>,
+) -> Option<Term> {
+    if let Some(atom) = atoms.get(symbol) {
+        return Some(Term::Atom(*atom));
+    }
+
+    let max_length = MAX_PREFIX_LENGTH.min(symbol.len().saturating_sub(1));
+
+    (1..=max_length).rev().find_map(|length| {
+        let prefix = prefixes.get(symbol.get(..length)?)?;
+        let atom = atoms.get(&symbol[length..])?;
+
+        Some(Term::PrefixAtom(PrefixAtom::new(*prefix, *atom)))
+    })
+}
 
 #[cfg(test)]
-mod symbol_parser_test;
+mod tests {
+    use super::*;
+
+    macro_rules! validate_lookup {
+        ($test_name:ident, $symbol:expr, $case_mode:expr, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                assert_eq!(
+                    format!("{:?}", lookup($symbol, $case_mode)),
+                    format!("{:?}", $expected)
+                );
+            }
+        };
+    }
+
+    const fn atom(atom: Atom) -> Term {
+        Term::Atom(atom)
+    }
+
+    const fn prefix_atom(prefix: Prefix, atom: Atom) -> Term {
+        Term::PrefixAtom(PrefixAtom::new(prefix, atom))
+    }
+
+    validate_lookup!(
+        validate_atom,
+        "m",
+        CaseMode::Sensitive,
+        Some(atom(Atom::Meter))
+    );
+    validate_lookup!(
+        validate_secondary_atom,
+        "M",
+        CaseMode::Insensitive,
+        Some(atom(Atom::Meter))
+    );
+    validate_lookup!(
+        validate_atom_with_possible_prefix,
+        "cal",
+        CaseMode::Sensitive,
+        Some(atom(Atom::Calorie))
+    );
+    validate_lookup!(
+        validate_secondary_atom_with_possible_prefix,
+        "CAL",
+        CaseMode::Both,
+        Some(atom(Atom::Calorie))
+    );
+    validate_lookup!(
+        validate_ten_star,
+        "10*",
+        CaseMode::Sensitive,
+        Some(atom(Atom::TheNumberTenForArbitraryPowersStar))
+    );
+    validate_lookup!(
+        validate_thermochemical_calorie,
+        "CAL_TH",
+        CaseMode::Insensitive,
+        Some(atom(Atom::ThermochemicalCalorie))
+    );
+    validate_lookup!(
+        validate_prefix_atom,
+        "km",
+        CaseMode::Sensitive,
+        Some(prefix_atom(Prefix::Kilo, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_secondary_prefix_atom,
+        "dm",
+        CaseMode::Insensitive,
+        Some(prefix_atom(Prefix::Deci, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_longest_prefix,
+        "dam",
+        CaseMode::Sensitive,
+        Some(prefix_atom(Prefix::Deka, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_longest_prefix_with_atom,
+        "dar",
+        CaseMode::Sensitive,
+        Some(prefix_atom(Prefix::Deci, Atom::Are))
+    );
+    validate_lookup!(
+        validate_three_letter_prefix,
+        "KIBBIT",
+        CaseMode::Insensitive,
+        Some(prefix_atom(Prefix::Kibi, Atom::Bit))
+    );
+    validate_lookup!(
+        validate_both_prefers_primary,
+        "MG",
+        CaseMode::Both,
+        Some(prefix_atom(Prefix::Mega, Atom::Gauss))
+    );
+    validate_lookup!(
+        validate_both_secondary,
+        "KG",
+        CaseMode::Both,
+        Some(prefix_atom(Prefix::Kilo, Atom::Gram))
+    );
+    validate_lookup!(
+        validate_shared_secondary_code,
+        "[iu]",
+        CaseMode::Insensitive,
+        Some(atom(Atom::InternationalUnit))
+    );
+    validate_lookup!(
+        validate_kelvin,
+        "K",
+        CaseMode::Sensitive,
+        Some(atom(Atom::Kelvin))
+    );
+    validate_lookup!(
+        validate_primary_thermochemical_calorie,
+        "cal_th",
+        CaseMode::Sensitive,
+        Some(atom(Atom::ThermochemicalCalorie))
+    );
+    validate_lookup!(
+        validate_primary_prefix_atom_with_possible_atom,
+        "dm",
+        CaseMode::Sensitive,
+        Some(prefix_atom(Prefix::Deci, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_secondary_prefix_atom_with_possible_atom,
+        "DM",
+        CaseMode::Insensitive,
+        Some(prefix_atom(Prefix::Deci, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_secondary_kilo,
+        "KM",
+        CaseMode::Insensitive,
+        Some(prefix_atom(Prefix::Kilo, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_atto,
+        "am",
+        CaseMode::Sensitive,
+        Some(prefix_atom(Prefix::Atto, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_yotta,
+        "Ym",
+        CaseMode::Sensitive,
+        Some(prefix_atom(Prefix::Yotta, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_secondary_yotta,
+        "YAM",
+        CaseMode::Insensitive,
+        Some(prefix_atom(Prefix::Yotta, Atom::Meter))
+    );
+    validate_lookup!(
+        validate_not_a_prefix,
+        "im",
+        CaseMode::Sensitive,
+        None::<Term>
+    );
+    validate_lookup!(validate_unknown, "kgg", CaseMode::Both, None::<Term>);
+    validate_lookup!(validate_prefix_only, "k", CaseMode::Sensitive, None::<Term>);
+    validate_lookup!(validate_empty, "", CaseMode::Both, None::<Term>);
+    validate_lookup!(validate_wrong_case, "KM", CaseMode::Sensitive, None::<Term>);
+    validate_lookup!(validate_non_ascii, "µm", CaseMode::Both, None::<Term>);
+
+    validate_lookup!(
+        validate_mixed_case_secondary_code,
+        "[degR]",
+        CaseMode::Insensitive,
+        Some(atom(Atom::DegreeRankine))
+    );
+    validate_lookup!(
+        validate_mixed_case_secondary_code_both,
+        "[DEGRE]",
+        CaseMode::Both,
+        Some(atom(Atom::DegreeReaumur))
+    );
+    validate_lookup!(
+        validate_too_long,
+        &"M".repeat(MAX_SYMBOL_LENGTH + 1),
+        CaseMode::Insensitive,
+        None::<Term>
+    );
 
-pub(super) use self::symbol::Symbol;
+    #[test]
+    fn validate_max_symbol_length() {
+        for atom in PRIMARY_ATOMS.keys().chain(SECONDARY_ATOMS.keys()) {
+            assert!(
+                atom.len() + MAX_PREFIX_LENGTH <= MAX_SYMBOL_LENGTH,
+                "{atom}"
+            );
+        }
+    }
+}
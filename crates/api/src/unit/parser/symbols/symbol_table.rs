@@ -0,0 +1,883 @@
+//-----------------------------------------------------------------------------
+// DO NOT EDIT THIS FILE!
+// This is generated by wise_units-atom_generator.
+//-----------------------------------------------------------------------------
+
+#![allow(clippy::unreadable_literal)]
+use crate::{Atom, Prefix};
+/// `Atom`s by their case-sensitive code (ex. `m`).
+pub(super) static PRIMARY_ATOMS: phf::Map<&'static str, Atom> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 180),
+        (2, 38),
+        (1, 295),
+        (0, 229),
+        (4, 119),
+        (1, 1),
+        (0, 89),
+        (1, 1),
+        (0, 0),
+        (0, 2),
+        (0, 22),
+        (0, 1),
+        (0, 225),
+        (0, 200),
+        (0, 0),
+        (0, 253),
+        (0, 104),
+        (0, 21),
+        (1, 94),
+        (0, 10),
+        (0, 62),
+        (0, 0),
+        (0, 87),
+        (0, 100),
+        (0, 50),
+        (0, 83),
+        (1, 52),
+        (0, 12),
+        (0, 55),
+        (0, 0),
+        (1, 151),
+        (0, 43),
+        (3, 26),
+        (1, 252),
+        (1, 45),
+        (3, 234),
+        (1, 183),
+        (0, 0),
+        (0, 0),
+        (0, 37),
+        (0, 6),
+        (0, 16),
+        (0, 32),
+        (7, 95),
+        (0, 3),
+        (1, 45),
+        (2, 19),
+        (0, 3),
+        (0, 108),
+        (18, 300),
+        (0, 70),
+        (36, 36),
+        (0, 122),
+        (3, 30),
+        (8, 273),
+        (0, 1),
+        (0, 40),
+        (2, 58),
+        (37, 26),
+        (7, 165),
+        (10, 161),
+        (20, 174),
+    ],
+    entries: &[
+        ("erg", Atom::Erg),
+        ("sph", Atom::Spere),
+        ("B[kW]", Atom::BelKilowatt),
+        ("[ka'U]", Atom::KingArmstrongUnit),
+        ("[fth_br]", Atom::FathomBritish),
+        ("mo_s", Atom::SynodalMonth),
+        ("m[H2O]", Atom::MeterOfWaterColumn),
+        ("REM", Atom::RadiationEquivalentMan),
+        ("[PRU]", Atom::PeripheralVascularResistanceUnit),
+        ("%", Atom::Percent),
+        ("[hd_i]", Atom::HandInternational),
+        ("[dpt_us]", Atom::DryPintUS),
+        ("[stone_av]", Atom::StoneAvoirdupois),
+        ("kat", Atom::Katal),
+        ("Cel", Atom::DegreeCelsius),
+        ("[tsp_us]", Atom::TeaspoonUS),
+        ("C", Atom::Coulomb),
+        ("[pc_br]", Atom::PaceBritish),
+        ("cd", Atom::Candela),
+        ("Oe", Atom::Oersted),
+        ("[ft_i]", Atom::FootInternational),
+        ("mo_j", Atom::MeanJulianMonth),
+        ("[m_e]", Atom::ElectronMass),
+        ("[CCID_50]", Atom::CellCultureInfectiousDose),
+        ("[oz_av]", Atom::OunceAvoirdupois),
+        ("B[V]", Atom::BelVolt),
+        ("[twp]", Atom::Township),
+        ("cal_th", Atom::ThermochemicalCalorie),
+        ("[in_us]", Atom::InchUS),
+        ("[car_Au]", Atom::CaratOfGoldAlloys),
+        ("[cyd_i]", Atom::CubicYardInternational),
+        ("[cr_i]", Atom::CordInternational),
+        ("Pa", Atom::Pascal),
+        ("[bdsk'U]", Atom::BodanskyUnit),
+        ("[cicero]", Atom::Cicero),
+        ("[EU]", Atom::EhrlichUnit),
+        ("[IR]", Atom::IndexOfReactivity),
+        ("Mx", Atom::Maxwell),
+        ("Gy", Atom::Gray),
+        ("By", Atom::Byte),
+        ("[IU]", Atom::InternationalUnitSecondary),
+        ("K", Atom::Kelvin),
+        ("B[mV]", Atom::BelMillivolt),
+        ("ph", Atom::Phot),
+        ("[Ch]", Atom::Charriere),
+        ("'", Atom::MinuteAngle),
+        ("[FFU]", Atom::FocusFormingUnits),
+        (
+            "[kp_Q]",
+            Atom::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries,
+        ),
+        ("[S]", Atom::SvedbergUnit),
+        ("[cft_i]", Atom::CubicFootInternational),
+        ("10*", Atom::TheNumberTenForArbitraryPowersStar),
+        ("[sin_i]", Atom::SquareInchInternational),
+        ("[GPL'U]", Atom::GplUnit),
+        ("[oz_ap]", Atom::OunceApothecaries),
+        ("d", Atom::Day),
+        ("[min_br]", Atom::MinimBritish),
+        ("[mi_br]", Atom::MileBritish),
+        ("[PFU]", Atom::PlaqueFormingUnits),
+        ("[ligne]", Atom::Ligne),
+        ("cal", Atom::Calorie),
+        ("Np", Atom::Neper),
+        ("[bu_br]", Atom::BushelBritish),
+        ("[in_br]", Atom::InchBritish),
+        (
+            "[hp_X]",
+            Atom::HomeopathicPotencyOfDecimalHahnemannianSeries,
+        ),
+        ("[dr_av]", Atom::DramAvoirdupois),
+        ("[anti'Xa'U]", Atom::AntiFactorXaUnit),
+        ("[beth'U]", Atom::BethesdaUnit),
+        ("[bf_i]", Atom::BoardFootInternational),
+        ("[mi_i]", Atom::MileInternational),
+        ("[fth_i]", Atom::FathomInternational),
+        ("[m_p]", Atom::ProtonMass),
+        ("[Btu_39]", Atom::BritishThermalUnitAt39F),
+        ("[kn_i]", Atom::KnotInternational),
+        ("[Btu_59]", Atom::BritishThermalUnitAt59F),
+        ("[yd_us]", Atom::YardUS),
+        ("[didot]", Atom::Didot),
+        ("[e]", Atom::ElementaryCharge),
+        ("dyn", Atom::Dyne),
+        ("mho", Atom::Mho),
+        ("l", Atom::Liter),
+        ("cal_[15]", Atom::CalorieAt15C),
+        ("[AU]", Atom::AllergenUnit),
+        ("[mu_0]", Atom::PermeabilityOfVacuum),
+        ("[in_i]", Atom::InchInternational),
+        ("h", Atom::Hour),
+        ("[degR]", Atom::DegreeRankine),
+        ("[mesh_i]", Atom::MeshInternational),
+        ("[gr]", Atom::Grain),
+        ("[Amb'a'1'U]", Atom::AllergenUnitForAmbrosiaArtemisiifolia),
+        ("[HPF]", Atom::HighPowerField),
+        ("[Lf]", Atom::LimitOfFlocculation),
+        ("[CFU]", Atom::ColonyFormingUnits),
+        ("[pk_br]", Atom::PeckBritish),
+        (
+            "[hp'_Q]",
+            Atom::HomeopathicPotencyOfQuintamillesimalSeriesRetired,
+        ),
+        ("m[Hg]", Atom::MeterOfMercuryColumn),
+        ("[tbs_us]", Atom::TablespoonUS),
+        ("[EID_50]", Atom::EmbryoInfectiousDose),
+        ("deg", Atom::Degree),
+        ("[bu_us]", Atom::BushelUS),
+        ("[ELU]", Atom::ElisaUnit),
+        ("[pca]", Atom::Pica),
+        ("[acr_us]", Atom::AcreUS),
+        ("Wb", Atom::Weber),
+        ("a", Atom::Year),
+        ("[psi]", Atom::PoundPerSquareInch),
+        ("[in_i'H2O]", Atom::InchOfWaterColumn),
+        (
+            "[m/s2/Hz^(1/2)]",
+            Atom::MeterPerSquareSecondsPerSquareRootOfHertz,
+        ),
+        ("[nmi_br]", Atom::NauticalMileBritish),
+        ("[fur_us]", Atom::FurlongUS),
+        ("gf", Atom::GramForce),
+        ("[rch_us]", Atom::RamdensChainUS),
+        (
+            "[hp_Q]",
+            Atom::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries,
+        ),
+        ("[foz_m]", Atom::MetricFluidOunce),
+        ("[sc_ap]", Atom::ScrupleApothecaries),
+        ("[cup_us]", Atom::CupUS),
+        ("[smoot]", Atom::Smoot),
+        ("[ppm]", Atom::PartsPerMillion),
+        ("[tbs_m]", Atom::MetricTablespoon),
+        ("[h]", Atom::PlanckConstant),
+        ("lm", Atom::Lumen),
+        ("[ch_br]", Atom::GuntersChainBritish),
+        ("10^", Atom::TheNumberTenForArbitraryPowersCaret),
+        ("RAD", Atom::RadiationAbsorbedDose),
+        ("rad", Atom::Radian),
+        ("[foz_us]", Atom::FluidOunceUS),
+        ("mol", Atom::Mole),
+        ("B", Atom::Bel),
+        ("min", Atom::Minute),
+        ("[pwt_tr]", Atom::PennyweightTroy),
+        ("bit", Atom::Bit),
+        ("[ston_av]", Atom::ShortTonAvoirdupois),
+        ("Gal", Atom::Gal),
+        ("[pt_us]", Atom::PintUS),
+        ("[ppth]", Atom::PartsPerThousand),
+        ("[ly]", Atom::LightYear),
+        ("W", Atom::Watt),
+        ("[degF]", Atom::DegreeFahrenheit),
+        ("G", Atom::Gauss),
+        ("[pk_us]", Atom::PeckUS),
+        ("[Btu_60]", Atom::BritishThermalUnitAt60F),
+        ("[hp'_M]", Atom::HomeopathicPotencyOfMillesimalSeriesRetired),
+        ("Bi", Atom::Biot),
+        ("[pi]", Atom::TheNumberPi),
+        ("[TCID_50]", Atom::TissueCultureInfectiousDose),
+        ("[qt_us]", Atom::QuartUS),
+        ("St", Atom::Stokes),
+        ("atm", Atom::StandardAtmosphere),
+        ("[mi_us]", Atom::MileUS),
+        ("[car_m]", Atom::MetricCarat),
+        ("Ky", Atom::Kayser),
+        ("a_g", Atom::MeanGregorianYear),
+        ("[smgy'U]", Atom::SomogyiUnit),
+        ("gon", Atom::Gon),
+        ("AU", Atom::AstronomicUnit),
+        ("Bd", Atom::Baud),
+        ("[ft_us]", Atom::FootUS),
+        ("cal_[20]", Atom::CalorieAt20C),
+        ("bit_s", Atom::BitLogarithmusDualis),
+        ("[sft_i]", Atom::SquareFootInternational),
+        ("[FEU]", Atom::FibrinogenEquivalentUnit),
+        ("[in_i'Hg]", Atom::InchOfMercuryColumn),
+        ("[APL'U]", Atom::AplUnit),
+        ("[cml_i]", Atom::CircularMilInternational),
+        ("Gb", Atom::Gilbert),
+        ("[PNU]", Atom::ProteinNitrogenUnit),
+        ("[LPF]", Atom::LowPowerField),
+        ("[lne]", Atom::Line),
+        ("H", Atom::Henry),
+        ("S", Atom::Siemens),
+        ("[BAU]", Atom::BioequivalentAllergenUnit),
+        ("a_t", Atom::TropicalYear),
+        ("N", Atom::Newton),
+        ("[cup_m]", Atom::MetricCup),
+        ("[fdr_br]", Atom::FluidDramBritish),
+        ("[rlk_us]", Atom::LinkForRamdensChainUS),
+        ("[pied]", Atom::Pied),
+        ("[yd_br]", Atom::YardBritish),
+        ("[dqt_us]", Atom::DryQuartUS),
+        ("tex", Atom::Tex),
+        ("F", Atom::Farad),
+        ("[tsp_m]", Atom::MetricTeaspoon),
+        ("pc", Atom::Parsec),
+        ("[pnt]", Atom::Point),
+        (
+            "[kp_C]",
+            Atom::HomeopathicPotencyOfCentesimalKorsakovianSeries,
+        ),
+        ("%[slope]", Atom::PercentOfSlope),
+        ("[G]", Atom::NewtonianConstantOfGravitation),
+        ("[HP]", Atom::Horsepower),
+        ("[fdr_us]", Atom::FluidDramUS),
+        ("Sv", Atom::Sievert),
+        ("[degRe]", Atom::DegreeReaumur),
+        ("[gil_us]", Atom::GillUS),
+        ("bar", Atom::Bar),
+        ("[lton_av]", Atom::LongTonAvoirdupois),
+        ("B[SPL]", Atom::BelSoundPressure),
+        ("[lb_tr]", Atom::PoundTroy),
+        ("att", Atom::TechnicalAtmosphere),
+        ("[sct]", Atom::Section),
+        ("[crd_us]", Atom::CordUS),
+        ("[MPL'U]", Atom::MplUnit),
+        ("B[uV]", Atom::BelMicrovolt),
+        ("[mil_us]", Atom::MilUS),
+        ("L", Atom::LiterSecondary),
+        ("circ", Atom::Circle),
+        ("[dr_ap]", Atom::DramApothecaries),
+        ("[yd_i]", Atom::YardInternational),
+        ("u", Atom::UnifiedAtomicMassUnit),
+        ("[kn_br]", Atom::KnotBritish),
+        ("[pouce]", Atom::Pouce),
+        ("[lcwt_av]", Atom::LongHunderdweightAvoirdupois),
+        ("[tb'U]", Atom::TuberculinUnit),
+        ("mo_g", Atom::MeanGregorianMonth),
+        ("[hp'_X]", Atom::HomeopathicPotencyOfDecimalSeriesRetired),
+        ("[Btu]", Atom::BritishThermalUnit),
+        ("[pptr]", Atom::PartsPerTrillion),
+        ("Lmb", Atom::Lambert),
+        ("[rd_br]", Atom::RodBritish),
+        ("[wood'U]", Atom::WoodUnit),
+        ("A", Atom::Ampere),
+        ("[g]", Atom::StandardAccelerationOfFreeFall),
+        ("[den]", Atom::Denier),
+        ("[dye'U]", Atom::DyeUnit),
+        ("Ci", Atom::Curie),
+        ("[lb_ap]", Atom::PoundApothecaries),
+        ("[nmi_i]", Atom::NauticalMileInternational),
+        ("[p'diop]", Atom::PrismDiopter),
+        ("[Btu_IT]", Atom::InternationalTableBritishThermalUnit),
+        ("[Cal]", Atom::NutritionLabelCalories),
+        ("[arb'U]", Atom::ArbitraryUnit),
+        ("s", Atom::Second),
+        ("[fth_us]", Atom::FathomUS),
+        ("[scwt_av]", Atom::ShortHundredweightAvoirdupois),
+        ("[srd_us]", Atom::SquareRodUS),
+        ("[hp'_C]", Atom::HomeopathicPotencyOfCentesimalSeriesRetired),
+        ("[pnt_pr]", Atom::PrintersPoint),
+        ("[syd_i]", Atom::SquareYardInternational),
+        ("g%", Atom::GramPercent),
+        ("V", Atom::Volt),
+        ("ar", Atom::Are),
+        (
+            "[hp_M]",
+            Atom::HomeopathicPotencyOfMillesimalHahnemannianSeries,
+        ),
+        ("[lk_us]", Atom::LinkForGuntersChainUS),
+        ("[gil_br]", Atom::GillBritish),
+        ("[pt_br]", Atom::PintBritish),
+        ("[USP'U]", Atom::UnitedStatesPharmacopeiaUnit),
+        ("[oz_tr]", Atom::OunceTroy),
+        ("[k]", Atom::BoltzmannConstant),
+        ("Hz", Atom::Hertz),
+        ("a_j", Atom::MeanJulianYear),
+        ("cal_m", Atom::MeanCalorie),
+        ("sb", Atom::Stilb),
+        ("[c]", Atom::VelocityOfLight),
+        ("mo", Atom::Month),
+        ("b", Atom::Barn),
+        ("[pca_pr]", Atom::PrintersPica),
+        ("[lb_av]", Atom::PoundAvoirdupois),
+        ("[gal_wi]", Atom::HistoricalWinchesterGallon),
+        ("[lk_br]", Atom::LinkForGuntersChainBritish),
+        ("[qt_br]", Atom::QuartBritish),
+        ("Ohm", Atom::Ohm),
+        ("[MET]", Atom::MetabolicEquivalent),
+        ("st", Atom::Stere),
+        ("[oz_m]", Atom::MetricOunce),
+        ("[smi_us]", Atom::SquareMileUS),
+        ("P", Atom::Poise),
+        ("[rd_us]", Atom::RodUS),
+        (
+            "[hp_C]",
+            Atom::HomeopathicPotencyOfCentesimalHahnemannianSeries,
+        ),
+        ("[Btu_th]", Atom::ThermochemicalBritishThermalUnit),
+        ("R", Atom::Roentgen),
+        ("[gal_us]", Atom::QueenAnnesWineGallonUS),
+        ("cal_IT", Atom::InternationalTableCalorie),
+        ("[hnsf'U]", Atom::HounsfieldUnit),
+        ("osm", Atom::Osmole),
+        ("[acr_br]", Atom::AcreBritish),
+        ("[knk'U]", Atom::KunkelUnit),
+        ("[mil_i]", Atom::MilInternational),
+        ("J", Atom::Joule),
+        ("T", Atom::Tesla),
+        ("wk", Atom::Week),
+        ("t", Atom::Tonne),
+        ("Ao", Atom::Angstrom),
+        ("Bq", Atom::Becquerel),
+        ("[foz_br]", Atom::FluidOunceBritish),
+        ("[ft_br]", Atom::FootBritish),
+        ("m", Atom::Meter),
+        ("''", Atom::SecondAngle),
+        ("eV", Atom::Electronvolt),
+        ("[min_us]", Atom::MinimUS),
+        ("[kp_X]", Atom::HomeopathicPotencyOfDecimalKorsakovianSeries),
+        ("[D'ag'U]", Atom::DAntigenUnit),
+        ("U", Atom::Unit),
+        ("[cin_i]", Atom::CubicInchInternational),
+        ("[Btu_m]", Atom::MeanBritishThermalUnit),
+        ("eq", Atom::Equivalents),
+        ("sr", Atom::Steradian),
+        ("[todd'U]", Atom::ToddUnit),
+        ("[pH]", Atom::PH),
+        ("B[10.nV]", Atom::Bel10Nanovolt),
+        ("[diop]", Atom::Diopter),
+        ("g", Atom::Gram),
+        (
+            "[kp_M]",
+            Atom::HomeopathicPotencyOfMillesimalKorsakovianSeries,
+        ),
+        ("[drp]", Atom::Drop),
+        ("[gal_br]", Atom::GallonBritish),
+        ("[ppb]", Atom::PartsPerBillion),
+        ("lx", Atom::Lux),
+        ("[eps_0]", Atom::PermittivityOfVacuum),
+        ("[ch_us]", Atom::GuntersChainUS),
+        ("[bbl_us]", Atom::BarrelUS),
+        ("B[W]", Atom::BelWatt),
+        ("[lbf_av]", Atom::PoundForceAvoirdupois),
+        ("[mclg'U]", Atom::MacLaganUnit),
+        ("[iU]", Atom::InternationalUnit),
+    ],
+};
+/// `Atom`s by their case-insensitive code (ex. `M`).
+pub(super) static SECONDARY_ATOMS: phf::Map<&'static str, Atom> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 2),
+        (1, 33),
+        (0, 2),
+        (0, 22),
+        (0, 169),
+        (0, 0),
+        (0, 10),
+        (0, 14),
+        (0, 30),
+        (0, 71),
+        (0, 17),
+        (0, 8),
+        (1, 252),
+        (0, 35),
+        (0, 0),
+        (1, 224),
+        (0, 59),
+        (0, 2),
+        (4, 289),
+        (0, 7),
+        (0, 5),
+        (0, 180),
+        (1, 53),
+        (3, 69),
+        (0, 0),
+        (0, 178),
+        (0, 10),
+        (0, 141),
+        (0, 39),
+        (0, 51),
+        (0, 255),
+        (0, 0),
+        (0, 40),
+        (11, 300),
+        (12, 193),
+        (8, 237),
+        (0, 0),
+        (0, 1),
+        (0, 200),
+        (0, 9),
+        (0, 232),
+        (0, 35),
+        (0, 21),
+        (4, 234),
+        (0, 77),
+        (1, 15),
+        (6, 23),
+        (0, 13),
+        (0, 55),
+        (0, 0),
+        (0, 3),
+        (0, 44),
+        (10, 265),
+        (0, 6),
+        (0, 177),
+        (1, 161),
+        (0, 57),
+        (0, 34),
+        (0, 7),
+        (16, 116),
+        (0, 0),
+        (1, 262),
+    ],
+    entries: &[
+        ("F", Atom::Farad),
+        ("ROE", Atom::Roentgen),
+        ("''", Atom::SecondAngle),
+        ("[TWP]", Atom::Township),
+        ("ATT", Atom::TechnicalAtmosphere),
+        ("K", Atom::Kelvin),
+        ("PRS", Atom::Parsec),
+        ("[RD_BR]", Atom::RodBritish),
+        ("[YD_US]", Atom::YardUS),
+        ("[CAR_AU]", Atom::CaratOfGoldAlloys),
+        ("HZ", Atom::Hertz),
+        ("[KNK'U]", Atom::KunkelUnit),
+        ("[CAL]", Atom::NutritionLabelCalories),
+        ("%", Atom::Percent),
+        ("[MU_0]", Atom::PermeabilityOfVacuum),
+        ("[BTU_IT]", Atom::InternationalTableBritishThermalUnit),
+        ("[GIL_BR]", Atom::GillBritish),
+        ("[CAR_M]", Atom::MetricCarat),
+        ("GB", Atom::Gilbert),
+        ("RAD", Atom::Radian),
+        ("[S]", Atom::SvedbergUnit),
+        ("[SIN_I]", Atom::SquareInchInternational),
+        ("[FOZ_BR]", Atom::FluidOunceBritish),
+        ("[FTH_US]", Atom::FathomUS),
+        ("[ACR_US]", Atom::AcreUS),
+        ("[CICERO]", Atom::Cicero),
+        ("[DQT_US]", Atom::DryQuartUS),
+        ("[PFU]", Atom::PlaqueFormingUnits),
+        (
+            "[HP_Q]",
+            Atom::HomeopathicPotencyOfQuintamillesimalHahnemannianSeries,
+        ),
+        ("[KN_BR]", Atom::KnotBritish),
+        ("LX", Atom::Lux),
+        ("[MIL_I]", Atom::MilInternational),
+        ("[PNT]", Atom::Point),
+        ("[CRD_US]", Atom::CordUS),
+        ("T", Atom::Tesla),
+        ("[GIL_US]", Atom::GillUS),
+        ("[TBS_US]", Atom::TablespoonUS),
+        ("[ELU]", Atom::ElisaUnit),
+        ("[FEU]", Atom::FibrinogenEquivalentUnit),
+        ("ST", Atom::Stokes),
+        ("10^", Atom::TheNumberTenForArbitraryPowersCaret),
+        ("[LBF_AV]", Atom::PoundForceAvoirdupois),
+        ("[PK_US]", Atom::PeckUS),
+        ("[PRU]", Atom::PeripheralVascularResistanceUnit),
+        ("[DPT_US]", Atom::DryPintUS),
+        ("[PPTR]", Atom::PartsPerTrillion),
+        ("10*", Atom::TheNumberTenForArbitraryPowersStar),
+        ("[CFU]", Atom::ColonyFormingUnits),
+        ("[CCID_50]", Atom::CellCultureInfectiousDose),
+        ("[BTU_60]", Atom::BritishThermalUnitAt60F),
+        ("[STONE_AV]", Atom::StoneAvoirdupois),
+        ("[NMI_BR]", Atom::NauticalMileBritish),
+        ("MHO", Atom::Mho),
+        ("[LIGNE]", Atom::Ligne),
+        ("PHT", Atom::Phot),
+        ("[BETH'U]", Atom::BethesdaUnit),
+        ("DEG", Atom::Degree),
+        ("[DIOP]", Atom::Diopter),
+        ("CAL", Atom::Calorie),
+        ("[BU_BR]", Atom::BushelBritish),
+        ("DYN", Atom::Dyne),
+        ("[BF_I]", Atom::BoardFootInternational),
+        ("[CUP_US]", Atom::CupUS),
+        ("[TBS_M]", Atom::MetricTablespoon),
+        ("[FDR_US]", Atom::FluidDramUS),
+        ("[LY]", Atom::LightYear),
+        ("[GAL_US]", Atom::QueenAnnesWineGallonUS),
+        ("[DEN]", Atom::Denier),
+        ("[AMB'A'1'U]", Atom::AllergenUnitForAmbrosiaArtemisiifolia),
+        ("[SMI_US]", Atom::SquareMileUS),
+        ("[HD_I]", Atom::HandInternational),
+        ("BY", Atom::Byte),
+        ("[PT_US]", Atom::PintUS),
+        ("AMU", Atom::UnifiedAtomicMassUnit),
+        ("[BTU_39]", Atom::BritishThermalUnitAt39F),
+        ("AO", Atom::Angstrom),
+        ("S", Atom::Second),
+        (
+            "[HP_C]",
+            Atom::HomeopathicPotencyOfCentesimalHahnemannianSeries,
+        ),
+        ("BIT_S", Atom::BitLogarithmusDualis),
+        ("[YD_BR]", Atom::YardBritish),
+        ("[RCH_US]", Atom::RamdensChainUS),
+        ("[CH_US]", Atom::GuntersChainUS),
+        ("[MIN_BR]", Atom::MinimBritish),
+        ("[HPF]", Atom::HighPowerField),
+        ("[PI]", Atom::TheNumberPi),
+        ("[PCA]", Atom::Pica),
+        ("[YD_I]", Atom::YardInternational),
+        ("[FT_I]", Atom::FootInternational),
+        ("[BTU]", Atom::BritishThermalUnit),
+        ("[NMI_I]", Atom::NauticalMileInternational),
+        ("U", Atom::Unit),
+        ("LMB", Atom::Lambert),
+        ("M[HG]", Atom::MeterOfMercuryColumn),
+        ("[SMOOT]", Atom::Smoot),
+        ("B[10.NV]", Atom::Bel10Nanovolt),
+        ("[CH_BR]", Atom::GuntersChainBritish),
+        ("[LK_BR]", Atom::LinkForGuntersChainBritish),
+        ("HR", Atom::Hour),
+        ("[SCWT_AV]", Atom::ShortHundredweightAvoirdupois),
+        ("[SRD_US]", Atom::SquareRodUS),
+        ("[KA'U]", Atom::KingArmstrongUnit),
+        ("GON", Atom::Gon),
+        (
+            "[M/S2/HZ^(1/2)]",
+            Atom::MeterPerSquareSecondsPerSquareRootOfHertz,
+        ),
+        ("SR", Atom::Steradian),
+        ("[MPL'U]", Atom::MplUnit),
+        ("[MCLG'U]", Atom::MacLaganUnit),
+        ("[DIDOT]", Atom::Didot),
+        ("GS", Atom::Gauss),
+        ("[IN_I]", Atom::InchInternational),
+        ("NEP", Atom::Neper),
+        ("CAL_TH", Atom::ThermochemicalCalorie),
+        ("OHM", Atom::Ohm),
+        ("[FDR_BR]", Atom::FluidDramBritish),
+        ("[LB_TR]", Atom::PoundTroy),
+        ("ANN", Atom::Year),
+        ("PAL", Atom::Pascal),
+        ("[RAD]", Atom::RadiationAbsorbedDose),
+        ("[BBL_US]", Atom::BarrelUS),
+        ("W", Atom::Watt),
+        ("[CUP_M]", Atom::MetricCup),
+        ("[CH]", Atom::Charriere),
+        ("[QT_US]", Atom::QuartUS),
+        ("[LTON_AV]", Atom::LongTonAvoirdupois),
+        ("GL", Atom::Gal),
+        ("[PPB]", Atom::PartsPerBillion),
+        ("[MI_BR]", Atom::MileBritish),
+        ("[LB_AP]", Atom::PoundApothecaries),
+        ("[HP]", Atom::Horsepower),
+        (
+            "[KP_M]",
+            Atom::HomeopathicPotencyOfMillesimalKorsakovianSeries,
+        ),
+        ("[DYE'U]", Atom::DyeUnit),
+        ("[CML_I]", Atom::CircularMilInternational),
+        ("[OZ_AP]", Atom::OunceApothecaries),
+        ("B[KW]", Atom::BelKilowatt),
+        ("KAT", Atom::Katal),
+        (
+            "[KP_C]",
+            Atom::HomeopathicPotencyOfCentesimalKorsakovianSeries,
+        ),
+        ("[CFT_I]", Atom::CubicFootInternational),
+        ("ANN_J", Atom::MeanJulianYear),
+        ("[TODD'U]", Atom::ToddUnit),
+        ("[SCT]", Atom::Section),
+        ("SV", Atom::Sievert),
+        ("CAL_IT", Atom::InternationalTableCalorie),
+        ("G%", Atom::GramPercent),
+        ("[MIL_US]", Atom::MilUS),
+        ("[MI_I]", Atom::MileInternational),
+        ("[BTU_M]", Atom::MeanBritishThermalUnit),
+        ("TEX", Atom::Tex),
+        ("[IN_I'H2O]", Atom::InchOfWaterColumn),
+        ("[KN_I]", Atom::KnotInternational),
+        ("EV", Atom::Electronvolt),
+        ("[IN_I'HG]", Atom::InchOfMercuryColumn),
+        ("[DR_AP]", Atom::DramApothecaries),
+        ("V", Atom::Volt),
+        ("MX", Atom::Maxwell),
+        ("[SFT_I]", Atom::SquareFootInternational),
+        ("[MI_US]", Atom::MileUS),
+        ("G", Atom::Gram),
+        ("MOL", Atom::Mole),
+        ("[HP'_X]", Atom::HomeopathicPotencyOfDecimalSeriesRetired),
+        ("[WOOD'U]", Atom::WoodUnit),
+        ("[PCA_PR]", Atom::PrintersPica),
+        ("EQ", Atom::Equivalents),
+        ("[ACR_BR]", Atom::AcreBritish),
+        ("[ANTI'XA'U]", Atom::AntiFactorXaUnit),
+        ("[CR_I]", Atom::CordInternational),
+        ("[PPM]", Atom::PartsPerMillion),
+        ("[D'AG'U]", Atom::DAntigenUnit),
+        ("'", Atom::MinuteAngle),
+        ("[KP_X]", Atom::HomeopathicPotencyOfDecimalKorsakovianSeries),
+        ("BQ", Atom::Becquerel),
+        ("[DR_AV]", Atom::DramAvoirdupois),
+        (
+            "[HP_M]",
+            Atom::HomeopathicPotencyOfMillesimalHahnemannianSeries,
+        ),
+        ("[IR]", Atom::IndexOfReactivity),
+        ("STR", Atom::Stere),
+        ("MIN", Atom::Minute),
+        ("[LPF]", Atom::LowPowerField),
+        ("BAR", Atom::Bar),
+        ("B[MV]", Atom::BelMillivolt),
+        ("[C]", Atom::VelocityOfLight),
+        ("CIRC", Atom::Circle),
+        ("[PWT_TR]", Atom::PennyweightTroy),
+        ("[K]", Atom::BoltzmannConstant),
+        ("[BTU_59]", Atom::BritishThermalUnitAt59F),
+        ("A", Atom::Ampere),
+        ("CEL", Atom::DegreeCelsius),
+        ("[FOZ_US]", Atom::FluidOunceUS),
+        ("[OZ_AV]", Atom::OunceAvoirdupois),
+        ("M[H2O]", Atom::MeterOfWaterColumn),
+        ("[IN_US]", Atom::InchUS),
+        ("WK", Atom::Week),
+        ("WB", Atom::Weber),
+        ("[FOZ_M]", Atom::MetricFluidOunce),
+        ("[STON_AV]", Atom::ShortTonAvoirdupois),
+        ("[FTH_BR]", Atom::FathomBritish),
+        ("[PSI]", Atom::PoundPerSquareInch),
+        ("MO_J", Atom::MeanJulianMonth),
+        ("[HNSF'U]", Atom::HounsfieldUnit),
+        ("[LCWT_AV]", Atom::LongHunderdweightAvoirdupois),
+        ("[EU]", Atom::EhrlichUnit),
+        ("[USP'U]", Atom::UnitedStatesPharmacopeiaUnit),
+        ("GY", Atom::Gray),
+        ("BD", Atom::Baud),
+        ("[RLK_US]", Atom::LinkForRamdensChainUS),
+        ("[EPS_0]", Atom::PermittivityOfVacuum),
+        ("[EID_50]", Atom::EmbryoInfectiousDose),
+        ("[GAL_BR]", Atom::GallonBritish),
+        ("OSM", Atom::Osmole),
+        ("[PIED]", Atom::Pied),
+        ("[DEGR]", Atom::DegreeRankine),
+        ("[CYD_I]", Atom::CubicYardInternational),
+        ("OE", Atom::Oersted),
+        ("B[W]", Atom::BelWatt),
+        ("[LF]", Atom::LimitOfFlocculation),
+        ("SPH", Atom::Spere),
+        ("SIE", Atom::Siemens),
+        ("[LK_US]", Atom::LinkForGuntersChainUS),
+        ("MO_G", Atom::MeanGregorianMonth),
+        ("ANN_G", Atom::MeanGregorianYear),
+        ("[HP'_M]", Atom::HomeopathicPotencyOfMillesimalSeriesRetired),
+        ("BIT", Atom::Bit),
+        ("[GC]", Atom::NewtonianConstantOfGravitation),
+        ("CI", Atom::Curie),
+        ("B[SPL]", Atom::BelSoundPressure),
+        ("[MET]", Atom::MetabolicEquivalent),
+        ("[SYD_I]", Atom::SquareYardInternational),
+        ("GF", Atom::GramForce),
+        ("[FTH_I]", Atom::FathomInternational),
+        ("[OZ_M]", Atom::MetricOunce),
+        ("[FT_US]", Atom::FootUS),
+        ("[PNT_PR]", Atom::PrintersPoint),
+        ("ERG", Atom::Erg),
+        ("LM", Atom::Lumen),
+        ("[SMGY'U]", Atom::SomogyiUnit),
+        ("[LNE]", Atom::Line),
+        ("[DRP]", Atom::Drop),
+        ("B[V]", Atom::BelVolt),
+        ("TNE", Atom::Tonne),
+        ("[P'DIOP]", Atom::PrismDiopter),
+        ("[FFU]", Atom::FocusFormingUnits),
+        ("[TB'U]", Atom::TuberculinUnit),
+        ("ASU", Atom::AstronomicUnit),
+        ("KY", Atom::Kayser),
+        ("[POUCE]", Atom::Pouce),
+        ("[DEGRE]", Atom::DegreeReaumur),
+        ("B", Atom::Bel),
+        ("[M_P]", Atom::ProtonMass),
+        ("BRN", Atom::Barn),
+        ("N", Atom::Newton),
+        ("[BU_US]", Atom::BushelUS),
+        ("[G]", Atom::StandardAccelerationOfFreeFall),
+        ("[M_E]", Atom::ElectronMass),
+        ("BI", Atom::Biot),
+        (
+            "[HP'_Q]",
+            Atom::HomeopathicPotencyOfQuintamillesimalSeriesRetired,
+        ),
+        ("[AU]", Atom::AllergenUnit),
+        ("[RD_US]", Atom::RodUS),
+        ("[QT_BR]", Atom::QuartBritish),
+        ("[FT_BR]", Atom::FootBritish),
+        ("[PC_BR]", Atom::PaceBritish),
+        ("J", Atom::Joule),
+        ("[GPL'U]", Atom::GplUnit),
+        ("ATM", Atom::StandardAtmosphere),
+        ("[REM]", Atom::RadiationEquivalentMan),
+        ("CAL_[15]", Atom::CalorieAt15C),
+        ("[DEGF]", Atom::DegreeFahrenheit),
+        ("[GAL_WI]", Atom::HistoricalWinchesterGallon),
+        ("ANN_T", Atom::TropicalYear),
+        ("[TCID_50]", Atom::TissueCultureInfectiousDose),
+        ("[FUR_US]", Atom::FurlongUS),
+        ("[H]", Atom::PlanckConstant),
+        ("M", Atom::Meter),
+        ("[PT_BR]", Atom::PintBritish),
+        ("P", Atom::Poise),
+        ("MO", Atom::Month),
+        ("D", Atom::Day),
+        ("[PNU]", Atom::ProteinNitrogenUnit),
+        ("[CIN_I]", Atom::CubicInchInternational),
+        ("C", Atom::Coulomb),
+        ("SB", Atom::Stilb),
+        ("CAL_[20]", Atom::CalorieAt20C),
+        ("[SC_AP]", Atom::ScrupleApothecaries),
+        ("[BDSK'U]", Atom::BodanskyUnit),
+        (
+            "[HP_X]",
+            Atom::HomeopathicPotencyOfDecimalHahnemannianSeries,
+        ),
+        ("[BTU_TH]", Atom::ThermochemicalBritishThermalUnit),
+        (
+            "[KP_Q]",
+            Atom::HomeopathicPotencyOfQuintamillesimalKorsakovianSeries,
+        ),
+        ("L", Atom::Liter),
+        ("[LB_AV]", Atom::PoundAvoirdupois),
+        ("CD", Atom::Candela),
+        ("%[SLOPE]", Atom::PercentOfSlope),
+        ("B[UV]", Atom::BelMicrovolt),
+        ("CAL_M", Atom::MeanCalorie),
+        ("[APL'U]", Atom::AplUnit),
+        ("AR", Atom::Are),
+        ("[ARB'U]", Atom::ArbitraryUnit),
+        ("[BAU]", Atom::BioequivalentAllergenUnit),
+        ("[HP'_C]", Atom::HomeopathicPotencyOfCentesimalSeriesRetired),
+        ("MO_S", Atom::SynodalMonth),
+        ("H", Atom::Henry),
+        ("[PPTH]", Atom::PartsPerThousand),
+        ("[IN_BR]", Atom::InchBritish),
+        ("[PH]", Atom::PH),
+        ("[MIN_US]", Atom::MinimUS),
+        ("[PK_BR]", Atom::PeckBritish),
+        ("[E]", Atom::ElementaryCharge),
+        ("[TSP_US]", Atom::TeaspoonUS),
+        ("[GR]", Atom::Grain),
+        ("[OZ_TR]", Atom::OunceTroy),
+        ("[IU]", Atom::InternationalUnit),
+        ("[TSP_M]", Atom::MetricTeaspoon),
+        ("[MESH_I]", Atom::MeshInternational),
+    ],
+};
+/// `Prefix`es by their case-sensitive code (ex. `k`).
+pub(super) static PRIMARY_PREFIXES: phf::Map<&'static str, Prefix> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[(0, 3), (22, 4), (7, 7), (16, 0), (4, 3)],
+    entries: &[
+        ("n", Prefix::Nano),
+        ("z", Prefix::Zepto),
+        ("p", Prefix::Pico),
+        ("k", Prefix::Kilo),
+        ("u", Prefix::Micro),
+        ("c", Prefix::Centi),
+        ("h", Prefix::Hecto),
+        ("Ti", Prefix::Tebi),
+        ("d", Prefix::Deci),
+        ("P", Prefix::Peta),
+        ("Z", Prefix::Zetta),
+        ("Y", Prefix::Yotta),
+        ("M", Prefix::Mega),
+        ("Mi", Prefix::Mebi),
+        ("da", Prefix::Deka),
+        ("E", Prefix::Exa),
+        ("a", Prefix::Atto),
+        ("G", Prefix::Giga),
+        ("y", Prefix::Yocto),
+        ("Gi", Prefix::Gibi),
+        ("f", Prefix::Femto),
+        ("m", Prefix::Milli),
+        ("Ki", Prefix::Kibi),
+        ("T", Prefix::Tera),
+    ],
+};
+/// `Prefix`es by their case-insensitive code (ex. `K`).
+pub(super) static SECONDARY_PREFIXES: phf::Map<&'static str, Prefix> = ::phf::Map {
+    key: 42584678569483946,
+    disps: &[(11, 0), (0, 6), (1, 6), (10, 19), (1, 7)],
+    entries: &[
+        ("P", Prefix::Pico),
+        ("YO", Prefix::Yocto),
+        ("F", Prefix::Femto),
+        ("M", Prefix::Milli),
+        ("PT", Prefix::Peta),
+        ("EX", Prefix::Exa),
+        ("MIB", Prefix::Mebi),
+        ("KIB", Prefix::Kibi),
+        ("U", Prefix::Micro),
+        ("K", Prefix::Kilo),
+        ("A", Prefix::Atto),
+        ("GIB", Prefix::Gibi),
+        ("C", Prefix::Centi),
+        ("N", Prefix::Nano),
+        ("DA", Prefix::Deka),
+        ("ZA", Prefix::Zetta),
+        ("GA", Prefix::Giga),
+        ("H", Prefix::Hecto),
+        ("TR", Prefix::Tera),
+        ("YA", Prefix::Yotta),
+        ("MA", Prefix::Mega),
+        ("D", Prefix::Deci),
+        ("ZO", Prefix::Zepto),
+        ("TIB", Prefix::Tebi),
+    ],
+};
+/// The length of the longest prefix code, in bytes.
+pub(super) const MAX_PREFIX_LENGTH: usize = 3;
@@ -8,6 +8,7 @@ mod annotation;
 mod ast_term;
 mod basic_component;
 mod component;
+mod decimal_factor;
 mod digits;
 mod exponent;
 mod factor;
@@ -18,24 +19,30 @@ mod simple_unit;
 use pest::iterators::{Pair, Pairs};
 
 use crate::{
-    unit::parser::{Error, Visit},
+    unit::parser::{Error, ParseOptions, VisitWith},
     Term,
 };
 
+pub(in crate::unit::parser) use self::{
+    decimal_factor::{push_power_of_ten, DecimalFactor},
+    exponent::Exponent,
+    simple_unit::SimpleUnit,
+};
+
 use self::{
     annotatable::Annotatable, annotation::Annotation, ast_term::AstTerm,
-    basic_component::BasicComponent, component::Component, digits::Digits, exponent::Exponent,
-    finishable::Finishable, main_term::MainTerm, simple_unit::SimpleUnit,
+    basic_component::BasicComponent, component::Component, digits::Digits, finishable::Finishable,
+    main_term::MainTerm,
 };
 
 use super::term_parser::Rule;
 
 #[allow(clippy::large_enum_variant)]
 #[allow(clippy::result_large_err)]
-pub(crate) fn map(mut pairs: Pairs<'_, Rule>) -> Result<Vec<Term>, Error> {
-    fn visit_pairs(pair: Pair<'_, Rule>) -> Result<Vec<Term>, Error> {
-        let main_term = if pair.as_rule() == Rule::main_term {
-            MainTerm::visit(pair)?
+pub(crate) fn map(mut pairs: Pairs<'_, Rule>, options: ParseOptions) -> Result<Vec<Term>, Error> {
+    fn visit_pairs(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Vec<Term>, Error> {
+        let main_term = if matches!(pair.as_rule(), Rule::main_term | Rule::lenient_main_term) {
+            MainTerm::visit_with(pair, options)?
         } else {
             return Err(Error::UnknownUnitString(
                 pair.as_span().as_str().to_string(),
@@ -49,7 +56,7 @@ pub(crate) fn map(mut pairs: Pairs<'_, Rule>) -> Result<Vec<Term>, Error> {
     }
 
     match pairs.next() {
-        Some(pair) => Ok(visit_pairs(pair)?),
+        Some(pair) => Ok(visit_pairs(pair, options)?),
         None => Ok(vec![]),
     }
 }
@@ -67,7 +74,7 @@ mod tests {
             #[test]
             fn $test_name() {
                 let pairs = TermParser::parse(Rule::main_term, $input).unwrap();
-                let actual = map(pairs).unwrap();
+                let actual = map(pairs, ParseOptions::default()).unwrap();
                 let expected = vec![$($terms),+];
 
                 assert_eq!(actual, expected);
@@ -78,7 +85,7 @@ mod tests {
     #[test]
     fn validate_exponent() {
         let pairs = TermParser::parse(Rule::main_term, "m-3").unwrap();
-        let actual = map(pairs).unwrap();
+        let actual = map(pairs, ParseOptions::default()).unwrap();
 
         let expected_term = term!(Meter, exponent: -3);
         let expected = vec![expected_term];
@@ -86,7 +93,7 @@ mod tests {
         assert_eq!(actual, expected);
 
         let pairs = TermParser::parse(Rule::main_term, "km2/m-3").unwrap();
-        let actual = map(pairs).unwrap();
+        let actual = map(pairs, ParseOptions::default()).unwrap();
 
         let term1 = term!(Kilo, Meter, exponent: 2);
         let term2 = term!(Meter, exponent: 3);
@@ -255,7 +262,7 @@ mod tests {
     fn validate_custom_atom() {
         let pairs = TermParser::parse(Rule::main_term, "[meow]").unwrap();
 
-        let actual = map(pairs).unwrap();
+        let actual = map(pairs, ParseOptions::default()).unwrap();
         let acre_term = term!(AcreUS);
         let inch_term = term!(InchInternational);
         let acre_inverse_term = term!(AcreUS, exponent: -1);
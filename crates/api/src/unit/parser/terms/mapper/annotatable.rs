@@ -2,7 +2,7 @@ use pest::iterators::Pair;
 
 use crate::{
     term::UNITY,
-    unit::parser::{terms::term_parser::Rule as TermRule, Error, Visit},
+    unit::parser::{terms::term_parser::Rule as TermRule, Error, ParseOptions, Visit, VisitWith},
     Term,
 };
 
@@ -10,13 +10,13 @@ use super::{Exponent, SimpleUnit};
 
 pub(super) struct Annotatable(pub(super) Term);
 
-impl Visit<'_, TermRule> for Annotatable {
-    fn visit(pair: Pair<'_, TermRule>) -> Result<Self, Error> {
+impl VisitWith<'_, TermRule> for Annotatable {
+    fn visit_with(pair: Pair<'_, TermRule>, options: ParseOptions) -> Result<Self, Error> {
         let mut pairs = pair.into_inner();
 
         let mut simple_unit = match pairs.next() {
             Some(first) => match first.as_rule() {
-                TermRule::simple_unit => SimpleUnit::visit(first)?,
+                TermRule::simple_unit => SimpleUnit::visit_with(first, options)?,
                 _ => unreachable!(),
             },
             None => unreachable!(),
@@ -1,6 +1,6 @@
 use pest::iterators::Pair;
 
-use crate::unit::parser::{terms::term_parser::Rule, Error, Visit};
+use crate::unit::parser::{terms::term_parser::Rule, Error, ParseOptions, VisitWith};
 
 use super::{Component, Finishable, Term};
 
@@ -10,12 +10,12 @@ pub(super) struct AstTerm {
 }
 
 impl AstTerm {
-    pub(super) fn visit(pair: Pair<'_, Rule>) -> Result<Self, Error> {
+    pub(super) fn visit(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Self, Error> {
         let mut pairs = pair.into_inner();
 
         let component = match pairs.next() {
             Some(first) => match first.as_rule() {
-                Rule::component => Component::visit(first)?,
+                Rule::component | Rule::lenient_component => Component::visit_with(first, options)?,
                 _ => unreachable!(),
             },
             None => unreachable!(),
@@ -37,8 +37,8 @@ impl AstTerm {
 
         match pairs.next() {
             Some(third) => match third.as_rule() {
-                Rule::term => {
-                    let mut new_terms: Vec<Term> = Self::visit(third)?.finish();
+                Rule::term | Rule::lenient_term => {
+                    let mut new_terms: Vec<Term> = Self::visit(third, options)?.finish();
 
                     match op {
                         SecondToken::Dot => (),
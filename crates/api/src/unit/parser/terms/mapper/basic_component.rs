@@ -2,26 +2,31 @@ use pest::iterators::Pair;
 
 use crate::{
     term::{variants::FactorAnnotation, Factor, UNITY},
-    unit::parser::{terms::term_parser::Rule, Error, Visit},
+    unit::parser::{terms::term_parser::Rule, Error, ParseOptions, Visit, VisitWith},
     Annotation,
 };
 
-use super::{Annotatable, Annotation as MapperAnnotation, AstTerm, Finishable, Term};
+use super::{
+    decimal_factor::push_power_of_ten, Annotatable, Annotation as MapperAnnotation, AstTerm,
+    DecimalFactor, Finishable, Term,
+};
 
 pub(super) struct BasicComponent<'a> {
-    pub(super) factor: Option<Factor>,
+    pub(super) factor: Option<DecimalFactor>,
     pub(super) annotatable: Option<Annotatable>,
     pub(super) annotation: Option<&'a str>,
     pub(super) terms: Vec<Term>,
 }
 
-impl<'a> Visit<'a, Rule> for BasicComponent<'a> {
-    fn visit(pair: Pair<'a, Rule>) -> Result<Self, Error> {
+impl<'a> VisitWith<'a, Rule> for BasicComponent<'a> {
+    fn visit_with(pair: Pair<'a, Rule>, options: ParseOptions) -> Result<Self, Error> {
         let mut pairs = pair.into_inner();
 
         let first_token = match pairs.next() {
             Some(first) => match first.as_rule() {
-                Rule::annotatable => FirstToken::Annotatable(Annotatable::visit(first)?),
+                Rule::annotatable => {
+                    FirstToken::Annotatable(Annotatable::visit_with(first, options)?)
+                }
                 Rule::annotation => {
                     return Ok(Self {
                         factor: None,
@@ -30,13 +35,14 @@ impl<'a> Visit<'a, Rule> for BasicComponent<'a> {
                         terms: Vec::with_capacity(0),
                     })
                 }
-                Rule::factor => FirstToken::Factor(Factor::visit(first)?),
-                Rule::term => {
+                Rule::factor => FirstToken::Factor(DecimalFactor::from(Factor::visit(first)?)),
+                Rule::decimal_factor => FirstToken::Factor(DecimalFactor::visit(first)?),
+                Rule::term | Rule::lenient_term => {
                     return Ok(Self {
                         factor: None,
                         annotatable: None,
                         annotation: None,
-                        terms: AstTerm::visit(first)?.finish(),
+                        terms: AstTerm::visit(first, options)?.finish(),
                     })
                 }
                 _ => unreachable!(),
@@ -74,14 +80,17 @@ impl<'a> Visit<'a, Rule> for BasicComponent<'a> {
 
 enum FirstToken {
     Annotatable(Annotatable),
-    Factor(Factor),
+    Factor(DecimalFactor),
 }
 
 impl Finishable for BasicComponent<'_> {
     fn finish(self) -> Vec<Term> {
-        let mut terms: Vec<Term> = Vec::with_capacity(self.terms.len() + 1);
+        let mut terms: Vec<Term> = Vec::with_capacity(self.terms.len() + 2);
+        let (factor, power_of_ten) = self.factor.map_or((None, 0), |decimal_factor| {
+            (Some(decimal_factor.factor), decimal_factor.power_of_ten)
+        });
 
-        let self_term = match (self.factor, self.annotatable, self.annotation) {
+        let self_term = match (factor, self.annotatable, self.annotation) {
             (None, None, None) => UNITY,
             (None, None, Some(annotation)) => Term::Annotation(Annotation::from(annotation)),
             (None, Some(Annotatable(term)), None) => term,
@@ -104,6 +113,7 @@ impl Finishable for BasicComponent<'_> {
         };
 
         terms.push(self_term);
+        push_power_of_ten(&mut terms, power_of_ten);
         terms.extend_from_slice(&self.terms);
 
         terms
@@ -2,27 +2,28 @@ use pest::iterators::Pair;
 
 use crate::{
     term::Factor,
-    unit::parser::{terms::term_parser::Rule, Error, Visit},
+    unit::parser::{terms::term_parser::Rule, Error, ParseOptions, Visit, VisitWith},
 };
 
-use super::{BasicComponent, Finishable, Term};
+use super::{decimal_factor::push_power_of_ten, BasicComponent, DecimalFactor, Finishable, Term};
 
 pub(super) struct Component {
-    pub(super) factor: Option<Factor>,
+    pub(super) factor: Option<DecimalFactor>,
     pub(super) terms: Vec<Term>,
 }
 
-impl Visit<'_, Rule> for Component {
-    fn visit(pair: Pair<'_, Rule>) -> Result<Self, Error> {
+impl VisitWith<'_, Rule> for Component {
+    fn visit_with(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Self, Error> {
         let mut pairs = pair.into_inner();
 
         let factor = match pairs.next() {
             Some(first) => match first.as_rule() {
-                Rule::factor => Factor::visit(first)?,
-                Rule::basic_component => {
+                Rule::factor => DecimalFactor::from(Factor::visit(first)?),
+                Rule::decimal_factor => DecimalFactor::visit(first)?,
+                Rule::basic_component | Rule::lenient_basic_component => {
                     return Ok(Self {
                         factor: None,
-                        terms: BasicComponent::visit(first)?.finish(),
+                        terms: BasicComponent::visit_with(first, options)?.finish(),
                     });
                 }
                 _ => unreachable!(),
@@ -32,9 +33,9 @@ impl Visit<'_, Rule> for Component {
 
         match pairs.next() {
             Some(second) => match second.as_rule() {
-                Rule::basic_component => Ok(Self {
+                Rule::basic_component | Rule::lenient_basic_component => Ok(Self {
                     factor: Some(factor),
-                    terms: BasicComponent::visit(second)?.finish(),
+                    terms: BasicComponent::visit_with(second, options)?.finish(),
                 }),
                 _ => unreachable!(),
             },
@@ -45,12 +46,18 @@ impl Visit<'_, Rule> for Component {
 
 impl Finishable for Component {
     fn finish(mut self) -> Vec<Term> {
-        if let Some(factor) = self.factor {
+        if let Some(DecimalFactor {
+            factor,
+            power_of_ten,
+        }) = self.factor
+        {
             if factor != 1 {
                 if let Some(first_term) = self.terms.first_mut() {
                     let _ = first_term.set_factor(factor);
                 }
             }
+
+            push_power_of_ten(&mut self.terms, power_of_ten);
         }
 
         self.terms
@@ -0,0 +1,153 @@
+use pest::iterators::Pair;
+
+use crate::{
+    term::{Exponent, Factor},
+    unit::parser::{
+        terms::term_parser::Rule as TermRule, Diagnostic, DiagnosticKind, Error, Visit,
+    },
+    Atom, Term,
+};
+
+/// A factor that may be decimal or in scientific notation (ex. `0.5`, `1e6`), represented exactly
+/// as an integer `factor` times `10^power_of_ten`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(in crate::unit::parser) struct DecimalFactor {
+    pub(in crate::unit::parser) factor: Factor,
+    pub(in crate::unit::parser) power_of_ten: Exponent,
+}
+
+impl From<Factor> for DecimalFactor {
+    fn from(factor: Factor) -> Self {
+        Self {
+            factor,
+            power_of_ten: 0,
+        }
+    }
+}
+
+impl Visit<'_, TermRule> for DecimalFactor {
+    fn visit(pair: Pair<'_, TermRule>) -> Result<Self, Error> {
+        let span = pair.as_span();
+        let bad_factor = || {
+            Error::InvalidExpression(Diagnostic::from_span(
+                DiagnosticKind::BadFactor,
+                &span,
+                vec!["factor"],
+            ))
+        };
+
+        let (significand, exponent) = match span.as_str().split_once(['e', 'E']) {
+            Some((significand, exponent)) => (
+                significand,
+                exponent.parse::<Exponent>().map_err(|_| bad_factor())?,
+            ),
+            None => (span.as_str(), 0),
+        };
+
+        let (integer, fraction) = significand.split_once('.').unwrap_or((significand, ""));
+        let digits = format!("{integer}{fraction}");
+        let significant_digits = digits.trim_end_matches('0');
+
+        if significant_digits.trim_start_matches('0').is_empty() {
+            return Ok(Self::from(0));
+        }
+
+        let mut factor: Factor = significant_digits.parse().map_err(|_| bad_factor())?;
+        let mut power_of_ten = Exponent::try_from(digits.len() - significant_digits.len())
+            .ok()
+            .zip(Exponent::try_from(fraction.len()).ok())
+            .and_then(|(trailing_zeros, fraction_digits)| {
+                exponent
+                    .checked_add(trailing_zeros)?
+                    .checked_sub(fraction_digits)
+            })
+            .ok_or_else(bad_factor)?;
+
+        // Keep the factor as a plain integer, if it fits.
+        while power_of_ten > 0 {
+            match factor.checked_mul(10) {
+                Some(multiplied) => {
+                    factor = multiplied;
+                    power_of_ten -= 1;
+                }
+                None => break,
+            }
+        }
+
+        Ok(Self {
+            factor,
+            power_of_ten,
+        })
+    }
+}
+
+/// Appends the `10*` term for a `DecimalFactor`'s `power_of_ten` to `terms`, the terms of the
+/// component it belongs to. Since the factor is applied to the first term, which may have an
+/// exponent (ex. `0.5m2` is `(0.5m)2`), the power of ten is raised to that exponent too.
+///
+pub(in crate::unit::parser) fn push_power_of_ten(terms: &mut Vec<Term>, power_of_ten: Exponent) {
+    if power_of_ten == 0 {
+        return;
+    }
+
+    let first_exponent = terms.first().and_then(Term::exponent).unwrap_or(1);
+    let exponent = power_of_ten.saturating_mul(first_exponent);
+    let mut term = Term::Atom(Atom::TheNumberTenForArbitraryPowersStar);
+
+    if exponent != 1 {
+        let _ = term.set_exponent(exponent);
+    }
+
+    terms.push(term);
+}
+
+#[cfg(test)]
+mod tests {
+    use pest::Parser;
+
+    use crate::unit::parser::terms::term_parser::TermParser;
+
+    use super::*;
+
+    macro_rules! validate_decimal_factor {
+        ($test_name:ident, $input:expr, $factor:expr, $power_of_ten:expr) => {
+            #[test]
+            fn $test_name() {
+                let pair = TermParser::parse(TermRule::decimal_factor, $input)
+                    .unwrap()
+                    .next()
+                    .unwrap();
+
+                assert_eq!(
+                    DecimalFactor::visit(pair).unwrap(),
+                    DecimalFactor {
+                        factor: $factor,
+                        power_of_ten: $power_of_ten
+                    }
+                );
+            }
+        };
+    }
+
+    validate_decimal_factor!(validate_integer, "42", 42, 0);
+    validate_decimal_factor!(validate_decimal, "0.5", 5, -1);
+    validate_decimal_factor!(validate_decimal_trailing_zeros, "2.500", 25, -1);
+    validate_decimal_factor!(validate_decimal_leading_zeros, "0.0025", 25, -4);
+    validate_decimal_factor!(validate_scientific, "1e6", 1_000_000, 0);
+    validate_decimal_factor!(validate_scientific_uppercase, "2.5E3", 2500, 0);
+    validate_decimal_factor!(validate_scientific_negative, "2.5e-3", 25, -4);
+    validate_decimal_factor!(validate_scientific_large, "1e12", 1_000_000_000, 3);
+    validate_decimal_factor!(validate_large_integer, "50000000000", 500_000_000, 2);
+    validate_decimal_factor!(validate_zero, "0.000", 0, 0);
+
+    #[test]
+    fn validate_too_many_significant_digits() {
+        let pair = TermParser::parse(TermRule::decimal_factor, "12345678901")
+            .unwrap()
+            .next()
+            .unwrap();
+
+        assert!(DecimalFactor::visit(pair).is_err());
+    }
+}
@@ -1,11 +1,19 @@
 use pest::iterators::Pair;
 
-use crate::unit::parser::{terms::term_parser::Rule as TermRule, Error, Visit};
+use crate::unit::parser::{
+    terms::term_parser::Rule as TermRule, Diagnostic, DiagnosticKind, Error, Visit,
+};
 
 pub(super) type Digits = i32;
 
 impl Visit<'_, TermRule> for Digits {
     fn visit(pair: Pair<'_, TermRule>) -> Result<Self, Error> {
-        pair.as_span().as_str().parse::<Self>().map_err(Error::from)
+        pair.as_span().as_str().parse::<Self>().map_err(|_| {
+            Error::InvalidExpression(Diagnostic::from_span(
+                DiagnosticKind::BadExponent,
+                &pair.as_span(),
+                vec!["exponent"],
+            ))
+        })
     }
 }
@@ -7,7 +7,7 @@ use crate::{
 
 use super::Digits;
 
-pub(super) struct Exponent(pub(super) term::Exponent);
+pub(in crate::unit::parser) struct Exponent(pub(in crate::unit::parser) term::Exponent);
 
 impl Visit<'_, TermRule> for Exponent {
     fn visit(pair: Pair<'_, TermRule>) -> Result<Self, Error> {
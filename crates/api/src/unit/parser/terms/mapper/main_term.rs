@@ -2,7 +2,7 @@ use num_traits::Inv;
 use pest::iterators::Pair;
 
 use crate::{
-    unit::parser::{terms::term_parser::Rule, Error, Visit},
+    unit::parser::{terms::term_parser::Rule, Error, ParseOptions, VisitWith},
     Term,
 };
 
@@ -12,16 +12,16 @@ pub(super) struct MainTerm {
     pub(super) terms: Vec<Term>,
 }
 
-impl Visit<'_, Rule> for MainTerm {
-    fn visit(pair: Pair<'_, Rule>) -> Result<Self, Error> {
+impl VisitWith<'_, Rule> for MainTerm {
+    fn visit_with(pair: Pair<'_, Rule>, options: ParseOptions) -> Result<Self, Error> {
         let mut pairs = pair.into_inner();
 
         // match first token
         match pairs.next() {
             Some(first) => match first.as_rule() {
-                Rule::term => {
+                Rule::term | Rule::lenient_term => {
                     return Ok(Self {
-                        terms: AstTerm::visit(first)?.finish(),
+                        terms: AstTerm::visit(first, options)?.finish(),
                     });
                 }
                 // Don't do anything, but because we proceed through this method, we can later
@@ -34,8 +34,8 @@ impl Visit<'_, Rule> for MainTerm {
 
         match pairs.next() {
             Some(second) => match second.as_rule() {
-                Rule::term => {
-                    let terms: Vec<Term> = AstTerm::visit(second)?.finish();
+                Rule::term | Rule::lenient_term => {
+                    let terms: Vec<Term> = AstTerm::visit(second, options)?.finish();
 
                     // If we're here it's because there was a leading slash, so invert.
                     let u = crate::Unit::new(terms).inv();
@@ -1,45 +1,25 @@
-use pest::{iterators::Pair, Parser};
+use pest::iterators::Pair;
 
 use crate::{
-    term::{variants::PrefixAtom, UNITY},
+    term::UNITY,
     unit::parser::{
-        symbols::{
-            mapper as symbol_mapper,
-            symbol_parser::{Rule as SymbolRule, SymbolParser},
-            Symbol,
-        },
-        terms::term_parser::Rule as TermRule,
-        Error, Visit,
+        symbols, terms::term_parser::Rule as TermRule, Diagnostic, Error, ParseOptions, VisitWith,
     },
     Term,
 };
 
-pub(super) struct SimpleUnit(pub(super) Term);
+pub(in crate::unit::parser) struct SimpleUnit(pub(in crate::unit::parser) Term);
 
-impl Visit<'_, TermRule> for SimpleUnit {
-    fn visit(pair: Pair<'_, TermRule>) -> Result<Self, Error> {
+impl VisitWith<'_, TermRule> for SimpleUnit {
+    fn visit_with(pair: Pair<'_, TermRule>, options: ParseOptions) -> Result<Self, Error> {
         let string = pair.as_span().as_str();
 
         if string == "1" {
             return Ok(Self(UNITY));
         }
 
-        if let Ok(mut symbol_pairs) = SymbolParser::parse(SymbolRule::symbol, string) {
-            match symbol_mapper::map(symbol_pairs.next().unwrap())? {
-                Symbol::PrimaryPrefixed { prefix, atom }
-                | Symbol::SecondaryPrefixed { prefix, atom } => {
-                    Ok(Self(Term::PrefixAtom(PrefixAtom { prefix, atom })))
-                }
-                Symbol::PrimaryBasic { atom } | Symbol::SecondaryBasic { atom } => {
-                    Ok(Self(Term::Atom(atom)))
-                }
-                Symbol::Unity => Ok(Self(UNITY)),
-            }
-        } else {
-            Err(Error::BadFragment {
-                fragment: string.to_string(),
-                position: pair.as_span().start(),
-            })
-        }
+        symbols::lookup(string, options.case_mode)
+            .map(Self)
+            .ok_or_else(|| Error::InvalidExpression(Diagnostic::unknown_symbol(&pair.as_span())))
     }
 }
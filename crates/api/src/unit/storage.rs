@@ -0,0 +1,180 @@
+use std::{borrow::Cow, fmt, ops::Deref, sync::Arc};
+
+use crate::{term::UNITY, Term};
+
+/// Most units have no more than this many `Term`s.
+///
+const INLINE_CAPACITY: usize = 3;
+
+/// How a `Unit` holds its `Term`s. Units with up to `INLINE_CAPACITY` terms keep them inline, and
+/// longer ones share theirs behind an `Arc`, so (since `Annotation`s are shared too) cloning a
+/// `Unit` never allocates. Changing a shared list in place copies it first.
+///
+#[allow(variant_size_differences)]
+pub(crate) enum Terms {
+    /// For `const` units (ex. `unit::UNITY`).
+    ///
+    Static(&'static [Term]),
+
+    /// Slots at and after `len` are unused (and hold `term::UNITY`).
+    ///
+    Inline {
+        len: usize,
+        terms: [Term; INLINE_CAPACITY],
+    },
+
+    Shared(Arc<[Term]>),
+}
+
+impl Terms {
+    pub(super) fn to_mut(&mut self) -> &mut [Term] {
+        if let Self::Static(terms) = self {
+            let owned = terms.to_vec();
+            *self = Self::from(owned);
+        }
+
+        if let Self::Shared(terms) = self {
+            if Arc::get_mut(terms).is_none() {
+                *terms = terms.iter().cloned().collect();
+            }
+        }
+
+        match self {
+            Self::Static(_) => unreachable!("static terms were just copied"),
+            Self::Inline { len, terms } => &mut terms[..*len],
+            Self::Shared(terms) => {
+                Arc::get_mut(terms).unwrap_or_else(|| unreachable!("terms were just made unique"))
+            }
+        }
+    }
+}
+
+// Not derived, since that would also clone the unused inline slots, which makes cloning a
+// single-term `Unit` take about twice as long.
+impl Clone for Terms {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Static(terms) => Self::Static(terms),
+            Self::Inline { len, terms } => {
+                let mut clone = [UNITY; INLINE_CAPACITY];
+                clone[..*len].clone_from_slice(&terms[..*len]);
+
+                Self::Inline {
+                    len: *len,
+                    terms: clone,
+                }
+            }
+            Self::Shared(terms) => Self::Shared(Arc::clone(terms)),
+        }
+    }
+}
+
+impl Deref for Terms {
+    type Target = [Term];
+
+    fn deref(&self) -> &[Term] {
+        match self {
+            Self::Static(terms) => terms,
+            Self::Inline { len, terms } => &terms[..*len],
+            Self::Shared(terms) => terms,
+        }
+    }
+}
+
+impl From<Vec<Term>> for Terms {
+    fn from(value: Vec<Term>) -> Self {
+        if value.len() > INLINE_CAPACITY {
+            return Self::Shared(Arc::from(value));
+        }
+
+        let len = value.len();
+        let mut terms = [UNITY; INLINE_CAPACITY];
+
+        for (slot, term) in terms.iter_mut().zip(value) {
+            *slot = term;
+        }
+
+        Self::Inline { len, terms }
+    }
+}
+
+impl From<Cow<'static, [Term]>> for Terms {
+    fn from(value: Cow<'static, [Term]>) -> Self {
+        match value {
+            Cow::Borrowed(terms) => Self::Static(terms),
+            Cow::Owned(terms) => Self::from(terms),
+        }
+    }
+}
+
+impl From<Terms> for Cow<'static, [Term]> {
+    fn from(value: Terms) -> Self {
+        match value {
+            Terms::Static(terms) => Cow::Borrowed(terms),
+            Terms::Inline { .. } | Terms::Shared(_) => Cow::Owned(value.to_vec()),
+        }
+    }
+}
+
+impl fmt::Debug for Terms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Annotation, Atom};
+
+    use super::*;
+
+    fn annotated(annotation: &str) -> Term {
+        Term::Annotation(Annotation::from(annotation))
+    }
+
+    fn annotation_ptr(term: &Term) -> *const u8 {
+        term.annotation().unwrap().as_ptr()
+    }
+
+    #[test]
+    fn validate_inline() {
+        let terms = Terms::from(vec![Term::Atom(Atom::Meter), annotated("foo")]);
+        assert!(matches!(terms, Terms::Inline { len: 2, .. }));
+        assert_eq!(&*terms, &[Term::Atom(Atom::Meter), annotated("foo")]);
+
+        let cloned = terms.clone();
+        assert!(matches!(cloned, Terms::Inline { len: 2, .. }));
+        assert_eq!(&*cloned, &*terms);
+        assert_eq!(annotation_ptr(&terms[1]), annotation_ptr(&cloned[1]));
+    }
+
+    #[test]
+    fn validate_shared() {
+        let terms = Terms::from(vec![
+            Term::Atom(Atom::Meter),
+            Term::Atom(Atom::Gram),
+            Term::Atom(Atom::Second),
+            annotated("foo"),
+        ]);
+        assert!(matches!(terms, Terms::Shared(_)));
+
+        let cloned = terms.clone();
+        assert_eq!(terms.as_ptr(), cloned.as_ptr());
+    }
+
+    #[test]
+    fn validate_to_mut_copies_on_write() {
+        let mut terms = Terms::from(vec![Term::Atom(Atom::Meter); 4]);
+        let cloned = terms.clone();
+
+        terms.to_mut()[0] = Term::Atom(Atom::Gram);
+        assert_eq!(terms[0], Term::Atom(Atom::Gram));
+        assert_eq!(cloned[0], Term::Atom(Atom::Meter));
+
+        let unity: &'static [Term] = crate::term::UNITY_ARRAY_REF;
+        let mut terms = Terms::from(Cow::Borrowed(unity));
+        terms.to_mut()[0] = Term::Atom(Atom::Gram);
+        assert!(matches!(terms, Terms::Inline { len: 1, .. }));
+        assert_eq!(terms[0], Term::Atom(Atom::Gram));
+    }
+}
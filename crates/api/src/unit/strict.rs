@@ -0,0 +1,229 @@
+use std::fmt;
+
+use crate::{term::Exponent, Atom, Prefix, UcumSymbol, UcumUnit, Unit};
+
+/// A way in which a `Unit` doesn't conform to the UCUM spec, even though `wise_units` accepts it.
+/// See `Unit::validate_strict()`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StrictFinding {
+    /// UCUM only allows prefixes on metric atoms (ex. `k[lb_av]` isn't valid).
+    ///
+    PrefixOnNonMetricAtom {
+        term_index: usize,
+        prefix: Prefix,
+        atom: Atom,
+    },
+
+    /// UCUM annotations may only contain ASCII characters 33–126, other than curly braces.
+    ///
+    InvalidAnnotationCharacter {
+        term_index: usize,
+        annotation: String,
+        character: char,
+    },
+
+    /// Special units (ex. `Cel`, `[pH]`) are defined by conversion functions, so can't be raised to
+    /// a power.
+    ///
+    ExponentOnSpecialUnit {
+        term_index: usize,
+        atom: Atom,
+        exponent: Exponent,
+    },
+}
+
+impl fmt::Display for StrictFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PrefixOnNonMetricAtom {
+                term_index,
+                prefix,
+                atom,
+            } => write!(
+                f,
+                "term {term_index}: prefix \"{}\" applied to non-metric atom \"{}\"",
+                prefix.primary_code(),
+                atom.primary_code()
+            ),
+            Self::InvalidAnnotationCharacter {
+                term_index,
+                annotation,
+                character,
+            } => write!(
+                f,
+                "term {term_index}: annotation \"{annotation}\" contains invalid character {character:?}"
+            ),
+            Self::ExponentOnSpecialUnit {
+                term_index,
+                atom,
+                exponent,
+            } => write!(
+                f,
+                "term {term_index}: special unit \"{}\" raised to the power of {exponent}",
+                atom.primary_code()
+            ),
+        }
+    }
+}
+
+impl Unit {
+    /// Checks `self` against the rules of the UCUM spec that `wise_units` doesn't enforce when
+    /// parsing or building units: prefixes may only be applied to metric atoms, annotations may
+    /// only contain ASCII characters 33–126 (other than curly braces), and special units can't
+    /// have exponents. Returns every violation found; an empty list means `self` is valid UCUM.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use wise_units::{unit::StrictFinding, Atom, Prefix, Unit};
+    ///
+    /// assert!(Unit::from_str("kg/[acr_us]").unwrap().validate_strict().is_empty());
+    ///
+    /// assert_eq!(
+    ///     Unit::from_str("k[lb_av]").unwrap().validate_strict(),
+    ///     vec![StrictFinding::PrefixOnNonMetricAtom {
+    ///         term_index: 0,
+    ///         prefix: Prefix::Kilo,
+    ///         atom: Atom::PoundAvoirdupois,
+    ///     }]
+    /// );
+    /// ```
+    ///
+    #[must_use]
+    pub fn validate_strict(&self) -> Vec<StrictFinding> {
+        let mut findings = Vec::new();
+
+        for (term_index, term) in self.terms.iter().enumerate() {
+            if let (Some(prefix), Some(atom)) = (term.prefix(), term.atom()) {
+                if !atom.is_metric() {
+                    findings.push(StrictFinding::PrefixOnNonMetricAtom {
+                        term_index,
+                        prefix,
+                        atom,
+                    });
+                }
+            }
+
+            if let Some(annotation) = term.annotation() {
+                let invalid_characters = annotation
+                    .chars()
+                    .filter(|c| !matches!(c, '!'..='~') || matches!(c, '{' | '}'));
+
+                for character in invalid_characters {
+                    findings.push(StrictFinding::InvalidAnnotationCharacter {
+                        term_index,
+                        annotation: annotation.to_string(),
+                        character,
+                    });
+                }
+            }
+
+            if let (Some(atom), Some(exponent)) = (term.atom(), term.exponent()) {
+                if atom.is_special() && exponent != 1 {
+                    findings.push(StrictFinding::ExponentOnSpecialUnit {
+                        term_index,
+                        atom,
+                        exponent,
+                    });
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{Annotation, Term};
+
+    use super::*;
+
+    macro_rules! validate_conforms {
+        ($test_name:ident, $input:expr) => {
+            #[test]
+            fn $test_name() {
+                let unit = Unit::from_str($input).unwrap();
+                assert_eq!(unit.validate_strict(), vec![]);
+            }
+        };
+    }
+
+    validate_conforms!(validate_metric_prefix, "kg");
+    validate_conforms!(validate_non_metric, "[lb_av]/[acr_us]");
+    validate_conforms!(validate_special, "Cel");
+    validate_conforms!(validate_prefixed_special, "mCel");
+    validate_conforms!(validate_annotation, "{tbl}/d");
+
+    #[test]
+    fn validate_prefix_on_non_metric_atom() {
+        let unit = Unit::from_str("m.k[lb_av]/c[acr_us]").unwrap();
+
+        assert_eq!(
+            unit.validate_strict(),
+            vec![
+                StrictFinding::PrefixOnNonMetricAtom {
+                    term_index: 1,
+                    prefix: Prefix::Kilo,
+                    atom: Atom::PoundAvoirdupois,
+                },
+                StrictFinding::PrefixOnNonMetricAtom {
+                    term_index: 2,
+                    prefix: Prefix::Centi,
+                    atom: Atom::AcreUS,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_exponent_on_special_unit() {
+        let unit = Unit::from_str("Cel2/[degF]").unwrap();
+
+        assert_eq!(
+            unit.validate_strict(),
+            vec![
+                StrictFinding::ExponentOnSpecialUnit {
+                    term_index: 0,
+                    atom: Atom::DegreeCelsius,
+                    exponent: 2,
+                },
+                StrictFinding::ExponentOnSpecialUnit {
+                    term_index: 1,
+                    atom: Atom::DegreeFahrenheit,
+                    exponent: -1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_invalid_annotation_characters() {
+        let unit = Unit::new(vec![Term::Annotation(Annotation::from("two words"))]);
+
+        assert_eq!(
+            unit.validate_strict(),
+            vec![StrictFinding::InvalidAnnotationCharacter {
+                term_index: 0,
+                annotation: "two words".to_string(),
+                character: ' ',
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_display() {
+        let findings = Unit::from_str("k[lb_av].Cel2").unwrap().validate_strict();
+
+        assert_eq!(
+            findings[0].to_string(),
+            "term 0: prefix \"k\" applied to non-metric atom \"[lb_av]\""
+        );
+        assert_eq!(
+            findings[1].to_string(),
+            "term 1: special unit \"Cel\" raised to the power of 2"
+        );
+    }
+}
@@ -73,7 +73,8 @@ impl UcumUnit for Unit {
     ///
     #[inline]
     fn scalar(&self) -> f64 {
-        self.reduce_value(num_traits::One::one())
+        self.memo
+            .scalar(|| self.reduce_value(num_traits::One::one()))
     }
 
     /// The scalar value of `self` in terms of `self`'s actual unit(s).
@@ -120,7 +121,8 @@ impl UcumUnit for Unit {
     ///
     #[inline]
     fn magnitude(&self) -> f64 {
-        self.calculate_magnitude(self.scalar())
+        self.memo
+            .magnitude(|| self.calculate_magnitude(self.scalar()))
     }
 }
 
@@ -23,8 +23,8 @@
 //!
 
 use crate::{
-    rust_structs::{RustAtom, RustFunctionSet},
-    toml_structs::{TomlAtom, TomlBaseUnit, TomlUnit},
+    rust_structs::{RustAtom, RustFunctionSet, RustPrefix},
+    toml_structs::{TomlAtom, TomlBaseUnit, TomlPrefix, TomlUnit},
 };
 use heck::ToUpperCamelCase;
 use proc_macro2::TokenStream;
@@ -33,6 +33,20 @@ use quote::quote;
 pub(crate) mod atoms;
 pub(crate) mod custom_atoms;
 
+/// Transforms a `Vec<TomlPrefix>` to a `Vec<RustPrefix>`.
+///
+fn transform_prefixes(atom_list_prefixes: &[TomlPrefix]) -> Vec<RustPrefix> {
+    atom_list_prefixes
+        .iter()
+        .map(|p| RustPrefix {
+            type_name: p.names[0].to_upper_camel_case(),
+            primary_code: p.primary_code.clone(),
+            secondary_code: p.secondary_code.clone(),
+            value: p.value,
+        })
+        .collect()
+}
+
 /// Transforms a `Vec<TomlBaseUnit>` to a `Vec<RustAtom>`.
 ///
 fn transform_base_units(atom_list_base_units: &[TomlBaseUnit]) -> Vec<RustAtom> {
@@ -43,6 +57,8 @@ fn transform_base_units(atom_list_base_units: &[TomlBaseUnit]) -> Vec<RustAtom>
             classification: "Si".to_string(),
             dim: Some(bu.dim.clone()),
             definition_signature: quote! { Ok(Definition::default()) },
+            definition_value: 1.0,
+            definition_unit: "1".to_string(),
             primary_code: bu.primary_code.clone(),
             print_symbol: Some(bu.print_symbol.clone()),
             property: bu.property.clone(),
@@ -61,6 +77,16 @@ fn transform_units(atom_list_units: &[TomlUnit]) -> Vec<RustAtom> {
     atom_list_units
         .iter()
         .map(|u| {
+            let (definition_value, definition_unit) = if u.is_special {
+                let function = u.definition.function.as_ref().unwrap();
+
+                (function.value, function.unit.clone())
+            } else if &u.primary_code == "[pi]" {
+                (std::f64::consts::PI, u.definition.unit.clone())
+            } else {
+                (u.definition.value, u.definition.unit.clone())
+            };
+
             let definition_signature = if u.is_special {
                 let function_set = RustFunctionSet {
                     convert_from: build_magnitude_function(&u.primary_code),
@@ -96,6 +122,8 @@ fn transform_units(atom_list_units: &[TomlUnit]) -> Vec<RustAtom> {
                 classification: u.classification.clone().to_upper_camel_case(),
                 dim: None,
                 definition_signature,
+                definition_value,
+                definition_unit,
                 primary_code: u.primary_code.clone(),
                 print_symbol: u.print_symbol.clone(),
                 property: u.property.clone(),
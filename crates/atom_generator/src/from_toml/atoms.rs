@@ -19,5 +19,7 @@ fn transform(toml_atom_list: &TomlAtomList) -> RustAtomList {
     let mut atoms = super::transform_base_units(&toml_atom_list.base_units);
     atoms.append(&mut super::transform_units(&toml_atom_list.units));
 
-    RustAtomList { atoms }
+    let prefixes = super::transform_prefixes(&toml_atom_list.prefixes);
+
+    RustAtomList { atoms, prefixes }
 }
@@ -7,15 +7,13 @@
 //!
 
 mod atom;
+mod atom_table;
 mod classification;
-mod handlebars;
 mod property;
-mod symbol_grammar;
+mod symbol_table;
 
 use proc_macro2::TokenStream;
 
-use self::handlebars::HANDLEBARS;
-
 use crate::rust_structs::RustAtomList;
 use std::{
     fs::File,
@@ -39,7 +37,8 @@ pub(crate) fn generate_files(rust_atom_list: &RustAtomList) {
     generate_classification_file(rust_atom_list);
     generate_property_file(rust_atom_list);
     generate_atom_file(rust_atom_list);
-    generate_symbol_grammar_file(rust_atom_list);
+    generate_atom_table_file(rust_atom_list);
+    generate_symbol_table_file(rust_atom_list);
 }
 
 fn generate_classification_file(rust_atom_list: &RustAtomList) {
@@ -60,10 +59,16 @@ fn generate_atom_file(rust_atom_list: &RustAtomList) {
     write_project_file(&file_path, &file_body, true);
 }
 
-fn generate_symbol_grammar_file(rust_atom_list: &RustAtomList) {
-    let file_body = symbol_grammar::generate_file_body(rust_atom_list);
-    let file_path = build_file_path("parser/symbols/symbol.pest");
-    write_project_file(&file_path, &file_body, false);
+fn generate_atom_table_file(rust_atom_list: &RustAtomList) {
+    let file_body = atom_table::generate_file_body(rust_atom_list);
+    let file_path = build_file_path("atom/table.rs");
+    write_project_file(&file_path, &file_body, true);
+}
+
+fn generate_symbol_table_file(rust_atom_list: &RustAtomList) {
+    let file_body = symbol_table::generate_file_body(rust_atom_list);
+    let file_path = build_file_path("unit/parser/symbols/symbol_table.rs");
+    write_project_file(&file_path, &file_body, true);
 }
 
 fn build_file_path(file_name: &str) -> PathBuf {
@@ -7,6 +7,7 @@ use crate::rust_structs::rust_atom_list::RustAtomList;
 ///
 pub(super) fn generate_file_body(atom_list: &RustAtomList) -> String {
     let atom_enum = atom_list.atom_enum();
+    let all_const = atom_list.all_const();
     let definition_method = atom_list.definition_method();
     let property_method = atom_list.property_method();
 
@@ -16,10 +17,6 @@ pub(super) fn generate_file_body(atom_list: &RustAtomList) -> String {
     let print_symbol_method = atom_list.print_symbol_method();
     let secondary_code_method = atom_list.secondary_code_method();
 
-    let is_arbitrary_method = atom_list.is_arbitrary_method();
-    let is_special_method = atom_list.is_special_method();
-    let is_metric_method = atom_list.is_metric_method();
-
     let tokens = quote! {
         #![allow(clippy::unreadable_literal, clippy::too_many_lines, clippy::match_same_arms)]
 
@@ -30,6 +27,7 @@ pub(super) fn generate_file_body(atom_list: &RustAtomList) -> String {
         mod hash;
         mod partial_eq;
         mod reducible;
+        mod table;
 
         #[cfg(feature = "v2")]
         mod v2;
@@ -51,6 +49,8 @@ pub(super) fn generate_file_body(atom_list: &RustAtomList) -> String {
         #atom_enum
 
         impl Atom {
+            #all_const
+
             #definition_method
 
             #property_method
@@ -78,18 +78,26 @@ pub(super) fn generate_file_body(atom_list: &RustAtomList) -> String {
 
         impl UcumUnit for Atom {
             fn scalar(&self) -> f64 {
-                self.reduce_value(num_traits::One::one())
+                self.entry()
+                    .scalar
+                    .unwrap_or_else(|| self.reduce_value(num_traits::One::one()))
             }
 
             fn magnitude(&self) -> f64 {
                 self.calculate_magnitude(self.scalar())
             }
 
-            #is_arbitrary_method
+            fn is_arbitrary(&self) -> bool {
+                self.entry().is_arbitrary
+            }
 
-            #is_special_method
+            fn is_special(&self) -> bool {
+                self.entry().is_special
+            }
 
-            #is_metric_method
+            fn is_metric(&self) -> bool {
+                self.entry().is_metric
+            }
         }
 
         impl DefaultCompatibility for Atom {}
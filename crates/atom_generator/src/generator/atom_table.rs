@@ -0,0 +1,382 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::rust_structs::{RustAtom, RustAtomList};
+
+/// The `dim` codes of the base units, in the order of `Composition::new_any()`'s
+/// parameters.
+///
+const DIMENSIONS: [&str; 7] = ["Q", "L", "F", "M", "A", "C", "T"];
+
+/// Generates the Rust code for the `ATOMS` table, which holds each atom's
+/// scalar (in base units), `Composition`, and flags, resolved from its
+/// definition so `wise_units` doesn't have to reduce definitions at runtime.
+///
+pub(super) fn generate_file_body(atom_list: &RustAtomList) -> String {
+    let mut resolver = Resolver::new(atom_list);
+    let entries = atom_list
+        .atoms
+        .iter()
+        .map(|atom| entry_ts(atom, resolver.reduce_atom(&atom.primary_code)));
+    let entries: Vec<_> = entries.collect();
+    let count = Literal::usize_unsuffixed(entries.len());
+
+    let tokens = quote! {
+        #![allow(clippy::approx_constant, clippy::unreadable_literal)]
+
+        use crate::Composition;
+
+        use super::Atom;
+
+        /// An `Atom`'s definition, reduced to base units.
+        pub(super) struct AtomEntry {
+            /// `None` for special atoms, whose scalars depend on the value being converted.
+            pub(super) scalar: Option<f64>,
+            pub(super) composition: Composition,
+            pub(super) is_arbitrary: bool,
+            pub(super) is_metric: bool,
+            pub(super) is_special: bool,
+        }
+
+        /// Every `Atom`'s entry, in the order they're defined in `Atoms.toml`.
+        pub(super) static ATOMS: [AtomEntry; #count] = [#(#entries),*];
+
+        impl Atom {
+            pub(super) fn entry(self) -> &'static AtomEntry {
+                &ATOMS[self as usize]
+            }
+        }
+    };
+
+    super::pretty_format(&tokens)
+}
+
+fn entry_ts(atom: &RustAtom, reduced: Reduced) -> TokenStream {
+    let scalar = if atom.is_special {
+        quote! { None }
+    } else {
+        let scalar: Literal = format!("{:?}", reduced.scalar)
+            .parse()
+            .expect("BUG! Unable to write scalar as a literal");
+
+        quote! { Some(#scalar) }
+    };
+
+    let exponents = reduced.exponents.iter().map(|&exponent| {
+        if exponent == 0 {
+            quote! { None }
+        } else {
+            let exponent = Literal::i32_unsuffixed(exponent);
+
+            quote! { Some(#exponent) }
+        }
+    });
+
+    let is_arbitrary = atom.is_arbitrary;
+    let is_metric = atom.is_metric;
+    let is_special = atom.is_special;
+
+    quote! {
+        AtomEntry {
+            scalar: #scalar,
+            composition: Composition::new_any(#(#exponents),*),
+            is_arbitrary: #is_arbitrary,
+            is_metric: #is_metric,
+            is_special: #is_special,
+        }
+    }
+}
+
+/// A definition, reduced to a scalar and the exponent of each base dimension
+/// (in `DIMENSIONS` order).
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Reduced {
+    scalar: f64,
+    exponents: [i32; 7],
+}
+
+impl Reduced {
+    const ONE: Self = Self {
+        scalar: 1.0,
+        exponents: [0; 7],
+    };
+
+    fn powi(mut self, exponent: i32) -> Self {
+        self.scalar = self.scalar.powi(exponent);
+
+        for e in &mut self.exponents {
+            *e *= exponent;
+        }
+
+        self
+    }
+
+    fn mul(mut self, rhs: Self) -> Self {
+        self.scalar *= rhs.scalar;
+
+        for (e, rhs_e) in self.exponents.iter_mut().zip(rhs.exponents) {
+            *e += rhs_e;
+        }
+
+        self
+    }
+}
+
+/// Reduces atoms' definitions, which are unit expressions in terms of other
+/// atoms (ex. `[in_i]` is `cm` * 2.54). The expressions in `Atoms.toml` only
+/// use `.`, `/`, integer factors, and exponents, so that's all this handles.
+///
+/// Like `wise_units`' parser, this treats `/` as dividing by everything to its
+/// right (ex. `cd/cm2/[pi]` is `cd/(cm2/[pi])`), so the table matches what
+/// the runtime definitions reduce to.
+///
+struct Resolver<'a> {
+    atoms: HashMap<&'a str, &'a RustAtom>,
+    prefixes: Vec<(&'a str, f64)>,
+    reduced: HashMap<&'a str, Reduced>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(atom_list: &'a RustAtomList) -> Self {
+        let atoms = atom_list
+            .atoms
+            .iter()
+            .map(|atom| (atom.primary_code.as_str(), atom))
+            .collect();
+
+        // Longest first, so `da` wins over `d`.
+        let mut prefixes: Vec<_> = atom_list
+            .prefixes
+            .iter()
+            .map(|prefix| (prefix.primary_code.as_str(), prefix.value))
+            .collect();
+        prefixes.sort_by_key(|(code, _)| std::cmp::Reverse(code.len()));
+
+        Self {
+            atoms,
+            prefixes,
+            reduced: HashMap::new(),
+        }
+    }
+
+    fn reduce_atom(&mut self, primary_code: &str) -> Reduced {
+        if let Some(reduced) = self.reduced.get(primary_code) {
+            return *reduced;
+        }
+
+        let atom = *self
+            .atoms
+            .get(primary_code)
+            .unwrap_or_else(|| panic!("Unknown atom in definition: {primary_code}"));
+
+        let reduced = atom.dim.as_deref().map_or_else(
+            || {
+                let mut reduced = self.reduce_expression(&atom.definition_unit);
+                reduced.scalar *= atom.definition_value;
+
+                reduced
+            },
+            base_unit,
+        );
+
+        let _ = self.reduced.insert(&atom.primary_code, reduced);
+
+        reduced
+    }
+
+    fn reduce_expression(&mut self, expression: &str) -> Reduced {
+        let mut reduced = Reduced::ONE;
+        let mut depth = 0;
+        let mut start = 0;
+        let mut exponent = 1;
+
+        for (i, c) in expression.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                '.' | '/' if depth == 0 => {
+                    if i > start {
+                        let component = self.reduce_component(&expression[start..i]);
+                        reduced = reduced.mul(component.powi(exponent));
+                    }
+
+                    if c == '/' {
+                        exponent = -exponent;
+                    }
+
+                    start = i + 1;
+                }
+                _ => (),
+            }
+        }
+
+        let component = self.reduce_component(&expression[start..]);
+
+        reduced.mul(component.powi(exponent))
+    }
+
+    fn reduce_component(&mut self, component: &str) -> Reduced {
+        if let Ok(factor) = component.parse::<u32>() {
+            return Reduced {
+                scalar: f64::from(factor),
+                ..Reduced::ONE
+            };
+        }
+
+        let (symbol, exponent) = split_exponent(component);
+
+        self.reduce_symbol(symbol).powi(exponent)
+    }
+
+    fn reduce_symbol(&mut self, symbol: &str) -> Reduced {
+        if self.atoms.contains_key(symbol) {
+            return self.reduce_atom(symbol);
+        }
+
+        let (prefix_value, atom_code) = self
+            .prefixes
+            .iter()
+            .find_map(|(code, value)| {
+                let rest = symbol.strip_prefix(code)?;
+
+                self.atoms.contains_key(rest).then_some((*value, rest))
+            })
+            .unwrap_or_else(|| panic!("Unknown symbol in definition: {symbol}"));
+
+        let mut reduced = self.reduce_atom(atom_code);
+        reduced.scalar *= prefix_value;
+
+        reduced
+    }
+}
+
+/// A base unit with the `dim` code `dim` (ex. `L`).
+///
+fn base_unit(dim: &str) -> Reduced {
+    let index = DIMENSIONS
+        .iter()
+        .position(|d| *d == dim)
+        .unwrap_or_else(|| panic!("Unknown dim on base unit: {dim}"));
+    let mut exponents = [0; 7];
+    exponents[index] = 1;
+
+    Reduced {
+        scalar: 1.0,
+        exponents,
+    }
+}
+
+/// Splits a trailing exponent off of `component` (ex. `cm-1` -> `cm`, `-1`).
+///
+fn split_exponent(component: &str) -> (&str, i32) {
+    let digits_start = component
+        .trim_end_matches(|c: char| c.is_ascii_digit())
+        .len();
+
+    if digits_start == component.len() {
+        return (component, 1);
+    }
+
+    let symbol_end = if component[..digits_start].ends_with(['-', '+']) {
+        digits_start - 1
+    } else {
+        digits_start
+    };
+
+    let exponent = component[symbol_end..]
+        .parse()
+        .unwrap_or_else(|_| panic!("Bad exponent in definition: {component}"));
+
+    (&component[..symbol_end], exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::from_toml::atoms::build_rust_atom_list;
+
+    use super::*;
+
+    fn assert_reduced(reduced: Reduced, scalar: f64, exponents: [i32; 7]) {
+        assert!(
+            (reduced.scalar - scalar).abs() <= scalar.abs() * 1e-12,
+            "{} != {scalar}",
+            reduced.scalar
+        );
+        assert_eq!(reduced.exponents, exponents);
+    }
+
+    #[test]
+    fn validate_reduce_atom() {
+        let atom_list = build_rust_atom_list();
+        let mut resolver = Resolver::new(&atom_list);
+
+        assert_reduced(resolver.reduce_atom("m"), 1.0, [0, 1, 0, 0, 0, 0, 0]);
+        assert_reduced(resolver.reduce_atom("N"), 1000.0, [0, 1, 0, 1, 0, 0, -2]);
+        assert_reduced(resolver.reduce_atom("[in_i]"), 0.0254, [0, 1, 0, 0, 0, 0, 0]);
+        assert_reduced(
+            resolver.reduce_atom("[acr_us]"),
+            4_046.872_609_874_252,
+            [0, 2, 0, 0, 0, 0, 0],
+        );
+        assert_reduced(
+            resolver.reduce_atom("''"),
+            4.848_136_811_095_36e-6,
+            [0, 0, 0, 0, 1, 0, 0],
+        );
+        assert_reduced(
+            resolver.reduce_atom("[mu_0]"),
+            1.256_637_061_435_917_3e-3,
+            [-2, 1, 0, 1, 0, 0, 0],
+        );
+        assert_reduced(resolver.reduce_atom("%"), 0.01, [0; 7]);
+        assert_reduced(resolver.reduce_atom("[pi]"), std::f64::consts::PI, [0; 7]);
+        assert_reduced(resolver.reduce_atom("Cel"), 1.0, [0, 0, 0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn validate_reduce_expression() {
+        let atom_list = build_rust_atom_list();
+        let mut resolver = Resolver::new(&atom_list);
+
+        assert_reduced(
+            resolver.reduce_expression("/s"),
+            1.0,
+            [0, 0, 0, 0, 0, 0, -1],
+        );
+        assert_reduced(
+            resolver.reduce_expression("kg.m/s2"),
+            1000.0,
+            [0, 1, 0, 1, 0, 0, -2],
+        );
+        assert_reduced(
+            resolver.reduce_expression("cd/cm2/[pi]"),
+            10_000.0 * std::f64::consts::PI,
+            [0, -2, 1, 0, 0, 0, 0],
+        );
+        assert_reduced(
+            resolver.reduce_expression("mL/min/kg"),
+            1.0e-6 * 1000.0 / 60.0,
+            [0, 3, 0, 1, 0, 0, -1],
+        );
+    }
+
+    #[test]
+    fn validate_split_exponent() {
+        assert_eq!(split_exponent("m"), ("m", 1));
+        assert_eq!(split_exponent("m3"), ("m", 3));
+        assert_eq!(split_exponent("cm-1"), ("cm", -1));
+        assert_eq!(split_exponent("10*-12"), ("10*", -12));
+        assert_eq!(split_exponent("[in_i]2"), ("[in_i]", 2));
+    }
+
+    #[test]
+    fn render_test() {
+        let output = generate_file_body(&build_rust_atom_list());
+
+        assert_eq!(&output[0..4], "//--");
+        assert!(output.contains("static ATOMS: [AtomEntry; 310]"));
+    }
+}
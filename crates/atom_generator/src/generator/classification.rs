@@ -77,6 +77,8 @@ mod tests {
                 classification: "CoolBeans".to_string(),
                 dim: None,
                 definition_signature: TokenStream::new(),
+                definition_value: 1.0,
+                definition_unit: "1".to_string(),
                 primary_code: String::new(),
                 print_symbol: None,
                 property: String::new(),
@@ -86,6 +88,7 @@ mod tests {
                 is_metric: false,
                 is_special: false,
             }],
+            prefixes: vec![],
         };
 
         let output = generate_file_body(&rust_atom_list);
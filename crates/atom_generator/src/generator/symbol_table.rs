@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::rust_structs::RustAtomList;
+
+/// Generates the Rust code for the perfect-hash tables that the `wise_units`
+/// parser uses to look up atoms and prefixes by their codes.
+///
+pub(super) fn generate_file_body(atom_list: &RustAtomList) -> String {
+    let primary_atoms = build_map(
+        atom_list
+            .atoms
+            .iter()
+            .map(|atom| (atom.primary_code.clone(), format!("Atom::{}", atom.type_name))),
+    );
+
+    // Case-insensitive lookups upper-case their input, so the keys must be upper case too (a few
+    // case-insensitive codes, like `[degR]`, aren't).
+    let secondary_atoms = build_map(atom_list.atoms.iter().filter_map(|atom| {
+        atom.secondary_code
+            .as_deref()
+            .map(|code| (code.to_ascii_uppercase(), format!("Atom::{}", atom.type_name)))
+    }));
+
+    let primary_prefixes = build_map(atom_list.prefixes.iter().map(|prefix| {
+        (
+            prefix.primary_code.clone(),
+            format!("Prefix::{}", prefix.type_name),
+        )
+    }));
+
+    let secondary_prefixes = build_map(atom_list.prefixes.iter().map(|prefix| {
+        (
+            prefix.secondary_code.to_ascii_uppercase(),
+            format!("Prefix::{}", prefix.type_name),
+        )
+    }));
+
+    let max_prefix_length = Literal::usize_unsuffixed(
+        atom_list
+            .prefixes
+            .iter()
+            .flat_map(|prefix| [prefix.primary_code.len(), prefix.secondary_code.len()])
+            .max()
+            .unwrap_or_default(),
+    );
+
+    let tokens = quote! {
+        #![allow(clippy::unreadable_literal)]
+
+        use crate::{Atom, Prefix};
+
+        /// `Atom`s by their case-sensitive code (ex. `m`).
+        pub(super) static PRIMARY_ATOMS: phf::Map<&'static str, Atom> = #primary_atoms;
+
+        /// `Atom`s by their case-insensitive code (ex. `M`).
+        pub(super) static SECONDARY_ATOMS: phf::Map<&'static str, Atom> = #secondary_atoms;
+
+        /// `Prefix`es by their case-sensitive code (ex. `k`).
+        pub(super) static PRIMARY_PREFIXES: phf::Map<&'static str, Prefix> = #primary_prefixes;
+
+        /// `Prefix`es by their case-insensitive code (ex. `K`).
+        pub(super) static SECONDARY_PREFIXES: phf::Map<&'static str, Prefix> = #secondary_prefixes;
+
+        /// The length of the longest prefix code, in bytes.
+        pub(super) const MAX_PREFIX_LENGTH: usize = #max_prefix_length;
+    };
+
+    super::pretty_format(&tokens)
+}
+
+/// Builds a `phf::Map` from `(code, value)` pairs. If two atoms share a code
+/// (ex. `[IU]` is the case-insensitive code for both `[IU]` and `[iU]`), the
+/// first one wins.
+///
+fn build_map<I>(entries: I) -> TokenStream
+where
+    I: Iterator<Item = (String, String)>,
+{
+    let mut map = phf_codegen::Map::new();
+    let mut codes = HashSet::new();
+
+    for (code, value) in entries {
+        if codes.insert(code.clone()) {
+            let _ = map.entry(code, &value);
+        }
+    }
+
+    map.build()
+        .to_string()
+        .parse()
+        .expect("phf_codegen built an invalid map")
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+
+    use crate::rust_structs::{RustAtom, RustPrefix};
+
+    use super::*;
+
+    fn atom(type_name: &str, primary_code: &str, secondary_code: &str) -> RustAtom {
+        RustAtom {
+            type_name: type_name.to_string(),
+            classification: String::new(),
+            dim: None,
+            definition_signature: TokenStream::new(),
+            definition_value: 1.0,
+            definition_unit: "1".to_string(),
+            primary_code: primary_code.to_string(),
+            print_symbol: None,
+            property: String::new(),
+            names: vec![],
+            secondary_code: Some(secondary_code.to_string()),
+            is_arbitrary: false,
+            is_metric: false,
+            is_special: false,
+        }
+    }
+
+    #[test]
+    fn render_test() {
+        let rust_atom_list = RustAtomList {
+            atoms: vec![
+                atom("InternationalUnit", "[IU]", "[IU]"),
+                atom("InternationalUnitLowercase", "[iU]", "[IU]"),
+                atom("DegreeRankine", "[degR]", "[degR]"),
+            ],
+            prefixes: vec![RustPrefix {
+                type_name: "Kibi".to_string(),
+                primary_code: "Ki".to_string(),
+                secondary_code: "KIB".to_string(),
+                value: 1024.0,
+            }],
+        };
+
+        let output = generate_file_body(&rust_atom_list);
+
+        assert_eq!(&output[0..4], "//--");
+        assert!(output.contains("(\"[iU]\", Atom::InternationalUnitLowercase)"));
+        assert!(output.contains("(\"[IU]\", Atom::InternationalUnit)"));
+        assert!(!output.contains("(\"[IU]\", Atom::InternationalUnitLowercase)"));
+        assert!(output.contains("(\"[degR]\", Atom::DegreeRankine)"));
+        assert!(output.contains("(\"[DEGR]\", Atom::DegreeRankine)"));
+        assert!(output.contains("(\"KIB\", Prefix::Kibi)"));
+        assert!(output.contains("MAX_PREFIX_LENGTH: usize = 3;"));
+    }
+}
@@ -1,12 +1,13 @@
-use std::collections::BTreeSet;
-
 use heck::ToUpperCamelCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
+use super::RustPrefix;
+
 #[derive(Debug)]
 pub(crate) struct RustAtomList {
     pub(crate) atoms: Vec<RustAtom>,
+    pub(crate) prefixes: Vec<RustPrefix>,
 }
 
 impl RustAtomList {
@@ -24,6 +25,21 @@ impl RustAtomList {
         }
     }
 
+    /// Emits the Rust code for defining `Atom::ALL`.
+    ///
+    pub(crate) fn all_const(&self) -> TokenStream {
+        let variants = self
+            .atoms
+            .iter()
+            .map(|atom| format_ident!("{}", &atom.type_name));
+        let count = self.atoms.len();
+
+        quote! {
+            /// Every `Atom`, in the order they're defined in `Atoms.toml`.
+            pub const ALL: [Self; #count] = [#(Self::#variants),*];
+        }
+    }
+
     /// Emits the Rust code for defining `Atom::definition()`.
     ///
     pub(crate) fn definition_method(&self) -> TokenStream {
@@ -115,63 +131,6 @@ impl RustAtomList {
             }
         }
     }
-
-    pub(crate) fn is_arbitrary_method(&self) -> TokenStream {
-        let true_variants: BTreeSet<_> = self
-            .atoms
-            .iter()
-            .filter(|atom| atom.is_arbitrary)
-            .map(|atom| format_ident!("{}", atom.type_name))
-            .collect();
-
-        let true_parts = true_variants.into_iter().map(|atom_variant| {
-            quote! { Self::#atom_variant }
-        });
-
-        quote! {
-            fn is_arbitrary(&self) -> bool {
-                matches!(*self, #(#true_parts) |*)
-            }
-        }
-    }
-
-    pub(crate) fn is_special_method(&self) -> TokenStream {
-        let true_variants: BTreeSet<_> = self
-            .atoms
-            .iter()
-            .filter(|atom| atom.is_special)
-            .map(|atom| format_ident!("{}", atom.type_name))
-            .collect();
-
-        let true_parts = true_variants.into_iter().map(|atom_variant| {
-            quote! { Self::#atom_variant }
-        });
-
-        quote! {
-            fn is_special(&self) -> bool {
-                matches!(*self, #(#true_parts) |*)
-            }
-        }
-    }
-
-    pub(crate) fn is_metric_method(&self) -> TokenStream {
-        let true_variants: BTreeSet<_> = self
-            .atoms
-            .iter()
-            .filter(|atom| atom.is_metric)
-            .map(|atom| format_ident!("{}", atom.type_name))
-            .collect();
-
-        let true_parts = true_variants.into_iter().map(|atom_variant| {
-            quote! { Self::#atom_variant }
-        });
-
-        quote! {
-            fn is_metric(&self) -> bool {
-                matches!(*self, #(#true_parts) |*)
-            }
-        }
-    }
 }
 
 #[derive(Debug)]
@@ -180,6 +139,8 @@ pub(crate) struct RustAtom {
     pub(crate) classification: String,
     pub(crate) dim: Option<String>,
     pub(crate) definition_signature: TokenStream,
+    pub(crate) definition_value: f64,
+    pub(crate) definition_unit: String,
     pub(crate) primary_code: String,
     pub(crate) print_symbol: Option<String>,
     pub(crate) property: String,
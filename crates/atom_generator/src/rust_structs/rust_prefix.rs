@@ -0,0 +1,7 @@
+#[derive(Debug)]
+pub(crate) struct RustPrefix {
+    pub(crate) type_name: String,
+    pub(crate) primary_code: String,
+    pub(crate) secondary_code: String,
+    pub(crate) value: f64,
+}
@@ -1,8 +1,11 @@
-use super::{TomlBaseUnit, TomlUnit};
+use super::{TomlBaseUnit, TomlPrefix, TomlUnit};
 use std::str::FromStr;
 
 #[derive(Debug, serde::Deserialize)]
 pub(crate) struct TomlAtomList {
+    #[serde(rename = "prefix")]
+    pub(crate) prefixes: Vec<TomlPrefix>,
+
     #[serde(rename = "base-unit")]
     pub(crate) base_units: Vec<TomlBaseUnit>,
 